@@ -1,61 +1,1310 @@
 use std::{
-    io::{Read, Write},
-    sync::{self, Arc},
-    thread, time,
+    io::Write,
+    sync::mpsc::{channel, sync_channel, Receiver, Sender, SyncSender},
+    time::Duration,
 };
 
-use crate::rust_proto::algorithim;
-use crate::{domain::domain, rust_proto::algorithim::AlgorithimConfiguration};
-use protobuf::{Message, SpecialFields};
+use app_algorithims::{
+    bounce::{Bounce, BounceConfig, BounceEngine},
+    end::EndCondition,
+    height::HeightModel,
+    orientation::OrientationFilter,
+    sample::AccelSample,
+    spike::{SpikeConfig, SpikeTrigger},
+};
+use protobuf::Message;
+
+use super::calibration::{CalibrationError, CalibrationRoutine};
+use super::session::{Session, SessionError, SessionEvent, SessionState};
+use super::session_log::SessionLog;
+use super::settings::{self, CALIBRATION_KEY, SETTINGS_KEY};
+use super::stream::{SampleStreamer, StreamConfig};
+use crate::domain::{
+    calibration::{self, Calibration, Pose},
+    fifo::{AccelMode, AccelScale},
+    imu::ImuSource,
+    sample::ImuSample,
+    sensor::SensorConfig,
+    settings::{MemorySettingsStore, SettingsStore},
+};
+use crate::rust_proto::algorithim::{
+    self,
+    algorithim_configuration::{EndAlgorithim, StartAlgorthim},
+    message::Content,
+    CalibrationStep, ErrorCode,
+};
+use crate::storage::flash::Flash;
+use crate::transport::{
+    duplex::ByteStream,
+    framing::{self, FrameDecoder},
+};
+
+// samples that can queue up between two runs of the app loop, enough for the fastest output data
+// rate the sensor can be configured with
+const SAMPLE_QUEUE_SIZE: usize = 128;
+// longest the app loop waits for bytes from the phone before handling the queued samples
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(5);
 
 pub struct App {
-    sensor_input_buffer: Arc<sync::Mutex<Vec<u8>>>,
-    sensor_output_buffer: Arc<sync::Mutex<Vec<u8>>>,
-    algorithimConfiguration: algorithim::AlgorithimConfiguration,
+    // bytes to and from the phone
+    link: Box<dyn ByteStream>,
+    sample_sender: SyncSender<ImuSample>,
+    sample_receiver: Receiver<ImuSample>,
+    sensor_config: SensorConfig,
+    // the message sensor_config came from, kept as sent so it can be saved
+    sensor_configuration: algorithim::SensorConfiguration,
+    sensor_config_sender: Sender<SensorConfig>,
+    sensor_config_receiver: Option<Receiver<SensorConfig>>,
+    frame_decoder: FrameDecoder,
+    // the last configuration that was accepted, only these are saved
+    algorithim_configuration: Option<algorithim::AlgorithimConfiguration>,
+    start_trigger: Option<SpikeTrigger>,
+    end_condition: Option<EndCondition>,
+    bounce_engine: BounceEngine,
+    bounces: Vec<Bounce>,
+    session: Session,
+    // finished sets are kept here for the phone to download later, none without a flash partition
+    session_log: Option<SessionLog>,
+    streamer: SampleStreamer,
+    calibration: Calibration,
+    settings_store: Box<dyn SettingsStore + Send>,
+    calibration_routine: CalibrationRoutine,
+    // runs on every sample, not just while recording, so it knows which way is up before a set
+    orientation: OrientationFilter,
+    // timestamp in micro seconds the current set started at
+    set_start: i64,
 }
 
 impl App {
-    pub fn new(
-        sensor_input_buffer: Arc<sync::Mutex<Vec<u8>>>,
-        sensor_output_buffer: Arc<sync::Mutex<Vec<u8>>>,
-    ) -> Self {
+    pub fn new(link: Box<dyn ByteStream>) -> Self {
+        let (sample_sender, sample_receiver) = sync_channel::<ImuSample>(SAMPLE_QUEUE_SIZE);
+        let (sensor_config_sender, sensor_config_receiver) = channel::<SensorConfig>();
+
         App {
-            sensor_input_buffer,
-            sensor_output_buffer,
-            algorithimConfiguration: algorithim::AlgorithimConfiguration::default(),
+            link,
+            sample_sender,
+            sample_receiver,
+            sensor_config: SensorConfig::default(),
+            sensor_configuration: algorithim::SensorConfiguration::default(),
+            sensor_config_sender,
+            sensor_config_receiver: Some(sensor_config_receiver),
+            frame_decoder: FrameDecoder::new(),
+            algorithim_configuration: None,
+            start_trigger: None,
+            end_condition: None,
+            bounce_engine: BounceEngine::new(BounceConfig::default()),
+            bounces: Vec::new(),
+            session: Session::new(),
+            session_log: None,
+            streamer: SampleStreamer::new(),
+            calibration: Calibration::default(),
+            settings_store: Box::new(MemorySettingsStore::new()),
+            calibration_routine: CalibrationRoutine::new(),
+            orientation: OrientationFilter::default(),
+            set_start: 0,
         }
     }
-    // write data to buffer
-    fn write_data(self, bytes: &Vec<u8>) {
-        let out_buffer = Arc::clone(&self.sensor_output_buffer);
-        out_buffer.lock().unwrap().write_all(bytes).unwrap();
+    // send_message frames a message and writes it to the link
+    fn send_message(&mut self, message: &algorithim::Message) -> Result<(), protobuf::Error> {
+        let bytes = message.write_to_bytes()?;
+        self.link.write_all(&framing::encode(&bytes))?;
+        Ok(())
     }
-    fn set_configuration(&mut self, conf: algorithim::AlgorithimConfiguration) {
-        self.algorithimConfiguration = conf;
+    fn transition(&mut self, event: SessionEvent) {
+        if let Err(err) = self.session.handle(event) {
+            println!("invalid session transition {:?}", err);
+        }
     }
-    pub fn run(mut self) {
-        let inp_buffer = Arc::clone(&self.sensor_input_buffer);
-        loop {
-            let mut buffer = inp_buffer.lock().unwrap(); // reads from the buffer untill an EOF is recieved
-            let tmp_buffer = buffer.clone();
-            buffer.clear(); // clear the bytes in the buffer once read
-            println!("received bytes: {:?}", buffer);
-
-            let test: Result<algorithim::Message, protobuf::Error> =
-                Message::parse_from_bytes(&tmp_buffer);
-
-            match test {
-                Ok(message) => {
-                    message.has_Algorithim();
-                    if message.has_Algorithim() {
-                        self.set_configuration(message.Algorithim().clone());
+    // respond tells the phone whether the message with request_id was accepted
+    fn respond(&mut self, request_id: u32, result: Result<(), ErrorCode>) {
+        let mut response = algorithim::Response::new();
+        response.requestId = request_id;
+        response.success = result.is_ok();
+        response.error = result.err().unwrap_or(ErrorCode::NONE).into();
+        let mut message = algorithim::Message::new();
+        message.set_response(response);
+
+        if let Err(err) = self.send_message(&message) {
+            println!("error sending response {:?}", err);
+        }
+    }
+    fn set_configuration(
+        &mut self,
+        conf: algorithim::AlgorithimConfiguration,
+    ) -> Result<(), SessionError> {
+        // a new configuration stops whatever was running and waits for the athlete to start, a
+        // rejected one leaves the running one alone
+        let mut start_trigger = match &conf.startAlgorthim {
+            Some(StartAlgorthim::SpikeStart(spike)) => SpikeTrigger::new(spike_config(spike)),
+            _ => return Err(SessionError::NoStartAlgorithim),
+        };
+        // a zero count or duration would end every set as soon as it started
+        let end_condition = match &conf.endAlgorithim {
+            Some(EndAlgorithim::Timed(timed)) if timed.duration == 0 => {
+                return Err(SessionError::InvalidEndCondition)
+            }
+            Some(EndAlgorithim::Timed(timed)) => Some(EndCondition::Timed {
+                duration: timed.duration as i64 * 1000,
+            }),
+            Some(EndAlgorithim::Counted(counted)) if counted.counts == 0 => {
+                return Err(SessionError::InvalidEndCondition)
+            }
+            Some(EndAlgorithim::Counted(counted)) => Some(EndCondition::Counted {
+                counts: counted.counts,
+            }),
+            _ => None,
+        };
+        let height_model = height_model(&conf)?;
+
+        // the set being recorded is sent with what was measured so far before it is replaced
+        if self.state() == SessionState::Recording {
+            self.finish_set();
+        }
+        start_trigger.arm();
+        self.start_trigger = Some(start_trigger);
+        self.end_condition = end_condition;
+        self.bounce_engine = BounceEngine::new(BounceConfig {
+            height_model,
+            ..Default::default()
+        });
+        self.algorithim_configuration = Some(conf);
+        self.transition(SessionEvent::Configured);
+        Ok(())
+    }
+    // set_raw_stream starts or stops streaming the raw samples to the phone
+    fn set_raw_stream(&mut self, stream: &algorithim::RawStream) -> Result<(), ErrorCode> {
+        let config = StreamConfig::from_message(stream)?;
+        if let Some(batch) = self.streamer.configure(config) {
+            self.send_batch(batch);
+        }
+        Ok(())
+    }
+    fn send_batch(&mut self, batch: algorithim::SampleBatch) {
+        let mut message = algorithim::Message::new();
+        message.set_sampleBatch(batch);
+        if let Err(err) = self.send_message(&message) {
+            println!("error sending sample batch {:?}", err);
+        }
+    }
+    // set_sensor_configuration validates a sensor configuration and passes it on to the driver
+    fn set_sensor_configuration(
+        &mut self,
+        conf: &algorithim::SensorConfiguration,
+    ) -> Result<(), ErrorCode> {
+        let config = sensor_config(conf)?;
+        config
+            .validate()
+            .map_err(|_| ErrorCode::UNSUPPORTED_SENSOR_CONFIGURATION)?;
+
+        self.sensor_config = config;
+        self.sensor_configuration = conf.clone();
+        if self.sensor_config_sender.send(config).is_err() {
+            println!("no sensor driver to configure");
+        }
+        Ok(())
+    }
+    // set_settings_store replaces where the settings and calibration are kept and carries on with
+    // what was saved there, until then they only live in memory
+    pub fn set_settings_store(&mut self, store: Box<dyn SettingsStore + Send>) {
+        self.settings_store = store;
+
+        match self.settings_store.load(CALIBRATION_KEY) {
+            Ok(Some(bytes)) => match Calibration::from_bytes(&bytes) {
+                Some(calibration) => self.calibration = calibration,
+                None => println!("ignoring calibration saved in an unknown format"),
+            },
+            Ok(None) => {}
+            Err(err) => println!("error loading calibration {:?}", err),
+        }
+
+        let saved = match self.settings_store.load(SETTINGS_KEY) {
+            Ok(Some(bytes)) => settings::decode(&bytes),
+            Ok(None) => return,
+            Err(err) => {
+                println!("error loading settings {:?}", err);
+                return;
+            }
+        };
+        match saved {
+            Ok(saved) => {
+                if let Err(err) = self.set_sensor_configuration(&saved.sensor) {
+                    println!("ignoring saved sensor configuration {:?}", err);
+                }
+                if let Some(conf) = saved.algorithim.into_option() {
+                    if let Err(err) = self.set_configuration(conf) {
+                        println!("ignoring saved configuration {:?}", err);
                     }
                 }
-                Err(_err) => {
-                    println!("error parsing message {:?}", _err)
+            }
+            Err(err) => println!("ignoring saved settings {:?}", err),
+        }
+    }
+    // save_settings keeps the configurations for the next boot. they are already in use so a
+    // failure is only logged, the phone sends them again after a reboot
+    fn save_settings(&mut self) {
+        let mut saved = algorithim::Settings::new();
+        saved.algorithim = self.algorithim_configuration.clone().into();
+        saved.sensor = Some(self.sensor_configuration.clone()).into();
+
+        let result = settings::encode(&saved)
+            .map_err(|err| format!("{:?}", err))
+            .and_then(|bytes| {
+                self.settings_store
+                    .save(SETTINGS_KEY, &bytes)
+                    .map_err(|err| format!("{:?}", err))
+            });
+        if let Err(err) = result {
+            println!("error saving settings {}", err);
+        }
+    }
+    pub fn calibration(&self) -> Calibration {
+        self.calibration
+    }
+    // orientation is which way up the sensor is and where it is heading
+    pub fn orientation(&self) -> &OrientationFilter {
+        &self.orientation
+    }
+    // calibrate runs a step of the calibration routine and sends the phone its progress
+    fn calibrate(&mut self, calibrate: &algorithim::Calibrate) -> Result<(), ErrorCode> {
+        let routine = &mut self.calibration_routine;
+        let result = match calibrate.step.enum_value() {
+            Ok(CalibrationStep::CALIBRATION_STATUS) => Ok(()),
+            Ok(CalibrationStep::ACCEL_START) => {
+                routine.start_accel();
+                Ok(())
+            }
+            Ok(CalibrationStep::ACCEL_CAPTURE) => {
+                routine.capture().map(|_| ()).map_err(calibration_error)
+            }
+            Ok(CalibrationStep::MAG_START) => {
+                routine.start_mag();
+                Ok(())
+            }
+            Ok(CalibrationStep::CALIBRATION_FINISH) => routine
+                .finish(self.calibration)
+                .map_err(calibration_error)
+                .and_then(|fitted| self.save_calibration(fitted)),
+            Ok(CalibrationStep::CALIBRATION_CANCEL) => {
+                routine.cancel();
+                Ok(())
+            }
+            Ok(CalibrationStep::CALIBRATION_RESET) => self.save_calibration(Calibration::default()),
+            Err(_) => Err(ErrorCode::INVALID_ARGUMENT),
+        };
+        self.send_calibration_status();
+        result
+    }
+    // save_calibration starts using a calibration and keeps it for the next boot
+    fn save_calibration(&mut self, calibration: Calibration) -> Result<(), ErrorCode> {
+        self.calibration = calibration;
+        let bytes = calibration.to_bytes();
+        self.settings_store
+            .save(CALIBRATION_KEY, &bytes)
+            .map_err(|err| {
+                println!("error saving calibration {:?}", err);
+                ErrorCode::OUTPUT_FAILED
+            })
+    }
+    fn send_calibration_status(&mut self) {
+        let default = Calibration::default();
+        let mut status = algorithim::CalibrationStatus::new();
+        status.accelCalibrated = self.calibration.accel != default.accel;
+        status.magCalibrated = self.calibration.mag != default.mag;
+        status.missingPoses = self
+            .calibration_routine
+            .missing_poses()
+            .into_iter()
+            .map(|p| pose(p).into())
+            .collect();
+        status.magSamples = self.calibration_routine.mag_samples() as u32;
+        let mut message = algorithim::Message::new();
+        message.set_calibrationStatus(status);
+        if let Err(err) = self.send_message(&message) {
+            println!("error sending calibration status {:?}", err);
+        }
+    }
+    // set_session_flash keeps finished sets in a log on flash, sets recorded before are kept
+    pub fn set_session_flash(&mut self, flash: Box<dyn Flash + Send>) {
+        match SessionLog::mount(flash) {
+            Ok(log) => self.session_log = Some(log),
+            Err(err) => println!("error mounting session log {:?}", err),
+        }
+    }
+    fn session_log(&mut self) -> Result<&mut SessionLog, ErrorCode> {
+        self.session_log.as_mut().ok_or(ErrorCode::STORAGE_FAILED)
+    }
+    fn list_sessions(&mut self, request: &algorithim::ListSessions) -> Result<(), ErrorCode> {
+        let list = self.session_log()?.list(request)?;
+        let mut message = algorithim::Message::new();
+        message.set_sessionList(list);
+        self.send_message(&message)
+            .map_err(|_| ErrorCode::OUTPUT_FAILED)
+    }
+    fn download_session(&mut self, request: &algorithim::DownloadSession) -> Result<(), ErrorCode> {
+        let chunk = self.session_log()?.download(request)?;
+        let mut message = algorithim::Message::new();
+        message.set_sessionChunk(chunk);
+        self.send_message(&message)
+            .map_err(|_| ErrorCode::OUTPUT_FAILED)
+    }
+    pub fn sensor_config(&self) -> SensorConfig {
+        self.sensor_config
+    }
+    // sensor_config_receiver hands the driver the configurations sent by the phone, it can only be
+    // taken once
+    pub fn sensor_config_receiver(&mut self) -> Option<Receiver<SensorConfig>> {
+        self.sensor_config_receiver.take()
+    }
+    pub fn state(&self) -> SessionState {
+        self.session.state()
+    }
+    // handle_bytes decodes the framed messages in bytes received from the phone, a message can be
+    // split over several calls. frames that can not be parsed are answered with request id 0 as the
+    // real one is unknown
+    pub fn handle_bytes(&mut self, bytes: &[u8]) {
+        for frame in self.frame_decoder.push(bytes) {
+            let parsed = frame
+                .map_err(|err| format!("{:?}", err))
+                .and_then(|payload| {
+                    algorithim::Message::parse_from_bytes(&payload).map_err(|err| err.to_string())
+                });
+
+            match parsed {
+                Ok(message) => self.handle_message(message),
+                Err(err) => {
+                    println!("error parsing message {:?}", err);
+                    self.respond(0, Err(ErrorCode::PARSE_ERROR));
                 }
             }
-            thread::sleep(time::Duration::from_millis(50));
         }
     }
+    // handle_message acts on a message received from the phone and responds to it
+    pub fn handle_message(&mut self, message: algorithim::Message) {
+        let result = match message.content {
+            Some(Content::Algorithim(conf)) => self
+                .set_configuration(conf)
+                .map_err(error_code)
+                .map(|()| self.save_settings()),
+            Some(Content::RawStream(stream)) => self.set_raw_stream(&stream),
+            Some(Content::SensorConfiguration(conf)) => self
+                .set_sensor_configuration(&conf)
+                .map(|()| self.save_settings()),
+            Some(Content::Calibrate(calibrate)) => self.calibrate(&calibrate),
+            Some(Content::ListSessions(request)) => self.list_sessions(&request),
+            Some(Content::DownloadSession(request)) => self.download_session(&request),
+            Some(Content::EraseSessions(_)) => self.session_log().and_then(|log| log.erase()),
+            // everything else only ever goes from the sensor to the phone
+            _ => Err(ErrorCode::UNSUPPORTED),
+        };
+        self.respond(message.requestId, result);
+    }
+    // sample_sender returns a sender that the sensor driver can push samples into from its own
+    // thread, they are processed by run
+    pub fn sample_sender(&self) -> SyncSender<ImuSample> {
+        self.sample_sender.clone()
+    }
+    // push_sample feeds a sample through the configured algorithims straight away. the raw stream
+    // and the calibration routine get the sample as it came from the driver, the algorithims get
+    // it calibrated
+    pub fn push_sample(&mut self, sample: ImuSample) {
+        if let Some(batch) = self.streamer.push(sample) {
+            self.send_batch(batch);
+        }
+        self.calibration_routine.push(&sample);
+        let sample = calibration::calibrate(&self.calibration, sample);
+        self.orientation.push(&sample.accel_sample());
+        self.orientation.push_mag(sample.timestamp, sample.mag);
+        self.push_accel(sample.accel_sample());
+    }
+    // push_source feeds every sample of a source through the algorithims until it runs out
+    pub fn push_source<S: ImuSource>(&mut self, source: &mut S) -> Result<(), S::Error> {
+        while let Some(sample) = source.next_sample()? {
+            self.push_sample(sample);
+        }
+        Ok(())
+    }
+    // drain_samples processes every sample queued through the sample sender
+    fn drain_samples(&mut self) {
+        while let Ok(sample) = self.sample_receiver.try_recv() {
+            self.push_sample(sample);
+        }
+    }
+    fn push_accel(&mut self, sample: AccelSample) {
+        match self.session.state() {
+            SessionState::Armed => match self.start_trigger.as_mut().and_then(|t| t.push(sample)) {
+                Some(start) => self.start_set(start),
+                None => return,
+            },
+            SessionState::Recording => {}
+            _ => return,
+        }
+
+        let up = self.orientation.up();
+        if let Some(bounce) = self.bounce_engine.push_oriented(sample, up) {
+            self.bounces.push(bounce);
+        }
+
+        let finished = self.end_condition.is_some_and(|end| {
+            end.is_met(sample.timestamp - self.set_start, self.bounces.len() as u32)
+        });
+        if finished {
+            self.finish_set();
+        }
+    }
+    fn start_set(&mut self, start: i64) {
+        self.transition(SessionEvent::StartTriggered);
+        self.set_start = start;
+        self.bounces.clear();
+        self.bounce_engine.reset();
+    }
+    // finish_set sends the measured set to the phone and keeps it in the session log
+    fn finish_set(&mut self) {
+        self.transition(SessionEvent::EndConditionMet);
+
+        let mut rep = algorithim::Rep::new();
+        rep.set_bouningRep(bouncing_rep(&self.bounces));
+        if let Some(log) = self.session_log.as_mut() {
+            if let Err(err) = log.record(&rep) {
+                println!("error logging set {:?}", err);
+            }
+        }
+        let mut message = algorithim::Message::new();
+        message.set_rep(rep);
+        self.bounces.clear();
+
+        match self.send_message(&message) {
+            Ok(()) => self.transition(SessionEvent::RepSent),
+            Err(err) => {
+                println!("error serialising rep {:?}", err);
+                self.transition(SessionEvent::Fault(SessionError::OutputFailed));
+            }
+        }
+    }
+    // run handles the phone's messages as they arrive and the samples as they queue up, until the
+    // link is closed
+    pub fn run(mut self) {
+        let mut buffer = [0u8; 512];
+        loop {
+            match self.link.read_timeout(&mut buffer, SAMPLE_INTERVAL) {
+                Ok(Some(0)) => {
+                    println!("link closed, stopping the app");
+                    return;
+                }
+                Ok(Some(len)) => self.handle_bytes(&buffer[..len]),
+                Ok(None) => {}
+                Err(err) => println!("error reading from the link {:?}", err),
+            }
+            self.drain_samples();
+        }
+    }
+}
+
+// bouncing_rep summarises the bounces of a set, times are sent in ms and heights in mm
+fn bouncing_rep(bounces: &[Bounce]) -> algorithim::BouningRep {
+    let mut rep = algorithim::BouningRep::new();
+    rep.measurements = bounces
+        .iter()
+        .map(|bounce| {
+            let mut measurement = algorithim::BounceMeasurment::new();
+            measurement.groundContactTime = bounce.ground_contact_ms();
+            measurement.height = bounce.height_mm();
+            measurement
+        })
+        .collect();
+
+    if !bounces.is_empty() {
+        let total_height: f32 = bounces.iter().map(|b| b.height).sum();
+        rep.averageHeight = (total_height / bounces.len() as f32 * 1000.0).round() as u32;
+    }
+
+    // the first jump out of a standing start has no contact time so it is left out of the average
+    let contacts: Vec<i64> = bounces
+        .iter()
+        .filter_map(|b| b.ground_contact_time)
+        .collect();
+    if !contacts.is_empty() {
+        rep.averageGroundContactTime =
+            (contacts.iter().sum::<i64>() / contacts.len() as i64 / 1000) as u32;
+    }
+    rep
+}
+
+// error_code is the code the phone gets for a session error
+fn error_code(err: SessionError) -> ErrorCode {
+    match err {
+        SessionError::NoStartAlgorithim => ErrorCode::NO_START_ALGORITHIM,
+        SessionError::InvalidEndCondition | SessionError::UnknownHeightModel => {
+            ErrorCode::INVALID_ARGUMENT
+        }
+        SessionError::OutputFailed => ErrorCode::OUTPUT_FAILED,
+    }
+}
+
+// calibration_error is the code the phone gets for a failed calibration step
+fn calibration_error(err: CalibrationError) -> ErrorCode {
+    println!("calibration failed {:?}", err);
+    ErrorCode::CALIBRATION_FAILED
+}
+
+fn pose(pose: Pose) -> algorithim::Pose {
+    match pose {
+        Pose::XUp => algorithim::Pose::X_UP,
+        Pose::XDown => algorithim::Pose::X_DOWN,
+        Pose::YUp => algorithim::Pose::Y_UP,
+        Pose::YDown => algorithim::Pose::Y_DOWN,
+        Pose::ZUp => algorithim::Pose::Z_UP,
+        Pose::ZDown => algorithim::Pose::Z_DOWN,
+    }
+}
+
+// sensor_config converts the protocol message into the sensor configuration, zero means default
+fn sensor_config(conf: &algorithim::SensorConfiguration) -> Result<SensorConfig, ErrorCode> {
+    let default = SensorConfig::default();
+    let scale = match conf.accelRange.enum_value() {
+        Ok(algorithim::AccelRange::RANGE_DEFAULT) => default.scale,
+        Ok(algorithim::AccelRange::RANGE_2G) => AccelScale::G2,
+        Ok(algorithim::AccelRange::RANGE_4G) => AccelScale::G4,
+        Ok(algorithim::AccelRange::RANGE_8G) => AccelScale::G8,
+        Ok(algorithim::AccelRange::RANGE_16G) => AccelScale::G16,
+        Err(_) => return Err(ErrorCode::INVALID_ARGUMENT),
+    };
+    let mode = match conf.accelMode.enum_value() {
+        Ok(algorithim::AccelMode::MODE_DEFAULT) => default.mode,
+        Ok(algorithim::AccelMode::LOW_POWER) => AccelMode::LowPower,
+        Ok(algorithim::AccelMode::NORMAL) => AccelMode::Normal,
+        Ok(algorithim::AccelMode::HIGH_RESOLUTION) => AccelMode::HighResolution,
+        Err(_) => return Err(ErrorCode::INVALID_ARGUMENT),
+    };
+    Ok(SensorConfig {
+        accel_odr: match conf.accelOdr {
+            0 => default.accel_odr,
+            odr => odr,
+        },
+        mag_odr: match conf.magOdr {
+            0 => default.mag_odr,
+            odr => odr,
+        },
+        scale,
+        mode,
+    })
+}
+
+// spike_config converts the protocol message into the trigger configuration, zero means default
+fn spike_config(spike: &algorithim::SpikeStart) -> SpikeConfig {
+    let default = SpikeConfig::default();
+    SpikeConfig {
+        threshold: match spike.threshold {
+            0 => default.threshold,
+            threshold => threshold as f32,
+        },
+        release_threshold: match spike.releaseThreshold {
+            0 => default.release_threshold,
+            threshold => threshold as f32,
+        },
+        refractory_period: match spike.refractoryPeriod {
+            0 => default.refractory_period,
+            millis => millis as i64 * 1000,
+        },
+    }
+}
+
+// height_model is how the configuration wants the bounce heights worked out
+fn height_model(conf: &algorithim::AlgorithimConfiguration) -> Result<HeightModel, SessionError> {
+    match conf.heightModel.enum_value() {
+        Ok(algorithim::HeightModel::FLIGHT_TIME) => Ok(HeightModel::FlightTime),
+        Ok(algorithim::HeightModel::TAKEOFF_VELOCITY) => Ok(HeightModel::TakeoffVelocity),
+        Err(_) => Err(SessionError::UnknownHeightModel),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::flash::MemoryFlash;
+    use crate::transport::duplex::{self, PipeEnd};
+    use app_algorithims::sample::Vector3;
+    use std::{
+        cell::RefCell,
+        ops::{Deref, DerefMut},
+    };
+
+    // an app with the phone's end of its link, everything the app sent is kept in sent
+    struct TestApp {
+        app: App,
+        phone: RefCell<PipeEnd>,
+        sent: RefCell<Vec<u8>>,
+    }
+
+    impl TestApp {
+        fn sent(&self) -> Vec<u8> {
+            let mut buffer = [0u8; 1024];
+            let mut phone = self.phone.borrow_mut();
+            while let Ok(Some(len @ 1..)) = phone.read_timeout(&mut buffer, Duration::ZERO) {
+                self.sent.borrow_mut().extend_from_slice(&buffer[..len]);
+            }
+            self.sent.borrow().clone()
+        }
+
+        fn clear_sent(&self) {
+            self.sent();
+            self.sent.borrow_mut().clear();
+        }
+    }
+
+    impl Deref for TestApp {
+        type Target = App;
+
+        fn deref(&self) -> &App {
+            &self.app
+        }
+    }
+
+    impl DerefMut for TestApp {
+        fn deref_mut(&mut self) -> &mut App {
+            &mut self.app
+        }
+    }
+
+    fn app() -> TestApp {
+        let (mut link, phone) = duplex::pipe(1 << 20);
+        // fail rather than hang if a test ever fills the pipe
+        link.set_write_timeout(Some(Duration::ZERO));
+        TestApp {
+            app: App::new(Box::new(link)),
+            phone: RefCell::new(phone),
+            sent: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn spike_start(threshold: u32, refractory_period: u32) -> algorithim::AlgorithimConfiguration {
+        let mut spike = algorithim::SpikeStart::new();
+        spike.threshold = threshold;
+        spike.refractoryPeriod = refractory_period;
+
+        let mut conf = algorithim::AlgorithimConfiguration::new();
+        conf.set_spikeStart(spike);
+        conf
+    }
+
+    fn sample(millis: i64, magnitude: f32) -> AccelSample {
+        AccelSample::new(millis * 1000, Vector3::new(0.0, 0.0, magnitude))
+    }
+
+    #[test]
+    fn spike_starts_recording() {
+        let mut app = app();
+        assert_eq!(app.state(), SessionState::Idle);
+        app.set_configuration(spike_start(1800, 200)).unwrap();
+
+        app.push_accel(sample(0, 1000.0));
+        app.push_accel(sample(100, 2000.0));
+        assert_eq!(app.state(), SessionState::Armed);
+
+        app.push_accel(sample(300, 1000.0));
+        app.push_accel(sample(310, 2000.0));
+        assert_eq!(app.state(), SessionState::Recording);
+    }
+
+    #[test]
+    fn no_start_algorithim_is_an_error() {
+        let mut app = app();
+        let result = app.set_configuration(algorithim::AlgorithimConfiguration::new());
+
+        assert_eq!(result, Err(SessionError::NoStartAlgorithim));
+
+        app.push_accel(sample(0, 1000.0));
+        app.push_accel(sample(1000, 5000.0));
+        assert_eq!(app.state(), SessionState::Idle);
+    }
+
+    #[test]
+    fn height_model_is_configured() {
+        let mut app = app();
+        assert_eq!(
+            app.bounce_engine.config().height_model,
+            HeightModel::FlightTime
+        );
+
+        let mut conf = spike_start(1800, 200);
+        conf.heightModel = algorithim::HeightModel::TAKEOFF_VELOCITY.into();
+        app.set_configuration(conf).unwrap();
+        assert_eq!(
+            app.bounce_engine.config().height_model,
+            HeightModel::TakeoffVelocity
+        );
+
+        let mut unknown = spike_start(1800, 200);
+        unknown.heightModel = protobuf::EnumOrUnknown::from_i32(7);
+        assert_eq!(
+            app.set_configuration(unknown),
+            Err(SessionError::UnknownHeightModel)
+        );
+        assert_eq!(
+            app.bounce_engine.config().height_model,
+            HeightModel::TakeoffVelocity
+        );
+    }
+
+    #[test]
+    fn zero_end_conditions_are_rejected() {
+        let mut app = app();
+        let mut counted = spike_start(1800, 200);
+        counted.set_counted(algorithim::Counted::new());
+        let mut timed = spike_start(1800, 200);
+        timed.set_timed(algorithim::Timed::new());
+
+        assert_eq!(
+            app.set_configuration(counted),
+            Err(SessionError::InvalidEndCondition)
+        );
+        assert_eq!(
+            app.set_configuration(timed.clone()),
+            Err(SessionError::InvalidEndCondition)
+        );
+        assert_eq!(app.state(), SessionState::Idle);
+
+        app.handle_bytes(&request(3, timed));
+        assert_eq!(
+            sent_responses(&app)[0].error.enum_value(),
+            Ok(ErrorCode::INVALID_ARGUMENT)
+        );
+    }
+
+    #[test]
+    fn rejected_configuration_keeps_the_running_one() {
+        let mut app = app();
+        app.set_configuration(spike_start(1800, 200)).unwrap();
+        let running = app.algorithim_configuration.clone();
+
+        let result = app.set_configuration(algorithim::AlgorithimConfiguration::new());
+
+        assert_eq!(result, Err(SessionError::NoStartAlgorithim));
+        assert_eq!(app.algorithim_configuration, running);
+        assert_eq!(app.state(), SessionState::Armed);
+        app.push_accel(sample(0, 1000.0));
+        app.push_accel(sample(300, 1000.0));
+        app.push_accel(sample(310, 2000.0));
+        assert_eq!(app.state(), SessionState::Recording);
+    }
+
+    // segments of (milli g, duration in ms) sampled at 100 Hz from the given time
+    fn push_trace(app: &mut App, mut millis: i64, segments: &[(f32, i64)]) -> i64 {
+        for (magnitude, duration) in segments {
+            let end = millis + duration;
+            while millis < end {
+                app.push_accel(sample(millis, *magnitude));
+                millis += 10;
+            }
+        }
+        millis
+    }
+
+    fn sent_messages(app: &TestApp) -> Vec<algorithim::Message> {
+        let bytes = app.sent();
+        FrameDecoder::new()
+            .push(&bytes)
+            .into_iter()
+            .map(|frame| algorithim::Message::parse_from_bytes(&frame.unwrap()).unwrap())
+            .collect()
+    }
+
+    fn sent_rep(app: &TestApp) -> algorithim::BouningRep {
+        let messages = sent_messages(app);
+        assert_eq!(messages.len(), 1);
+        messages[0].rep().bouningRep().clone()
+    }
+
+    #[test]
+    fn counted_set_sends_rep() {
+        let mut app = app();
+        let mut conf = spike_start(1800, 200);
+        let mut counted = algorithim::Counted::new();
+        counted.counts = 2;
+        conf.set_counted(counted);
+        app.set_configuration(conf).unwrap();
+
+        push_trace(
+            &mut app,
+            0,
+            &[
+                (1000.0, 500),
+                (2500.0, 100),
+                (0.0, 500),
+                (2500.0, 200),
+                (0.0, 400),
+                (2500.0, 200),
+            ],
+        );
+
+        assert_eq!(app.state(), SessionState::Idle);
+        let rep = sent_rep(&app);
+        assert_eq!(rep.measurements.len(), 2);
+        assert_eq!(rep.measurements[0].groundContactTime, 0);
+        assert!((rep.measurements[0].height as i32 - 306).abs() < 15);
+        assert!((rep.measurements[1].groundContactTime as i32 - 200).abs() <= 10);
+        assert_eq!(
+            rep.averageGroundContactTime,
+            rep.measurements[1].groundContactTime
+        );
+    }
+
+    #[test]
+    fn reconfiguring_finishes_the_set() {
+        let mut app = app();
+        let mut conf = spike_start(1800, 200);
+        let mut counted = algorithim::Counted::new();
+        counted.counts = 3;
+        conf.set_counted(counted);
+        app.set_configuration(conf).unwrap();
+        push_trace(
+            &mut app,
+            0,
+            &[(1000.0, 500), (2500.0, 100), (0.0, 500), (2500.0, 200)],
+        );
+        assert_eq!(app.state(), SessionState::Recording);
+
+        app.set_configuration(spike_start(1800, 200)).unwrap();
+
+        assert_eq!(app.state(), SessionState::Armed);
+        assert_eq!(sent_rep(&app).measurements.len(), 1);
+    }
+
+    #[test]
+    fn timed_set_sends_rep() {
+        let mut app = app();
+        let mut conf = spike_start(1800, 200);
+        let mut timed = algorithim::Timed::new();
+        timed.duration = 1000;
+        conf.set_timed(timed);
+        app.set_configuration(conf).unwrap();
+
+        let millis = push_trace(&mut app, 0, &[(1000.0, 500), (2500.0, 100), (0.0, 500)]);
+        assert_eq!(app.state(), SessionState::Recording);
+
+        push_trace(&mut app, millis, &[(1000.0, 500)]);
+        assert_eq!(app.state(), SessionState::Idle);
+        assert_eq!(sent_rep(&app).measurements.len(), 1);
+    }
+
+    #[test]
+    fn orientation_is_tracked_while_idle() {
+        let mut app = app();
+        // an accel offset the calibration takes off again
+        let mut calibration = Calibration::default();
+        calibration.accel.offset = Vector3::new(0.0, 0.0, 100.0);
+        app.save_calibration(calibration).unwrap();
+
+        for millis in 0..100 {
+            let accel = Vector3::new(600.0, 0.0, 900.0);
+            app.push_sample(ImuSample::new(millis * 10_000, accel, Vector3::default()));
+        }
+
+        assert_eq!(app.state(), SessionState::Idle);
+        let up = app.orientation().up().unwrap();
+        assert!((up - Vector3::new(0.6, 0.0, 0.8)).magnitude() < 1e-3);
+    }
+
+    #[test]
+    fn samples_from_the_sender_are_processed() {
+        let mut app = app();
+        app.set_configuration(spike_start(1800, 200)).unwrap();
+        let sender = app.sample_sender();
+
+        for (millis, magnitude) in [(0, 1000.0), (300, 1000.0), (310, 2000.0)] {
+            let accel = Vector3::new(0.0, 0.0, magnitude);
+            sender
+                .send(ImuSample::new(millis * 1000, accel, Vector3::default()))
+                .unwrap();
+        }
+        assert_eq!(app.state(), SessionState::Armed);
+
+        app.drain_samples();
+        assert_eq!(app.state(), SessionState::Recording);
+    }
+
+    #[test]
+    fn configuration_split_over_writes() {
+        let mut message = algorithim::Message::new();
+        message.set_Algorithim(spike_start(1800, 200));
+        let frame = framing::encode(&message.write_to_bytes().unwrap());
+
+        let mut app = app();
+        app.handle_bytes(&frame[..3]);
+        assert_eq!(app.state(), SessionState::Idle);
+
+        app.handle_bytes(&frame[3..]);
+        assert_eq!(app.state(), SessionState::Armed);
+    }
+
+    fn request(id: u32, conf: algorithim::AlgorithimConfiguration) -> Vec<u8> {
+        let mut message = algorithim::Message::new();
+        message.requestId = id;
+        message.set_Algorithim(conf);
+        framing::encode(&message.write_to_bytes().unwrap())
+    }
+
+    fn sent_responses(app: &TestApp) -> Vec<algorithim::Response> {
+        sent_messages(app)
+            .iter()
+            .filter(|message| message.has_response())
+            .map(|message| message.response().clone())
+            .collect()
+    }
+
+    #[test]
+    fn configuration_is_acknowledged() {
+        let mut app = app();
+        app.handle_bytes(&request(7, spike_start(1800, 200)));
+
+        let responses = sent_responses(&app);
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].requestId, 7);
+        assert!(responses[0].success);
+        assert_eq!(responses[0].error.enum_value(), Ok(ErrorCode::NONE));
+    }
+
+    #[test]
+    fn invalid_configuration_is_rejected() {
+        let mut app = app();
+        app.handle_bytes(&request(8, algorithim::AlgorithimConfiguration::new()));
+
+        let responses = sent_responses(&app);
+        assert_eq!(responses[0].requestId, 8);
+        assert!(!responses[0].success);
+        assert_eq!(
+            responses[0].error.enum_value(),
+            Ok(ErrorCode::NO_START_ALGORITHIM)
+        );
+    }
+
+    #[test]
+    fn parse_errors_are_reported() {
+        let mut app = app();
+        let mut bytes = framing::encode(&[0xFF, 0xFF, 0xFF]);
+        let mut corrupt = request(9, spike_start(1800, 200));
+        corrupt[2] ^= 0x40;
+        bytes.extend_from_slice(&corrupt);
+
+        app.handle_bytes(&bytes);
+
+        let responses = sent_responses(&app);
+        assert_eq!(responses.len(), 2);
+        for response in responses {
+            assert_eq!(response.requestId, 0);
+            assert!(!response.success);
+            assert_eq!(response.error.enum_value(), Ok(ErrorCode::PARSE_ERROR));
+        }
+        assert_eq!(app.state(), SessionState::Idle);
+    }
+
+    #[test]
+    fn messages_for_the_phone_are_unsupported() {
+        let mut app = app();
+        let mut message = algorithim::Message::new();
+        message.requestId = 3;
+        message.set_rep(algorithim::Rep::new());
+
+        app.handle_message(message);
+
+        let responses = sent_responses(&app);
+        assert_eq!(responses[0].requestId, 3);
+        assert_eq!(responses[0].error.enum_value(), Ok(ErrorCode::UNSUPPORTED));
+    }
+
+    #[test]
+    fn streams_raw_samples() {
+        let mut app = app();
+        let mut stream = algorithim::RawStream::new();
+        stream.enabled = true;
+        stream.batchSize = 4;
+        let mut message = algorithim::Message::new();
+        message.requestId = 5;
+        message.set_rawStream(stream);
+        app.handle_message(message);
+
+        for millis in 0..10 {
+            let accel = Vector3::new(0.0, 0.0, 1000.0);
+            app.push_sample(ImuSample::new(millis * 10_000, accel, Vector3::default()));
+        }
+        let mut message = algorithim::Message::new();
+        message.set_rawStream(algorithim::RawStream::new());
+        app.handle_message(message);
+
+        let sent = sent_messages(&app);
+        assert!(sent[0].response().success);
+        let batches: Vec<_> = sent
+            .iter()
+            .filter(|m| m.has_sampleBatch())
+            .map(|m| m.sampleBatch())
+            .collect();
+        let sizes: Vec<_> = batches.iter().map(|b| b.timestampDeltas.len()).collect();
+        assert_eq!(sizes, vec![4, 4, 2]);
+        assert_eq!(batches[2].startTimestamp, 80_000);
+    }
+
+    fn sensor_configuration(accel_odr: u32, mode: algorithim::AccelMode) -> algorithim::Message {
+        let mut conf = algorithim::SensorConfiguration::new();
+        conf.accelOdr = accel_odr;
+        conf.accelRange = algorithim::AccelRange::RANGE_8G.into();
+        conf.accelMode = mode.into();
+        let mut message = algorithim::Message::new();
+        message.requestId = 11;
+        message.set_sensorConfiguration(conf);
+        message
+    }
+
+    #[test]
+    fn sensor_configuration_reaches_the_driver() {
+        let mut app = app();
+        let driver = app.sensor_config_receiver().unwrap();
+
+        app.handle_message(sensor_configuration(1344, algorithim::AccelMode::NORMAL));
+
+        let expected = SensorConfig {
+            accel_odr: 1344,
+            mag_odr: 100,
+            scale: AccelScale::G8,
+            mode: AccelMode::Normal,
+        };
+        assert!(sent_responses(&app)[0].success);
+        assert_eq!(app.sensor_config(), expected);
+        assert_eq!(driver.try_recv(), Ok(expected));
+    }
+
+    #[test]
+    fn unsupported_sensor_configuration_is_rejected() {
+        let mut app = app();
+        let driver = app.sensor_config_receiver().unwrap();
+
+        app.handle_message(sensor_configuration(1344, algorithim::AccelMode::LOW_POWER));
+
+        let response = &sent_responses(&app)[0];
+        assert!(!response.success);
+        assert_eq!(
+            response.error.enum_value(),
+            Ok(ErrorCode::UNSUPPORTED_SENSOR_CONFIGURATION)
+        );
+        assert_eq!(app.sensor_config(), SensorConfig::default());
+        assert!(driver.try_recv().is_err());
+    }
+
+    fn calibrate(app: &mut TestApp, step: CalibrationStep) -> algorithim::CalibrationStatus {
+        let mut calibrate = algorithim::Calibrate::new();
+        calibrate.step = step.into();
+        let mut message = algorithim::Message::new();
+        message.set_calibrate(calibrate);
+        app.handle_message(message);
+
+        let sent = sent_messages(app);
+        let status = sent
+            .iter()
+            .rev()
+            .find(|m| m.has_calibrationStatus())
+            .unwrap();
+        status.calibrationStatus().clone()
+    }
+
+    #[test]
+    fn accelerometer_calibration_is_saved_and_applied() {
+        let mut app = app();
+        let offset = Vector3::new(15.0, -30.0, 40.0);
+        let status = calibrate(&mut app, CalibrationStep::ACCEL_START);
+        assert_eq!(status.missingPoses.len(), 6);
+
+        for (i, g) in [
+            Vector3::new(1000.0, 0.0, 0.0),
+            Vector3::new(-1000.0, 0.0, 0.0),
+            Vector3::new(0.0, 1000.0, 0.0),
+            Vector3::new(0.0, -1000.0, 0.0),
+            Vector3::new(0.0, 0.0, 1000.0),
+            Vector3::new(0.0, 0.0, -1000.0),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            for millis in 0..100 {
+                app.push_sample(ImuSample::new(
+                    millis * 2500,
+                    g + offset,
+                    Vector3::default(),
+                ));
+            }
+            let status = calibrate(&mut app, CalibrationStep::ACCEL_CAPTURE);
+            assert_eq!(status.missingPoses.len(), 5 - i);
+        }
+        let status = calibrate(&mut app, CalibrationStep::CALIBRATION_FINISH);
+
+        assert!(status.accelCalibrated);
+        assert!(!status.magCalibrated);
+        assert!(sent_responses(&app).iter().all(|r| r.success));
+        assert_eq!(app.calibration().accel.offset, offset);
+
+        // a fresh app loading from the same store starts calibrated
+        let mut store = MemorySettingsStore::new();
+        store
+            .save(CALIBRATION_KEY, &app.calibration().to_bytes())
+            .unwrap();
+        let mut restarted = self::app();
+        restarted.set_settings_store(Box::new(store));
+        assert_eq!(restarted.calibration(), app.calibration());
+
+        let status = calibrate(&mut restarted, CalibrationStep::CALIBRATION_RESET);
+        assert!(!status.accelCalibrated);
+        assert_eq!(restarted.calibration(), Calibration::default());
+    }
+
+    #[test]
+    fn failed_calibration_steps_are_reported() {
+        let mut app = app();
+
+        calibrate(&mut app, CalibrationStep::ACCEL_CAPTURE);
+        calibrate(&mut app, CalibrationStep::MAG_START);
+        calibrate(&mut app, CalibrationStep::CALIBRATION_FINISH);
+
+        let responses = sent_responses(&app);
+        assert_eq!(
+            responses[0].error.enum_value(),
+            Ok(ErrorCode::CALIBRATION_FAILED)
+        );
+        assert!(responses[1].success);
+        assert_eq!(
+            responses[2].error.enum_value(),
+            Ok(ErrorCode::CALIBRATION_FAILED)
+        );
+        assert_eq!(app.calibration(), Calibration::default());
+    }
+
+    #[test]
+    fn settings_survive_a_restart() {
+        let store = MemorySettingsStore::new();
+        let mut app = app();
+        app.set_settings_store(Box::new(store.clone()));
+        app.handle_bytes(&request(1, spike_start(1800, 200)));
+        app.handle_message(sensor_configuration(200, algorithim::AccelMode::NORMAL));
+        assert!(sent_responses(&app).iter().all(|r| r.success));
+
+        let mut restarted = self::app();
+        let driver = restarted.sensor_config_receiver().unwrap();
+        restarted.set_settings_store(Box::new(store));
+
+        assert_eq!(restarted.state(), SessionState::Armed);
+        assert_eq!(
+            restarted.algorithim_configuration,
+            app.algorithim_configuration
+        );
+        assert_eq!(restarted.sensor_config(), app.sensor_config());
+        assert_eq!(driver.try_recv(), Ok(app.sensor_config()));
+        // nothing is sent to the phone for what was loaded
+        assert!(sent_messages(&restarted).is_empty());
+    }
+
+    #[test]
+    fn rejected_settings_are_not_saved() {
+        let mut store = MemorySettingsStore::new();
+        let mut app = app();
+        app.set_settings_store(Box::new(store.clone()));
+
+        app.handle_message(sensor_configuration(1344, algorithim::AccelMode::LOW_POWER));
+
+        assert_eq!(store.load(SETTINGS_KEY), Ok(None));
+    }
+
+    #[test]
+    fn rejected_configurations_are_not_saved() {
+        let store = MemorySettingsStore::new();
+        let mut app = app();
+        app.set_settings_store(Box::new(store.clone()));
+        app.handle_bytes(&request(1, spike_start(1800, 200)));
+        app.handle_bytes(&request(2, algorithim::AlgorithimConfiguration::new()));
+        app.handle_message(sensor_configuration(200, algorithim::AccelMode::NORMAL));
+
+        let mut restarted = self::app();
+        restarted.set_settings_store(Box::new(store));
+
+        assert_eq!(restarted.state(), SessionState::Armed);
+        assert_eq!(
+            restarted.algorithim_configuration,
+            Some(spike_start(1800, 200))
+        );
+    }
+
+    #[test]
+    fn settings_without_an_accepted_configuration_restart_idle() {
+        let store = MemorySettingsStore::new();
+        let mut app = app();
+        app.set_settings_store(Box::new(store.clone()));
+        app.handle_bytes(&request(1, algorithim::AlgorithimConfiguration::new()));
+        app.handle_message(sensor_configuration(200, algorithim::AccelMode::NORMAL));
+
+        let mut restarted = self::app();
+        restarted.set_settings_store(Box::new(store));
+
+        assert_eq!(restarted.state(), SessionState::Idle);
+        assert_eq!(restarted.algorithim_configuration, None);
+        assert_eq!(restarted.sensor_config(), app.sensor_config());
+    }
+
+    #[test]
+    fn finished_sets_can_be_downloaded_later() {
+        let mut app = app();
+        app.set_session_flash(Box::new(MemoryFlash::new(4)));
+        let mut conf = spike_start(1800, 200);
+        let mut counted = algorithim::Counted::new();
+        counted.counts = 1;
+        conf.set_counted(counted);
+        app.set_configuration(conf).unwrap();
+        push_trace(
+            &mut app,
+            0,
+            &[(1000.0, 500), (2500.0, 100), (0.0, 500), (2500.0, 200)],
+        );
+        let sent = sent_rep(&app);
+        app.clear_sent();
+
+        let mut message = algorithim::Message::new();
+        message.set_listSessions(algorithim::ListSessions::new());
+        app.handle_message(message);
+        let list = sent_messages(&app)[0].sessionList().clone();
+        assert_eq!(list.sessions.len(), 1);
+
+        let mut download = algorithim::DownloadSession::new();
+        download.id = list.sessions[0].id;
+        let mut message = algorithim::Message::new();
+        message.set_downloadSession(download);
+        app.handle_message(message);
+        let chunk = sent_messages(&app)[2].sessionChunk().clone();
+        let stored = algorithim::Rep::parse_from_bytes(&chunk.data).unwrap();
+        assert_eq!(stored.bouningRep(), &sent);
+
+        let mut message = algorithim::Message::new();
+        message.set_eraseSessions(algorithim::EraseSessions::new());
+        app.handle_message(message);
+        assert!(sent_responses(&app).iter().all(|r| r.success));
+    }
+
+    #[test]
+    fn session_requests_need_a_log() {
+        let mut app = app();
+        let mut message = algorithim::Message::new();
+        message.set_listSessions(algorithim::ListSessions::new());
+
+        app.handle_message(message);
+
+        assert_eq!(
+            sent_responses(&app)[0].error.enum_value(),
+            Ok(ErrorCode::STORAGE_FAILED)
+        );
+    }
+
+    #[test]
+    fn zero_fields_use_defaults() {
+        assert_eq!(
+            spike_config(&algorithim::SpikeStart::new()),
+            SpikeConfig::default()
+        );
+        assert_eq!(
+            sensor_config(&algorithim::SensorConfiguration::new()),
+            Ok(SensorConfig::default())
+        );
+    }
 }
@@ -0,0 +1,231 @@
+use std::collections::VecDeque;
+
+use app_algorithims::calibration::{fit_ellipsoid, still_mean, SixPoseCalibrator};
+
+use crate::domain::{
+    calibration::{Calibration, Pose},
+    sample::{ImuSample, Vector3},
+};
+
+/*
+    calibration routine
+
+    the phone walks the athlete through it. for the accelerometer the sensor is held still in each
+    of the six poses, one axis straight up or down, and the phone asks for a capture in each. for
+    the magnetometer the sensor is turned slowly through every orientation while readings are
+    collected. finishing fits the coefficients, the part that was not calibrated is left as it was.
+
+    the routine sees the raw samples, the current calibration would skew the fit.
+*/
+
+/// samples averaged for a pose, at least a tenth of a second at the default data rate
+const CAPTURE_WINDOW: usize = 64;
+/// how far a sample of a still pose may be from the mean, milli g
+const STILL_TOLERANCE: f32 = 50.0;
+/// mag readings kept for the fit, the first ones are kept once it is full
+const MAX_MAG_SAMPLES: usize = 2000;
+/// fewer readings than this do not cover enough orientations
+const MIN_MAG_SAMPLES: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalibrationError {
+    /// the step needs a calibration to have been started
+    NotStarted,
+    /// the sensor moved during the capture
+    NotStill,
+    /// the sensor was not held with one axis vertical
+    UnknownPose,
+    /// not every pose was captured or too few mag readings were collected
+    Incomplete,
+    /// the readings do not fit, e.g. the sensor was only turned about one axis
+    FitFailed,
+}
+
+#[derive(Debug, Clone, Default)]
+enum Collecting {
+    #[default]
+    Nothing,
+    Accel(SixPoseCalibrator),
+    Mag(Vec<Vector3>),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CalibrationRoutine {
+    collecting: Collecting,
+    // the latest accel readings, for captures
+    recent: VecDeque<Vector3>,
+}
+
+impl CalibrationRoutine {
+    pub fn new() -> Self {
+        CalibrationRoutine::default()
+    }
+
+    pub fn is_running(&self) -> bool {
+        !matches!(self.collecting, Collecting::Nothing)
+    }
+
+    pub fn start_accel(&mut self) {
+        self.collecting = Collecting::Accel(SixPoseCalibrator::new());
+        self.recent.clear();
+    }
+
+    pub fn start_mag(&mut self) {
+        self.collecting = Collecting::Mag(Vec::new());
+    }
+
+    pub fn cancel(&mut self) {
+        self.collecting = Collecting::Nothing;
+    }
+
+    /// poses still to capture, empty unless calibrating the accelerometer
+    pub fn missing_poses(&self) -> Vec<Pose> {
+        match &self.collecting {
+            Collecting::Accel(calibrator) => calibrator.missing(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// mag readings collected, zero unless calibrating the magnetometer
+    pub fn mag_samples(&self) -> usize {
+        match &self.collecting {
+            Collecting::Mag(readings) => readings.len(),
+            _ => 0,
+        }
+    }
+
+    /// push takes a raw sample
+    pub fn push(&mut self, sample: &ImuSample) {
+        match &mut self.collecting {
+            Collecting::Nothing => {}
+            Collecting::Accel(_) => {
+                if self.recent.len() == CAPTURE_WINDOW {
+                    self.recent.pop_front();
+                }
+                self.recent.push_back(sample.accel);
+            }
+            // the mag runs slower than the accel so the same reading comes with several samples
+            Collecting::Mag(readings) => {
+                if readings.len() < MAX_MAG_SAMPLES && readings.last() != Some(&sample.mag) {
+                    readings.push(sample.mag);
+                }
+            }
+        }
+    }
+
+    /// captures the pose the sensor is being held still in from the latest samples
+    pub fn capture(&mut self) -> Result<Pose, CalibrationError> {
+        let Collecting::Accel(calibrator) = &mut self.collecting else {
+            return Err(CalibrationError::NotStarted);
+        };
+        if self.recent.len() < CAPTURE_WINDOW {
+            return Err(CalibrationError::NotStill);
+        }
+        let samples: Vec<Vector3> = self.recent.iter().copied().collect();
+        let mean = still_mean(&samples, STILL_TOLERANCE).ok_or(CalibrationError::NotStill)?;
+        calibrator.add(mean).ok_or(CalibrationError::UnknownPose)
+    }
+
+    /// fits what was collected into the current calibration and ends the routine
+    pub fn finish(&mut self, current: Calibration) -> Result<Calibration, CalibrationError> {
+        let calibration = match &self.collecting {
+            Collecting::Nothing => return Err(CalibrationError::NotStarted),
+            Collecting::Accel(calibrator) => Calibration {
+                accel: calibrator.solve().ok_or(CalibrationError::Incomplete)?,
+                ..current
+            },
+            Collecting::Mag(readings) if readings.len() < MIN_MAG_SAMPLES => {
+                return Err(CalibrationError::Incomplete)
+            }
+            Collecting::Mag(readings) => Calibration {
+                mag: fit_ellipsoid(readings).ok_or(CalibrationError::FitFailed)?,
+                ..current
+            },
+        };
+        self.collecting = Collecting::Nothing;
+        Ok(calibration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::calibration::AccelCalibration;
+
+    fn hold(routine: &mut CalibrationRoutine, accel: Vector3) {
+        for i in 0..CAPTURE_WINDOW as i64 {
+            routine.push(&ImuSample::new(i * 2500, accel, Vector3::default()));
+        }
+    }
+
+    #[test]
+    fn six_poses_calibrate_the_accelerometer() {
+        let mut routine = CalibrationRoutine::new();
+        routine.start_accel();
+        let offset = Vector3::new(20.0, -10.0, 30.0);
+
+        for g in [
+            Vector3::new(1000.0, 0.0, 0.0),
+            Vector3::new(-1000.0, 0.0, 0.0),
+            Vector3::new(0.0, 1000.0, 0.0),
+            Vector3::new(0.0, -1000.0, 0.0),
+            Vector3::new(0.0, 0.0, 1000.0),
+        ] {
+            hold(&mut routine, g + offset);
+            routine.capture().unwrap();
+        }
+        assert_eq!(routine.missing_poses(), vec![Pose::ZDown]);
+        assert_eq!(
+            routine.finish(Calibration::default()),
+            Err(CalibrationError::Incomplete)
+        );
+
+        hold(&mut routine, Vector3::new(0.0, 0.0, -1000.0) + offset);
+        assert_eq!(routine.capture(), Ok(Pose::ZDown));
+        let calibration = routine.finish(Calibration::default()).unwrap();
+
+        assert_eq!(
+            calibration.accel,
+            AccelCalibration {
+                offset,
+                ..Default::default()
+            }
+        );
+        assert!(!routine.is_running());
+    }
+
+    #[test]
+    fn moving_captures_are_rejected() {
+        let mut routine = CalibrationRoutine::new();
+        assert_eq!(routine.capture(), Err(CalibrationError::NotStarted));
+
+        routine.start_accel();
+        hold(&mut routine, Vector3::new(0.0, 0.0, 1000.0));
+        routine.push(&ImuSample::new(
+            0,
+            Vector3::new(300.0, 0.0, 900.0),
+            Vector3::default(),
+        ));
+        assert_eq!(routine.capture(), Err(CalibrationError::NotStill));
+
+        hold(&mut routine, Vector3::new(700.0, 0.0, 700.0));
+        assert_eq!(routine.capture(), Err(CalibrationError::UnknownPose));
+    }
+
+    #[test]
+    fn mag_readings_are_collected_once() {
+        let mut routine = CalibrationRoutine::new();
+        routine.start_mag();
+
+        for i in 0..40 {
+            let mag = Vector3::new((i / 4) as f32, 0.0, 0.0);
+            routine.push(&ImuSample::new(i * 2500, Vector3::default(), mag));
+        }
+
+        assert_eq!(routine.mag_samples(), 10);
+        assert_eq!(
+            routine.finish(Calibration::default()),
+            Err(CalibrationError::Incomplete)
+        );
+    }
+}
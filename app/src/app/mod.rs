@@ -1 +1,7 @@
-pub mod app;
\ No newline at end of file
+#[allow(clippy::module_inception)]
+pub mod app;
+pub mod calibration;
+pub mod session;
+pub mod session_log;
+pub mod settings;
+pub mod stream;
@@ -0,0 +1,158 @@
+/*
+    session state machine
+
+    Idle -> Armed -> Recording -> Summarising -> Idle
+
+    - a valid configuration from the phone arms the session, the app finishes a set being
+      recorded first so what was measured is still sent
+    - the start algorithim firing starts recording
+    - the end algorithim firing summarises the set, once the rep is sent we are idle again
+    - an invalid configuration is turned away by the app and leaves the session as it was
+    - a fault moves to Error, a valid configuration leaves it
+
+    the session only tracks state, the app decides when events happen. this keeps the transitions
+    deterministic so they can be driven from the firmware and from host tests alike.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionError {
+    /// the configuration has no start algorithim so a set could never start
+    NoStartAlgorithim,
+    /// the end algorithim would end a set before it started, a zero count or duration
+    InvalidEndCondition,
+    /// the configuration asks for a height model this firmware does not have
+    UnknownHeightModel,
+    /// the rep could not be serialised or written out
+    OutputFailed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SessionState {
+    #[default]
+    Idle,
+    Armed,
+    Recording,
+    Summarising,
+    Error(SessionError),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionEvent {
+    Configured,
+    StartTriggered,
+    EndConditionMet,
+    RepSent,
+    Fault(SessionError),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidTransition {
+    pub from: SessionState,
+    pub event: SessionEvent,
+}
+
+#[derive(Debug, Default)]
+pub struct Session {
+    state: SessionState,
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Session::default()
+    }
+
+    pub fn state(&self) -> SessionState {
+        self.state
+    }
+
+    /// apply an event, transitions that make no sense leave the state as it was
+    pub fn handle(&mut self, event: SessionEvent) -> Result<SessionState, InvalidTransition> {
+        use SessionEvent::*;
+        use SessionState::*;
+
+        let next = match (self.state, event) {
+            (Idle | Armed | Error(_), Configured) => Armed,
+            (_, Fault(err)) => Error(err),
+            (Armed, StartTriggered) => Recording,
+            (Recording, EndConditionMet) => Summarising,
+            (Summarising, RepSent) => Idle,
+            (from, event) => return Err(InvalidTransition { from, event }),
+        };
+
+        self.state = next;
+        Ok(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_set() {
+        let mut session = Session::new();
+
+        assert_eq!(
+            session.handle(SessionEvent::Configured),
+            Ok(SessionState::Armed)
+        );
+        assert_eq!(
+            session.handle(SessionEvent::StartTriggered),
+            Ok(SessionState::Recording)
+        );
+        assert_eq!(
+            session.handle(SessionEvent::EndConditionMet),
+            Ok(SessionState::Summarising)
+        );
+        assert_eq!(
+            session.handle(SessionEvent::RepSent),
+            Ok(SessionState::Idle)
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_order_events() {
+        let mut session = Session::new();
+
+        assert_eq!(
+            session.handle(SessionEvent::StartTriggered),
+            Err(InvalidTransition {
+                from: SessionState::Idle,
+                event: SessionEvent::StartTriggered
+            })
+        );
+        assert_eq!(session.state(), SessionState::Idle);
+    }
+
+    #[test]
+    fn error_is_left_by_configuring() {
+        let mut session = Session::new();
+        let err = SessionError::OutputFailed;
+
+        assert_eq!(
+            session.handle(SessionEvent::Fault(err)),
+            Ok(SessionState::Error(err))
+        );
+        assert!(session.handle(SessionEvent::StartTriggered).is_err());
+        assert_eq!(
+            session.handle(SessionEvent::Configured),
+            Ok(SessionState::Armed)
+        );
+    }
+
+    #[test]
+    fn reconfiguring_needs_the_set_finished() {
+        let mut session = Session::new();
+        session.handle(SessionEvent::Configured).unwrap();
+        session.handle(SessionEvent::StartTriggered).unwrap();
+
+        assert_eq!(
+            session.handle(SessionEvent::Configured),
+            Err(InvalidTransition {
+                from: SessionState::Recording,
+                event: SessionEvent::Configured
+            })
+        );
+        assert_eq!(session.state(), SessionState::Recording);
+    }
+}
@@ -0,0 +1,183 @@
+use protobuf::Message;
+
+use crate::rust_proto::algorithim::{self, ErrorCode};
+use crate::storage::{
+    flash::Flash,
+    ring_log::{LogError, RingLog},
+};
+
+/*
+    session log
+
+    athletes often train without the phone connected, so every finished set is also written to a
+    flash partition. the phone lists what is stored, downloads each session in chunks and erases
+    them once it has them all. the log is a ring, once the partition is full the oldest sessions are
+    overwritten.
+*/
+
+/// kind of the records holding a Rep
+pub const KIND_REP: u8 = 1;
+/// sessions per SessionList, an entry takes at most 14 bytes
+pub const MAX_LISTED: usize = 100;
+/// bytes per SessionChunk, leaves room in a frame for the rest of the message
+pub const CHUNK_SIZE: usize = 2048;
+
+pub struct SessionLog {
+    log: RingLog<Box<dyn Flash + Send>>,
+}
+
+impl SessionLog {
+    pub fn mount(flash: Box<dyn Flash + Send>) -> Result<Self, LogError> {
+        Ok(SessionLog {
+            log: RingLog::mount(flash)?,
+        })
+    }
+
+    /// stores a finished set, returns its id
+    pub fn record(&mut self, rep: &algorithim::Rep) -> Result<u32, ErrorCode> {
+        let bytes = rep
+            .write_to_bytes()
+            .map_err(|_| ErrorCode::STORAGE_FAILED)?;
+        self.log.append(KIND_REP, &bytes).map_err(storage_error)
+    }
+
+    pub fn list(
+        &mut self,
+        request: &algorithim::ListSessions,
+    ) -> Result<algorithim::SessionList, ErrorCode> {
+        let mut entries = self
+            .log
+            .entries(request.from, MAX_LISTED + 1)
+            .map_err(storage_error)?;
+
+        let mut list = algorithim::SessionList::new();
+        list.more = entries.len() > MAX_LISTED;
+        entries.truncate(MAX_LISTED);
+        list.sessions = entries
+            .iter()
+            .map(|entry| {
+                let mut session = algorithim::StoredSession::new();
+                session.id = entry.id;
+                session.size = entry.size as u32;
+                session
+            })
+            .collect();
+        Ok(list)
+    }
+
+    pub fn download(
+        &mut self,
+        request: &algorithim::DownloadSession,
+    ) -> Result<algorithim::SessionChunk, ErrorCode> {
+        let entry = self.log.find(request.id).map_err(storage_error)?;
+        let offset = request.offset as usize;
+        if offset > entry.size {
+            return Err(ErrorCode::INVALID_ARGUMENT);
+        }
+
+        let mut data = vec![0u8; CHUNK_SIZE.min(entry.size - offset)];
+        self.log
+            .read(&entry, offset, &mut data)
+            .map_err(storage_error)?;
+
+        let mut chunk = algorithim::SessionChunk::new();
+        chunk.id = entry.id;
+        chunk.offset = request.offset;
+        chunk.size = entry.size as u32;
+        chunk.data = data;
+        Ok(chunk)
+    }
+
+    pub fn erase(&mut self) -> Result<(), ErrorCode> {
+        self.log.erase().map_err(storage_error)
+    }
+}
+
+// storage_error is the code the phone gets for a log error
+fn storage_error(err: LogError) -> ErrorCode {
+    match err {
+        LogError::NotFound => ErrorCode::SESSION_NOT_FOUND,
+        err => {
+            println!("session log error {:?}", err);
+            ErrorCode::STORAGE_FAILED
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::flash::MemoryFlash;
+
+    fn rep(heights: &[u32]) -> algorithim::Rep {
+        let mut bouncing = algorithim::BouningRep::new();
+        for height in heights {
+            let mut measurement = algorithim::BounceMeasurment::new();
+            measurement.height = *height;
+            bouncing.measurements.push(measurement);
+        }
+        let mut rep = algorithim::Rep::new();
+        rep.set_bouningRep(bouncing);
+        rep
+    }
+
+    fn log() -> SessionLog {
+        SessionLog::mount(Box::new(MemoryFlash::new(4))).unwrap()
+    }
+
+    #[test]
+    fn lists_in_pages() {
+        let mut log = log();
+        for i in 0..MAX_LISTED as u32 + 5 {
+            log.record(&rep(&[i])).unwrap();
+        }
+
+        let mut request = algorithim::ListSessions::new();
+        let first = log.list(&request).unwrap();
+        request.from = first.sessions.last().unwrap().id + 1;
+        let second = log.list(&request).unwrap();
+
+        assert_eq!(first.sessions.len(), MAX_LISTED);
+        assert!(first.more);
+        assert_eq!(second.sessions.len(), 5);
+        assert!(!second.more);
+        assert!(
+            first.write_to_bytes().unwrap().len() <= crate::transport::framing::MAX_PAYLOAD_SIZE
+        );
+    }
+
+    #[test]
+    fn downloads_in_chunks() {
+        let mut log = log();
+        // big enough to take two chunks
+        let stored = rep(&[250; 700]);
+        let id = log.record(&stored).unwrap();
+
+        let mut request = algorithim::DownloadSession::new();
+        request.id = id;
+        let mut bytes = Vec::new();
+        loop {
+            let chunk = log.download(&request).unwrap();
+            assert_eq!(chunk.offset as usize, bytes.len());
+            bytes.extend_from_slice(&chunk.data);
+            if bytes.len() == chunk.size as usize {
+                break;
+            }
+            request.offset = bytes.len() as u32;
+        }
+
+        assert!(bytes.len() > CHUNK_SIZE);
+        assert_eq!(algorithim::Rep::parse_from_bytes(&bytes).unwrap(), stored);
+    }
+
+    #[test]
+    fn unknown_sessions() {
+        let mut log = log();
+        log.record(&rep(&[1])).unwrap();
+        log.erase().unwrap();
+
+        let mut request = algorithim::DownloadSession::new();
+        request.id = 0;
+        assert_eq!(log.download(&request), Err(ErrorCode::SESSION_NOT_FOUND));
+    }
+}
@@ -0,0 +1,79 @@
+use protobuf::Message;
+
+use crate::rust_proto::algorithim;
+
+/*
+    saved settings
+
+    the algorithim and sensor configurations the phone sent last are kept as a Settings message
+    behind a version byte, so a reboot (e.g. the one after an ota update) carries on with the
+    athlete's setup. protobuf copes with fields being added, the version only goes up for changes
+    it can not cope with, e.g. a field changing meaning, and decode is where an older version would
+    be migrated.
+*/
+
+/// key of the settings in the settings store
+pub const SETTINGS_KEY: &str = "settings";
+/// key of the calibration in the settings store, see Calibration::to_bytes for the format
+pub const CALIBRATION_KEY: &str = "calibration";
+
+pub const SETTINGS_VERSION: u8 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingsError {
+    Empty,
+    /// saved by firmware that knows a newer format
+    UnsupportedVersion(u8),
+    Parse(String),
+}
+
+/// [version] ++ settings
+pub fn encode(settings: &algorithim::Settings) -> Result<Vec<u8>, protobuf::Error> {
+    let mut bytes = vec![SETTINGS_VERSION];
+    settings.write_to_vec(&mut bytes)?;
+    Ok(bytes)
+}
+
+pub fn decode(bytes: &[u8]) -> Result<algorithim::Settings, SettingsError> {
+    match bytes.split_first() {
+        None => Err(SettingsError::Empty),
+        Some((&SETTINGS_VERSION, message)) => algorithim::Settings::parse_from_bytes(message)
+            .map_err(|err| SettingsError::Parse(err.to_string())),
+        Some((version, _)) => Err(SettingsError::UnsupportedVersion(*version)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut settings = algorithim::Settings::new();
+        let mut counted = algorithim::Counted::new();
+        counted.counts = 5;
+        settings
+            .algorithim
+            .mut_or_insert_default()
+            .set_counted(counted);
+        settings.sensor.mut_or_insert_default().accelOdr = 200;
+
+        let bytes = encode(&settings).unwrap();
+
+        assert_eq!(bytes[0], SETTINGS_VERSION);
+        assert_eq!(decode(&bytes), Ok(settings));
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        assert_eq!(decode(&[]), Err(SettingsError::Empty));
+        assert_eq!(
+            decode(&[SETTINGS_VERSION + 1, 0x0a, 0x00]),
+            Err(SettingsError::UnsupportedVersion(SETTINGS_VERSION + 1))
+        );
+        assert!(matches!(
+            decode(&[SETTINGS_VERSION, 0xff]),
+            Err(SettingsError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::domain::sample::{ImuSample, Vector3};
+use crate::rust_proto::algorithim::{self, ErrorCode};
+
+/*
+    raw sample streaming
+
+    for algorithim development the phone can ask for the samples exactly as the driver delivers
+    them. samples are decimated and packed into SampleBatch messages, which go out over byte out
+    like every other message. a batch is sent once it is full or when the stream is turned off.
+*/
+
+/// samples per batch when the phone does not ask for a size, a quarter of a second at 100 Hz
+pub const DEFAULT_BATCH_SIZE: usize = 25;
+/// largest batch that still fits in a frame, a sample with mag takes at most 35 bytes
+pub const MAX_BATCH_SIZE: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamConfig {
+    /// every nth sample is sent
+    pub decimation: u32,
+    pub batch_size: usize,
+    pub include_mag: bool,
+}
+
+impl StreamConfig {
+    /// None when the message turns streaming off
+    pub fn from_message(message: &algorithim::RawStream) -> Result<Option<Self>, ErrorCode> {
+        if !message.enabled {
+            return Ok(None);
+        }
+        let batch_size = match message.batchSize as usize {
+            0 => DEFAULT_BATCH_SIZE,
+            size if size > MAX_BATCH_SIZE => return Err(ErrorCode::INVALID_ARGUMENT),
+            size => size,
+        };
+        Ok(Some(StreamConfig {
+            decimation: message.decimation.max(1),
+            batch_size,
+            include_mag: message.includeMag,
+        }))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SampleStreamer {
+    config: Option<StreamConfig>,
+    // samples seen since the last one that was kept
+    skipped: u32,
+    batch: Vec<ImuSample>,
+    sequence: u32,
+}
+
+impl SampleStreamer {
+    pub fn new() -> Self {
+        SampleStreamer::default()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.is_some()
+    }
+
+    /// starts, changes or with None stops the stream, returns whatever was still batched up
+    pub fn configure(&mut self, config: Option<StreamConfig>) -> Option<algorithim::SampleBatch> {
+        let remaining = self.flush();
+        self.config = config;
+        self.skipped = 0;
+        remaining
+    }
+
+    /// returns a batch when this sample filled one
+    pub fn push(&mut self, sample: ImuSample) -> Option<algorithim::SampleBatch> {
+        let config = self.config?;
+
+        if self.skipped > 0 {
+            self.skipped = (self.skipped + 1) % config.decimation;
+            return None;
+        }
+        self.skipped = 1 % config.decimation;
+
+        self.batch.push(sample);
+        if self.batch.len() >= config.batch_size {
+            return self.flush();
+        }
+        None
+    }
+
+    /// the samples batched up so far, if any
+    pub fn flush(&mut self) -> Option<algorithim::SampleBatch> {
+        if self.batch.is_empty() {
+            return None;
+        }
+        let include_mag = self.config.is_some_and(|c| c.include_mag);
+
+        let mut batch = algorithim::SampleBatch::new();
+        batch.sequence = self.sequence;
+        batch.startTimestamp = self.batch[0].timestamp;
+        let mut previous = batch.startTimestamp;
+        for sample in self.batch.drain(..) {
+            batch
+                .timestampDeltas
+                .push((sample.timestamp - previous).max(0) as u32);
+            previous = sample.timestamp;
+            batch.accel.extend(components(sample.accel));
+            if include_mag {
+                batch.mag.extend(components(sample.mag));
+            }
+        }
+        self.sequence = self.sequence.wrapping_add(1);
+        Some(batch)
+    }
+}
+
+fn components(v: Vector3) -> [i32; 3] {
+    [v.x.round() as i32, v.y.round() as i32, v.z.round() as i32]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::Message;
+
+    fn sample(millis: i64) -> ImuSample {
+        ImuSample::new(
+            millis * 1000,
+            Vector3::new(1.0, -2.0, millis as f32),
+            Vector3::new(300.0, 0.0, -300.0),
+        )
+    }
+
+    fn config(decimation: u32, batch_size: usize, include_mag: bool) -> Option<StreamConfig> {
+        Some(StreamConfig {
+            decimation,
+            batch_size,
+            include_mag,
+        })
+    }
+
+    #[test]
+    fn batches_samples() {
+        let mut streamer = SampleStreamer::new();
+        streamer.configure(config(1, 3, false));
+
+        let batches: Vec<_> = (0..7)
+            .filter_map(|i| streamer.push(sample(i * 10)))
+            .collect();
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[1].sequence, 1);
+        assert_eq!(batches[1].startTimestamp, 30_000);
+        assert_eq!(batches[1].timestampDeltas, vec![0, 10_000, 10_000]);
+        assert_eq!(batches[1].accel, vec![1, -2, 30, 1, -2, 40, 1, -2, 50]);
+        assert!(batches[1].mag.is_empty());
+    }
+
+    #[test]
+    fn decimates() {
+        let mut streamer = SampleStreamer::new();
+        streamer.configure(config(3, 2, true));
+
+        let batches: Vec<_> = (0..6).filter_map(|i| streamer.push(sample(i))).collect();
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].timestampDeltas, vec![0, 3000]);
+        assert_eq!(batches[0].mag, vec![300, 0, -300, 300, 0, -300]);
+    }
+
+    #[test]
+    fn stopping_flushes() {
+        let mut streamer = SampleStreamer::new();
+        streamer.configure(config(1, 10, false));
+        streamer.push(sample(0));
+
+        let remaining = streamer.configure(None).unwrap();
+
+        assert_eq!(remaining.timestampDeltas.len(), 1);
+        assert!(!streamer.is_enabled());
+        assert_eq!(streamer.push(sample(10)), None);
+    }
+
+    #[test]
+    fn largest_batch_fits_a_frame() {
+        let mut streamer = SampleStreamer::new();
+        streamer.configure(config(1, MAX_BATCH_SIZE, true));
+        let extreme = Vector3::new(-16000.0, 16000.0, -16000.0);
+
+        let batch = (0..MAX_BATCH_SIZE as i64)
+            .find_map(|i| streamer.push(ImuSample::new(i * 2_500_000, extreme, extreme * 300.0)))
+            .unwrap();
+
+        let size = batch.write_to_bytes().unwrap().len();
+        assert!(
+            size <= crate::transport::framing::MAX_PAYLOAD_SIZE,
+            "{}",
+            size
+        );
+    }
+
+    #[test]
+    fn validates_messages() {
+        let mut message = algorithim::RawStream::new();
+        assert_eq!(StreamConfig::from_message(&message), Ok(None));
+
+        message.enabled = true;
+        assert_eq!(
+            StreamConfig::from_message(&message),
+            Ok(config(1, DEFAULT_BATCH_SIZE, false))
+        );
+
+        message.batchSize = MAX_BATCH_SIZE as u32 + 1;
+        assert_eq!(
+            StreamConfig::from_message(&message),
+            Err(ErrorCode::INVALID_ARGUMENT)
+        );
+    }
+}
@@ -0,0 +1,51 @@
+pub use app_algorithims::calibration::{AccelCalibration, Calibration, MagCalibration, Pose};
+
+use super::sample::ImuSample;
+
+/*
+    calibration coefficients
+
+    the coefficients are fitted once by the calibration routine and kept in the settings store,
+    see Calibration::to_bytes for the format. every sample is corrected with them before the
+    algorithims see it.
+*/
+
+/// the sample corrected with the calibration
+pub fn calibrate(calibration: &Calibration, sample: ImuSample) -> ImuSample {
+    ImuSample {
+        timestamp: sample.timestamp,
+        accel: calibration.accel.apply(sample.accel),
+        mag: calibration.mag.apply(sample.mag),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::sample::Vector3;
+
+    #[test]
+    fn corrects_samples() {
+        let calibration = Calibration {
+            accel: AccelCalibration {
+                offset: Vector3::new(10.0, 0.0, -20.0),
+                scale: Vector3::new(1.0, 1.0, 0.5),
+            },
+            mag: MagCalibration {
+                offset: Vector3::new(100.0, 100.0, 0.0),
+                ..Default::default()
+            },
+        };
+        let sample = ImuSample::new(
+            5,
+            Vector3::new(10.0, 3.0, 1980.0),
+            Vector3::new(100.0, 0.0, 0.0),
+        );
+
+        let corrected = calibrate(&calibration, sample);
+
+        assert_eq!(corrected.timestamp, 5);
+        assert_eq!(corrected.accel, Vector3::new(0.0, 3.0, 1000.0));
+        assert_eq!(corrected.mag, Vector3::new(0.0, -100.0, 0.0));
+    }
+}
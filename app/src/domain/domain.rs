@@ -1,8 +1,6 @@
-use std::io::{Write, Read};
-
-
+use std::io::{Read, Write};
 
 pub trait ReadWrite: Write + Read {}
 
 // blanket implementation:
-impl<T: Write + Read> ReadWrite for T {}
\ No newline at end of file
+impl<T: Write + Read> ReadWrite for T {}
@@ -0,0 +1,224 @@
+use super::sample::Vector3;
+
+/*
+    accelerometer fifo
+
+    the lsm303agr buffers up to 32 accel samples in its fifo and raises an interrupt once a
+    watermark is reached, the firmware then reads them all in one go. the samples do not carry a
+    time so it is reconstructed from the output data rate. the internal oscillator is only accurate
+    to a few percent, so OdrClock locks on to the real rate from when the fifo was read, the same
+    way a pll would.
+*/
+
+pub const FIFO_SIZE: usize = 32;
+/// bytes per sample in the fifo, x y z as little endian i16
+pub const FIFO_SAMPLE_SIZE: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AccelMode {
+    /// 8 bit
+    LowPower,
+    /// 10 bit
+    Normal,
+    /// 12 bit
+    #[default]
+    HighResolution,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AccelScale {
+    G2,
+    G4,
+    G8,
+    #[default]
+    G16,
+}
+
+impl AccelScale {
+    /// milli g per digit of the left justified output, from the datasheet
+    pub fn sensitivity(&self, mode: AccelMode) -> f32 {
+        use AccelMode::*;
+        use AccelScale::*;
+        match (mode, self) {
+            (HighResolution, G2) => 0.98,
+            (HighResolution, G4) => 1.95,
+            (HighResolution, G8) => 3.9,
+            (HighResolution, G16) => 11.72,
+            (Normal, G2) => 3.9,
+            (Normal, G4) => 7.82,
+            (Normal, G8) => 15.63,
+            (Normal, G16) => 46.9,
+            (LowPower, G2) => 15.63,
+            (LowPower, G4) => 31.26,
+            (LowPower, G8) => 62.52,
+            (LowPower, G16) => 187.58,
+        }
+    }
+}
+
+/// accel readings in milli g from the bytes read out of the fifo, a trailing partial sample is
+/// ignored
+pub fn decode_accel(bytes: &[u8], scale: AccelScale, mode: AccelMode) -> Vec<Vector3> {
+    let shift = match mode {
+        AccelMode::LowPower => 8,
+        AccelMode::Normal => 6,
+        AccelMode::HighResolution => 4,
+    };
+    let sensitivity = scale.sensitivity(mode);
+    let axis = |low: u8, high: u8| (i16::from_le_bytes([low, high]) >> shift) as f32 * sensitivity;
+
+    bytes
+        .chunks_exact(FIFO_SAMPLE_SIZE)
+        .map(|b| Vector3::new(axis(b[0], b[1]), axis(b[2], b[3]), axis(b[4], b[5])))
+        .collect()
+}
+
+// how much of the timing error is corrected per read
+const PHASE_GAIN: f64 = 0.1;
+const PERIOD_GAIN: f64 = 0.01;
+// errors bigger than this many periods mean samples were lost, e.g. the fifo overran
+const RESYNC_PERIODS: f64 = 4.0;
+// the rate is only corrected to within 10% of nominal, anything further off is not the oscillator.
+// this is a margin over the few percent it is accurate to, not a figure from the datasheet
+const MAX_PERIOD_ERROR: f64 = 0.10;
+
+/// OdrClock timestamps the samples read out of the fifo
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OdrClock {
+    nominal_period: f64,
+    period: f64,
+    // time of the newest sample handed out
+    last: Option<f64>,
+}
+
+impl OdrClock {
+    pub fn new(odr_hz: u32) -> Self {
+        let nominal_period = 1_000_000.0 / odr_hz.max(1) as f64;
+        OdrClock {
+            nominal_period,
+            period: nominal_period,
+            last: None,
+        }
+    }
+
+    /// forget the phase, the next read starts a new run of timestamps. the period estimate is kept
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+
+    /// estimated sample period in micro seconds
+    pub fn period(&self) -> f64 {
+        self.period
+    }
+
+    /// timestamps in micro seconds for count samples read out of the fifo at read_time, oldest
+    /// first. the newest of them was sampled some time in the period before the fifo was read.
+    pub fn timestamps(&mut self, count: usize, read_time: i64) -> Vec<i64> {
+        if count == 0 {
+            return Vec::new();
+        }
+        let observed = read_time as f64 - self.period / 2.0;
+
+        let newest = match self.last {
+            Some(last) => {
+                let expected = last + count as f64 * self.period;
+                let error = observed - expected;
+                if error.abs() > RESYNC_PERIODS * self.period {
+                    observed
+                } else {
+                    let period = self.period + error / count as f64 * PERIOD_GAIN;
+                    self.period = period.clamp(
+                        self.nominal_period * (1.0 - MAX_PERIOD_ERROR),
+                        self.nominal_period * (1.0 + MAX_PERIOD_ERROR),
+                    );
+                    expected + error * PHASE_GAIN
+                }
+            }
+            None => observed,
+        };
+        self.last = Some(newest);
+
+        (0..count)
+            .map(|i| (newest - (count - 1 - i) as f64 * self.period).round() as i64)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_fifo_bytes() {
+        // 1000 mg on z in high resolution at 2 g, -500 mg on x
+        let x = ((-500.0f32 / 0.98).round() as i16) << 4;
+        let z = ((1000.0f32 / 0.98).round() as i16) << 4;
+        let mut bytes = Vec::new();
+        for value in [x, 0, z] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes.extend_from_slice(&[1, 2, 3]);
+
+        let samples = decode_accel(&bytes, AccelScale::G2, AccelMode::HighResolution);
+
+        assert_eq!(samples.len(), 1);
+        assert!((samples[0].x + 500.0).abs() < 1.0);
+        assert_eq!(samples[0].y, 0.0);
+        assert!((samples[0].z - 1000.0).abs() < 1.0);
+    }
+
+    // fifo reads of a sensor running 3% slow at 400 Hz, returns (samples read, read time) and the
+    // true time of every sample
+    fn reads() -> (Vec<(usize, i64)>, Vec<i64>) {
+        let period = 2500.0 * 1.03;
+        let mut seed: u32 = 1;
+        let mut random = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) as f64 / 65536.0
+        };
+
+        let mut reads = Vec::new();
+        let mut truth = Vec::new();
+        let mut next = 0;
+        for _ in 0..200 {
+            // watermark of 16, read somewhere up to a sample and a bit later
+            let read_time = (next + 15) as f64 * period + random() * period * 1.5;
+            let count = (read_time / period) as usize + 1 - next;
+            truth.extend((next..next + count).map(|i| (i as f64 * period) as i64));
+            reads.push((count, read_time as i64));
+            next += count;
+        }
+        (reads, truth)
+    }
+
+    #[test]
+    fn locks_on_to_the_real_rate() {
+        let (reads, truth) = reads();
+        let mut clock = OdrClock::new(400);
+
+        let timestamps: Vec<i64> = reads
+            .iter()
+            .flat_map(|(count, read_time)| clock.timestamps(*count, *read_time))
+            .collect();
+
+        assert!((clock.period() - 2575.0).abs() < 10.0, "{}", clock.period());
+        // once locked the timestamps are within half a period of the truth
+        let settled = timestamps.len() / 2;
+        for (t, truth) in timestamps[settled..].iter().zip(&truth[settled..]) {
+            assert!((t - truth).abs() < 1300, "{} {}", t, truth);
+        }
+        // and always increasing
+        assert!(timestamps.windows(2).all(|w| w[1] > w[0]));
+    }
+
+    #[test]
+    fn resyncs_after_a_gap() {
+        let mut clock = OdrClock::new(100);
+        clock.timestamps(10, 100_000);
+
+        let timestamps = clock.timestamps(10, 1_000_000);
+
+        assert_eq!(timestamps[9], 995_000);
+        assert_eq!(timestamps[0], 905_000);
+    }
+}
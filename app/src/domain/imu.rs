@@ -0,0 +1,268 @@
+use std::{
+    collections::VecDeque,
+    convert::Infallible,
+    fmt::Debug,
+    sync::mpsc::{Receiver, SyncSender, TrySendError},
+};
+
+use app_algorithims::{
+    capture::{Capture, CaptureError},
+    sample::{Vector3, ONE_G},
+};
+
+use super::{sample::ImuSample, sensor::SensorConfig};
+
+/*
+    imu sources
+
+    ImuSource is how samples get into the app. the firmware implements it for the real sensor, the
+    replay and synthetic sources here let the whole sample -> algorithim -> rep pipeline run on the
+    host without an esp32.
+*/
+
+pub trait ImuSource {
+    type Error: Debug;
+
+    /// the next sample, blocking until there is one. None once the source has run out
+    fn next_sample(&mut self) -> Result<Option<ImuSample>, Self::Error>;
+
+    /// applies a validated configuration, sources that do not read hardware ignore it
+    fn configure(&mut self, _config: SensorConfig) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// forward reads a source until it runs out and hands the samples to the app. a sample is dropped
+/// when the app is behind, the source is never blocked as it is usually reading hardware.
+/// configurations from the app are applied between samples
+pub fn forward<S: ImuSource>(
+    source: &mut S,
+    sender: &SyncSender<ImuSample>,
+    configurations: &Receiver<SensorConfig>,
+) -> Result<usize, S::Error> {
+    let mut dropped = 0;
+    loop {
+        for config in configurations.try_iter() {
+            source.configure(config)?;
+        }
+        let Some(sample) = source.next_sample()? else {
+            break;
+        };
+        match sender.try_send(sample) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => dropped += 1,
+            // nobody is listening any more
+            Err(TrySendError::Disconnected(_)) => break,
+        }
+    }
+    Ok(dropped)
+}
+
+/// ReplaySource plays back a recorded session
+#[derive(Debug, Clone, Default)]
+pub struct ReplaySource {
+    samples: VecDeque<ImuSample>,
+}
+
+impl ReplaySource {
+    pub fn new(samples: Vec<ImuSample>) -> Self {
+        ReplaySource {
+            samples: samples.into(),
+        }
+    }
+
+    pub fn from_capture(capture: &Capture) -> Self {
+        let samples = capture
+            .samples
+            .iter()
+            .map(|s| ImuSample::new(s.timestamp, s.accel, s.mag.unwrap_or_default()))
+            .collect();
+        ReplaySource::new(samples)
+    }
+
+    /// a capture in its csv form, see app_algorithims::capture
+    pub fn from_csv(csv: &str) -> Result<Self, CaptureError> {
+        Ok(ReplaySource::from_capture(&Capture::from_csv(csv)?))
+    }
+
+    /// a capture in its binary form, see app_algorithims::capture
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CaptureError> {
+        Ok(ReplaySource::from_capture(&Capture::from_bytes(bytes)?))
+    }
+
+    /// samples left to play back
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+impl ImuSource for ReplaySource {
+    type Error = Infallible;
+
+    fn next_sample(&mut self) -> Result<Option<ImuSample>, Infallible> {
+        Ok(self.samples.pop_front())
+    }
+}
+
+/*
+    SyntheticSource generates a trace out of segments of constant acceleration, sampled at a fixed
+    rate. the jumps follow a rigid body model: free fall in the air and a constant force on the
+    ground that turns the landing velocity around into the takeoff velocity.
+*/
+#[derive(Debug, Clone)]
+pub struct SyntheticSource {
+    sample_period: i64,
+    timestamp: i64,
+    // acceleration and the time in micro seconds the segment ends at
+    segments: VecDeque<(Vector3, i64)>,
+    end: i64,
+    mag: Vector3,
+}
+
+impl SyntheticSource {
+    /// sample period in micro seconds, the first sample is at time 0
+    pub fn new(sample_period: i64) -> Self {
+        SyntheticSource {
+            sample_period,
+            timestamp: 0,
+            segments: VecDeque::new(),
+            end: 0,
+            mag: Vector3::default(),
+        }
+    }
+
+    /// the magnetometer reading sent with every sample
+    pub fn with_mag(mut self, mag: Vector3) -> Self {
+        self.mag = mag;
+        self
+    }
+
+    /// constant acceleration in milli g along z for duration micro seconds
+    pub fn constant(mut self, accel: f32, duration: i64) -> Self {
+        self.end += duration;
+        self.segments
+            .push_back((Vector3::new(0.0, 0.0, accel), self.end));
+        self
+    }
+
+    /// standing still
+    pub fn stand(self, duration: i64) -> Self {
+        self.constant(ONE_G, duration)
+    }
+
+    /// a counter movement jump followed by rebound jumps, jumps in total, all with the same flight
+    /// and ground contact time in micro seconds. the athlete stands still after the last landing
+    pub fn rebound_jumps(mut self, jumps: u32, flight_time: i64, contact_time: i64) -> Self {
+        if jumps == 0 {
+            return self;
+        }
+        let ratio = flight_time as f32 / contact_time as f32;
+        // pushing off from standing only has to reach the takeoff velocity
+        self = self.constant(ONE_G * (1.0 + ratio / 2.0), contact_time);
+        for jump in 0..jumps {
+            self = self.constant(0.0, flight_time);
+            if jump + 1 < jumps {
+                // landing velocity to takeoff velocity
+                self = self.constant(ONE_G * (1.0 + ratio), contact_time);
+            }
+        }
+        // sticking the landing
+        self.constant(ONE_G * (1.0 + ratio / 2.0), contact_time)
+    }
+}
+
+impl ImuSource for SyntheticSource {
+    type Error = Infallible;
+
+    fn next_sample(&mut self) -> Result<Option<ImuSample>, Infallible> {
+        while let Some((accel, end)) = self.segments.front() {
+            if self.timestamp < *end {
+                let sample = ImuSample::new(self.timestamp, *accel, self.mag);
+                self.timestamp += self.sample_period;
+                return Ok(Some(sample));
+            }
+            self.segments.pop_front();
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::{channel, sync_channel};
+
+    fn drain<S: ImuSource>(source: &mut S) -> Vec<ImuSample> {
+        std::iter::from_fn(|| source.next_sample().unwrap()).collect()
+    }
+
+    #[test]
+    fn replays_csv() {
+        let csv = "# a comment\n\
+                   # timestamp_us,accel_x_mg,accel_y_mg,accel_z_mg,mag_x,mag_y,mag_z\n\
+                   0,1,2,1000\n\
+                   10000, 3, 4, 980, 200, -10, 30\n";
+
+        let samples = drain(&mut ReplaySource::from_csv(csv).unwrap());
+
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].accel, Vector3::new(1.0, 2.0, 1000.0));
+        assert_eq!(samples[0].mag, Vector3::default());
+        assert_eq!(samples[1].timestamp, 10_000);
+        assert_eq!(samples[1].mag, Vector3::new(200.0, -10.0, 30.0));
+    }
+
+    #[test]
+    fn replays_binary() {
+        let capture = Capture::from_csv("0,1,2,1000,5,6,7\n").unwrap();
+
+        let samples = drain(&mut ReplaySource::from_bytes(&capture.to_bytes()).unwrap());
+
+        assert_eq!(samples[0].mag, Vector3::new(5.0, 6.0, 7.0));
+        assert_eq!(
+            ReplaySource::from_csv("0,1,2\n").unwrap_err(),
+            CaptureError::InvalidLine { line: 1 }
+        );
+    }
+
+    #[test]
+    fn synthetic_segments() {
+        let mut source = SyntheticSource::new(10_000)
+            .stand(30_000)
+            .constant(0.0, 20_000);
+
+        let samples = drain(&mut source);
+
+        let z: Vec<f32> = samples.iter().map(|s| s.accel.z).collect();
+        assert_eq!(z, vec![ONE_G, ONE_G, ONE_G, 0.0, 0.0]);
+        assert_eq!(samples[4].timestamp, 40_000);
+    }
+
+    #[test]
+    fn rebound_jumps_are_balanced() {
+        // over a whole session the athlete ends up where they started, so the mean is 1 g
+        let mut source = SyntheticSource::new(1_000)
+            .stand(500_000)
+            .rebound_jumps(3, 500_000, 200_000)
+            .stand(500_000);
+
+        let samples = drain(&mut source);
+
+        let mean = samples.iter().map(|s| s.accel.z).sum::<f32>() / samples.len() as f32;
+        assert!((mean - ONE_G).abs() < 1.0);
+    }
+
+    #[test]
+    fn forward_drops_when_full() {
+        let (sender, receiver) = sync_channel(2);
+        let (_, configurations) = channel();
+        let mut source = SyntheticSource::new(10_000).stand(50_000);
+
+        assert_eq!(forward(&mut source, &sender, &configurations), Ok(3));
+        assert_eq!(receiver.try_iter().count(), 2);
+    }
+}
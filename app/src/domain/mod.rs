@@ -1 +1,8 @@
-pub mod domain;
\ No newline at end of file
+pub mod calibration;
+#[allow(clippy::module_inception)]
+pub mod domain;
+pub mod fifo;
+pub mod imu;
+pub mod sample;
+pub mod sensor;
+pub mod settings;
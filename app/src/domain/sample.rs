@@ -0,0 +1,24 @@
+pub use app_algorithims::sample::{AccelSample, Vector3};
+
+/// a reading from the imu, accel is in milli g and mag is as reported by the magnetometer driver.
+/// the timestamp is in micro seconds from an arbitrary but fixed point, e.g. boot.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ImuSample {
+    pub timestamp: i64,
+    pub accel: Vector3,
+    pub mag: Vector3,
+}
+
+impl ImuSample {
+    pub fn new(timestamp: i64, accel: Vector3, mag: Vector3) -> Self {
+        ImuSample {
+            timestamp,
+            accel,
+            mag,
+        }
+    }
+
+    pub fn accel_sample(&self) -> AccelSample {
+        AccelSample::new(self.timestamp, self.accel)
+    }
+}
@@ -0,0 +1,98 @@
+use super::fifo::{AccelMode, AccelScale};
+
+/*
+    sensor configuration
+
+    the output data rates, range and operating mode the imu runs at. the phone can change them at
+    runtime, the app validates them and hands them to the driver which applies them between reads.
+*/
+
+/// accel output data rates in Hz. the 1620 and 5376 Hz low power rates are left out, the app can
+/// not keep up with them
+const ACCEL_ODRS: [u32; 8] = [1, 10, 25, 50, 100, 200, 400, 1344];
+/// only available in normal and high resolution mode
+const ACCEL_ODR_1344: u32 = 1344;
+/// mag output data rates in Hz
+const MAG_ODRS: [u32; 4] = [10, 20, 50, 100];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SensorConfigError {
+    /// the accelerometer can not run at this rate in this mode
+    UnsupportedAccelOdr,
+    UnsupportedMagOdr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SensorConfig {
+    /// Hz
+    pub accel_odr: u32,
+    /// Hz
+    pub mag_odr: u32,
+    pub scale: AccelScale,
+    pub mode: AccelMode,
+}
+
+impl Default for SensorConfig {
+    fn default() -> Self {
+        SensorConfig {
+            accel_odr: 400,
+            mag_odr: 100,
+            // landings from a drop jump go well over 8 g
+            scale: AccelScale::G16,
+            mode: AccelMode::HighResolution,
+        }
+    }
+}
+
+impl SensorConfig {
+    pub fn validate(&self) -> Result<(), SensorConfigError> {
+        if !ACCEL_ODRS.contains(&self.accel_odr)
+            || (self.accel_odr == ACCEL_ODR_1344 && self.mode == AccelMode::LowPower)
+        {
+            return Err(SensorConfigError::UnsupportedAccelOdr);
+        }
+        if !MAG_ODRS.contains(&self.mag_odr) {
+            return Err(SensorConfigError::UnsupportedMagOdr);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(accel_odr: u32, mag_odr: u32, mode: AccelMode) -> SensorConfig {
+        SensorConfig {
+            accel_odr,
+            mag_odr,
+            mode,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(SensorConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_unsupported_combinations() {
+        assert_eq!(
+            config(1344, 100, AccelMode::HighResolution).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            config(1344, 100, AccelMode::LowPower).validate(),
+            Err(SensorConfigError::UnsupportedAccelOdr)
+        );
+        assert_eq!(
+            config(300, 100, AccelMode::Normal).validate(),
+            Err(SensorConfigError::UnsupportedAccelOdr)
+        );
+        assert_eq!(
+            config(100, 40, AccelMode::Normal).validate(),
+            Err(SensorConfigError::UnsupportedMagOdr)
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/*
+    settings storage
+
+    everything the sensor keeps across restarts, the athlete's configuration, the sensor
+    configuration and the calibration, is saved as a blob under a key. the app decides what goes in
+    the blobs, the store only keeps them. on the esp32 they live in the nvs partition.
+*/
+
+/// keys are at most 15 characters, the longest nvs allows
+pub const MAX_KEY_LENGTH: usize = 15;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoreError(pub String);
+
+/// SettingsStore keeps blobs across restarts
+pub trait SettingsStore {
+    /// None when nothing was saved under the key yet
+    fn load(&mut self, key: &str) -> Result<Option<Vec<u8>>, StoreError>;
+    fn save(&mut self, key: &str, value: &[u8]) -> Result<(), StoreError>;
+}
+
+/// keeps the blobs for as long as it lives, for tests and running without hardware. clones share
+/// the blobs, so a clone kept back sees what an app saved, the same way a restart would
+#[derive(Debug, Clone, Default)]
+pub struct MemorySettingsStore {
+    values: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+}
+
+impl MemorySettingsStore {
+    pub fn new() -> Self {
+        MemorySettingsStore::default()
+    }
+}
+
+impl SettingsStore for MemorySettingsStore {
+    fn load(&mut self, key: &str) -> Result<Option<Vec<u8>>, StoreError> {
+        Ok(self.values.lock().unwrap().get(key).cloned())
+    }
+
+    fn save(&mut self, key: &str, value: &[u8]) -> Result<(), StoreError> {
+        if key.len() > MAX_KEY_LENGTH {
+            return Err(StoreError(format!("key {} is too long", key)));
+        }
+        self.values
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), value.to_vec());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_the_blobs() {
+        let mut store = MemorySettingsStore::new();
+        let mut kept = store.clone();
+        assert_eq!(kept.load("settings"), Ok(None));
+
+        store.save("settings", &[1, 2, 3]).unwrap();
+
+        assert_eq!(kept.load("settings"), Ok(Some(vec![1, 2, 3])));
+        assert!(store.save("a_much_too_long_key", &[]).is_err());
+    }
+}
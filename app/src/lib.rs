@@ -1,6 +1,9 @@
-pub mod app; // this is needed here as library project 
+pub mod app; // this is needed here as library project
 pub mod domain;
+pub mod ota;
 pub mod rust_proto;
+pub mod storage;
+pub mod transport;
 
 pub fn add(left: usize, right: usize) -> usize {
     left + right
@@ -0,0 +1,11 @@
+/*
+    this ota module keeps the firmware update logic out of the ble callbacks so it can be tested on
+    the host
+
+    - update
+    - signature
+
+*/
+
+pub mod signature;
+pub mod update;
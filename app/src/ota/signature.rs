@@ -0,0 +1,67 @@
+use ed25519_compact::{KeyPair, Seed, Signature};
+
+/*
+    signed images
+
+    any connected phone can write to the ota characteristics, so the sensor only boots images
+    signed with the private key whose public key it was built with. signing appends an ed25519
+    signature of the image and a marker to the esp.bin espflash save-image writes
+
+        [image] [ed25519 signature of the image] [SIGNED_MARKER]
+
+    the phone sends the signature in the ota request and the image without the trailer, the sensor
+    checks the signature as the image streams in, see update.
+
+    the private key is kept as the 32 byte ed25519 seed, the public key as its 32 bytes.
+*/
+
+pub const SIGNATURE_SIZE: usize = Signature::BYTES;
+pub const PUBLIC_KEY_SIZE: usize = 32;
+pub const SEED_SIZE: usize = Seed::BYTES;
+pub const SIGNED_MARKER: &[u8; 4] = b"SIG1";
+pub const TRAILER_SIZE: usize = SIGNATURE_SIZE + SIGNED_MARKER.len();
+
+/// the public key to build the firmware with for images signed with seed
+pub fn public_key(seed: &[u8; SEED_SIZE]) -> [u8; PUBLIC_KEY_SIZE] {
+    *KeyPair::from_seed(Seed::new(*seed)).pk
+}
+
+/// image with its signature trailer
+pub fn sign(image: &[u8], seed: &[u8; SEED_SIZE]) -> Vec<u8> {
+    let signature = KeyPair::from_seed(Seed::new(*seed)).sk.sign(image, None);
+    let mut signed = Vec::with_capacity(image.len() + TRAILER_SIZE);
+    signed.extend_from_slice(image);
+    signed.extend_from_slice(signature.as_ref());
+    signed.extend_from_slice(SIGNED_MARKER);
+    signed
+}
+
+/// the image and its signature, None when signed has no signature trailer
+pub fn split_signed(signed: &[u8]) -> Option<(&[u8], [u8; SIGNATURE_SIZE])> {
+    let rest = signed.strip_suffix(SIGNED_MARKER)?;
+    let split = rest.len().checked_sub(SIGNATURE_SIZE)?;
+    let (image, signature) = rest.split_at(split);
+    Some((image, signature.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_compact::PublicKey;
+
+    #[test]
+    fn signs_and_splits() {
+        let seed = [7; SEED_SIZE];
+        let image = b"an esp image".to_vec();
+
+        let signed = sign(&image, &seed);
+        let (unsigned, signature) = split_signed(&signed).unwrap();
+
+        assert_eq!(unsigned, image);
+        assert!(PublicKey::new(public_key(&seed))
+            .verify(unsigned, &Signature::new(signature))
+            .is_ok());
+        assert_eq!(split_signed(&image), None);
+        assert_eq!(split_signed(SIGNED_MARKER), None);
+    }
+}
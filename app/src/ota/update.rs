@@ -0,0 +1,653 @@
+use ed25519_compact::{PublicKey, Signature, VerifyingState};
+use sha2::{Digest, Sha256};
+
+use crate::ota::signature::{PUBLIC_KEY_SIZE, SIGNATURE_SIZE};
+use crate::transport::gatt::{
+    NONE_ATTEMPTED, OTA_CONTROL_DONE, OTA_CONTROL_DONE_ACK, OTA_CONTROL_DONE_NAK,
+    OTA_CONTROL_PROGRESS, OTA_CONTROL_REQUEST, OTA_CONTROL_REQUEST_ACK, OTA_CONTROL_REQUEST_NAK,
+    UPDATE_FAIL, UPDATE_SUCCESS,
+};
+
+/*
+    firmware updates
+
+    the phone starts an update with the size of the image, its sha-256 digest and its signature
+
+        [OTA_CONTROL_REQUEST] [packet size u16 le] [image size u32 le] [sha-256 of the image]
+        [ed25519 signature of the image]
+
+    the ack carries the offset the sensor expects the image from, 0 for a new update
+
+        [OTA_CONTROL_REQUEST_ACK] [next offset u32 le]
+
+    then writes the image from there in packet size chunks, numbered from 0 at the request
+
+        [sequence u32 le] [packet size bytes of the image, fewer for the last chunk]
+
+    and sends OTA_CONTROL_DONE once the sensor has it all. every PROGRESS_INTERVAL chunks and once
+    the image is complete the sensor notifies the offset it expects next
+
+        [OTA_CONTROL_PROGRESS] [next offset u32 le]
+
+    chunks that were already written are ignored, a chunk after a missing one makes the sensor
+    notify the offset it still expects straight away and ignore the rest until the phone goes back
+    to it. the update is kept when the phone disconnects, sending the same request again carries on
+    from where it stopped, even with another packet size.
+
+    the image is hashed as it is written and the signature is checked the same way, so at done the
+    sensor checks it got exactly the announced number of bytes with the announced digest, signed by
+    the key it was built with, before the target (the ota partition on the esp32) validates it.
+    only an image that passed every check can be applied. a rejected request or done is answered
+    with the reason
+
+        [OTA_CONTROL_REQUEST_NAK or OTA_CONTROL_DONE_NAK] [NakReason]
+
+    a failure while writing is remembered and reported at done.
+*/
+
+pub const DIGEST_SIZE: usize = 32;
+pub const REQUEST_SIZE: usize = 1 + 2 + 4 + DIGEST_SIZE + SIGNATURE_SIZE;
+/// bytes of an ota data write before the image, the sequence number
+pub const CHUNK_HEADER_SIZE: usize = 4;
+/// chunks between progress notifications
+pub const PROGRESS_INTERVAL: u32 = 16;
+
+/// why an ota request or done was rejected, the byte after the nak
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NakReason {
+    /// the request is too short to hold the image size and digest
+    InvalidRequest = 1,
+    /// done without a request, or after the update already failed
+    NotUpdating = 2,
+    /// done before the announced number of bytes arrived
+    SizeMismatch = 3,
+    /// the image does not hash to the announced digest
+    DigestMismatch = 4,
+    /// the image does not fit, or more bytes arrived than were announced
+    TooLarge = 5,
+    /// the target failed to start, write or finish the update
+    FlashFailed = 6,
+    /// the target rejected the image, e.g. esp_ota_end failed validation
+    InvalidImage = 7,
+    /// the image is not signed by the key the firmware was built with
+    BadSignature = 8,
+    /// a chunk had no sequence number, or was not packet size long and not the last one
+    BadChunk = 9,
+}
+
+impl NakReason {
+    pub fn from_code(code: u8) -> Option<Self> {
+        [
+            NakReason::InvalidRequest,
+            NakReason::NotUpdating,
+            NakReason::SizeMismatch,
+            NakReason::DigestMismatch,
+            NakReason::TooLarge,
+            NakReason::FlashFailed,
+            NakReason::InvalidImage,
+            NakReason::BadSignature,
+            NakReason::BadChunk,
+        ]
+        .into_iter()
+        .find(|reason| *reason as u8 == code)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OtaRequest {
+    pub packet_size: u16,
+    pub image_size: u32,
+    pub digest: [u8; DIGEST_SIZE],
+    pub signature: [u8; SIGNATURE_SIZE],
+}
+
+impl OtaRequest {
+    /// the request for sending image in packets of packet_size, see signature::split_signed
+    pub fn for_image(image: &[u8], signature: [u8; SIGNATURE_SIZE], packet_size: u16) -> Self {
+        OtaRequest {
+            packet_size,
+            image_size: image.len() as u32,
+            digest: Sha256::digest(image).into(),
+            signature,
+        }
+    }
+
+    /// the value written to the ota control characteristic
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(REQUEST_SIZE);
+        bytes.push(OTA_CONTROL_REQUEST);
+        bytes.extend_from_slice(&self.packet_size.to_le_bytes());
+        bytes.extend_from_slice(&self.image_size.to_le_bytes());
+        bytes.extend_from_slice(&self.digest);
+        bytes.extend_from_slice(&self.signature);
+        bytes
+    }
+
+    /// the same image, whatever the packet size
+    pub fn is_same_image(&self, other: &OtaRequest) -> bool {
+        (self.image_size, self.digest, self.signature)
+            == (other.image_size, other.digest, other.signature)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, NakReason> {
+        match bytes {
+            [OTA_CONTROL_REQUEST, p0, p1, s0, s1, s2, s3, rest @ ..]
+                if rest.len() >= DIGEST_SIZE + SIGNATURE_SIZE =>
+            {
+                let (digest, rest) = rest.split_at(DIGEST_SIZE);
+                Ok(OtaRequest {
+                    packet_size: u16::from_le_bytes([*p0, *p1]),
+                    image_size: u32::from_le_bytes([*s0, *s1, *s2, *s3]),
+                    digest: digest.try_into().unwrap(),
+                    signature: rest[..SIGNATURE_SIZE].try_into().unwrap(),
+                })
+            }
+            _ => Err(NakReason::InvalidRequest),
+        }
+    }
+}
+
+/// the ota data write of the sequence'th chunk since the request
+pub fn chunk(sequence: u32, data: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(CHUNK_HEADER_SIZE + data.len());
+    bytes.extend_from_slice(&sequence.to_le_bytes());
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+fn parse_chunk(bytes: &[u8]) -> Option<(u32, &[u8])> {
+    let (sequence, data) = bytes.split_first_chunk::<CHUNK_HEADER_SIZE>()?;
+    Some((u32::from_le_bytes(*sequence), data))
+}
+
+/// the offset in a request ack or progress notification
+pub fn next_offset(value: &[u8]) -> Option<u32> {
+    match value {
+        [OTA_CONTROL_REQUEST_ACK | OTA_CONTROL_PROGRESS, offset @ ..] => {
+            Some(u32::from_le_bytes(offset.try_into().ok()?))
+        }
+        _ => None,
+    }
+}
+
+fn with_offset(opcode: u8, offset: usize) -> Vec<u8> {
+    let mut value = vec![opcode];
+    value.extend_from_slice(&(offset as u32).to_le_bytes());
+    value
+}
+
+/// OtaTarget is where an image is written, the ota partition on the esp32
+pub trait OtaTarget {
+    /// largest image that fits
+    fn capacity(&self) -> usize;
+    fn begin(&mut self, image_size: usize) -> Result<(), NakReason>;
+    fn write(&mut self, data: &[u8]) -> Result<(), NakReason>;
+    /// finishes and validates the image
+    fn end(&mut self) -> Result<(), NakReason>;
+    /// throws away a partly written image
+    fn abort(&mut self);
+    /// boots the finished image next restart
+    fn apply(&mut self) -> Result<(), NakReason>;
+}
+
+// an update being written
+struct Transfer {
+    request: OtaRequest,
+    hasher: Sha256,
+    verifier: VerifyingState,
+    received: usize,
+    next_sequence: u32,
+    // the phone was told it skipped a chunk and has not gone back to it yet
+    rewound: bool,
+    // the first thing to go wrong while writing, reported at done
+    failed: Option<NakReason>,
+}
+
+enum State {
+    Idle,
+    // boxed, the hash states make it large
+    Updating(Box<Transfer>),
+    Available,
+}
+
+/// OtaUpdate follows the writes to the ota characteristics and answers them
+pub struct OtaUpdate<T: OtaTarget> {
+    target: T,
+    // images must be signed by its private key
+    public_key: PublicKey,
+    state: State,
+}
+
+impl<T: OtaTarget> OtaUpdate<T> {
+    pub fn new(target: T, public_key: [u8; PUBLIC_KEY_SIZE]) -> Self {
+        OtaUpdate {
+            target,
+            public_key: PublicKey::new(public_key),
+            state: State::Idle,
+        }
+    }
+
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    pub fn is_updating(&self) -> bool {
+        matches!(self.state, State::Updating(_))
+    }
+
+    /// bytes of the image received so far
+    pub fn received(&self) -> usize {
+        match &self.state {
+            State::Updating(transfer) => transfer.received,
+            _ => 0,
+        }
+    }
+
+    /// a write to the ota control characteristic, returns the value to notify
+    pub fn control(&mut self, value: &[u8]) -> Option<Vec<u8>> {
+        match value.first() {
+            Some(&OTA_CONTROL_REQUEST) => Some(match self.request(value) {
+                Ok(offset) => with_offset(OTA_CONTROL_REQUEST_ACK, offset),
+                Err(reason) => vec![OTA_CONTROL_REQUEST_NAK, reason as u8],
+            }),
+            Some(&OTA_CONTROL_DONE) => Some(match self.done() {
+                Ok(()) => vec![OTA_CONTROL_DONE_ACK],
+                Err(reason) => vec![OTA_CONTROL_DONE_NAK, reason as u8],
+            }),
+            _ => None,
+        }
+    }
+
+    // starts an update, or carries on with the one in progress, returns the next offset
+    fn request(&mut self, value: &[u8]) -> Result<usize, NakReason> {
+        let request = OtaRequest::from_bytes(value);
+        if let (Ok(request), State::Updating(transfer)) = (&request, &mut self.state) {
+            if transfer.failed.is_none() && transfer.request.is_same_image(request) {
+                transfer.request.packet_size = request.packet_size;
+                transfer.next_sequence = 0;
+                transfer.rewound = false;
+                return Ok(transfer.received);
+            }
+        }
+        self.cancel();
+        let request = request?;
+        if request.image_size as usize > self.target.capacity() {
+            return Err(NakReason::TooLarge);
+        }
+        let verifier = self
+            .public_key
+            .verify_incremental(&Signature::new(request.signature))
+            .map_err(|_| NakReason::BadSignature)?;
+        self.target.begin(request.image_size as usize)?;
+        self.state = State::Updating(Box::new(Transfer {
+            request,
+            hasher: Sha256::new(),
+            verifier,
+            received: 0,
+            next_sequence: 0,
+            rewound: false,
+            failed: None,
+        }));
+        Ok(0)
+    }
+
+    /// a write to the ota data characteristic, a chunk of the image. returns the progress to
+    /// notify on ota control, if any
+    pub fn data(&mut self, value: &[u8]) -> Option<Vec<u8>> {
+        let State::Updating(transfer) = &mut self.state else {
+            return None;
+        };
+        if transfer.failed.is_some() {
+            return None;
+        }
+        let Some((sequence, data)) = parse_chunk(value) else {
+            transfer.failed = Some(NakReason::BadChunk);
+            return None;
+        };
+        if sequence != transfer.next_sequence {
+            // a repeat is dropped, a chunk after a missing one sends the phone back to it once
+            if sequence < transfer.next_sequence || transfer.rewound {
+                return None;
+            }
+            transfer.rewound = true;
+            return Some(with_offset(OTA_CONTROL_PROGRESS, transfer.received));
+        }
+
+        let remaining = transfer.request.image_size as usize - transfer.received;
+        if data.len() > remaining {
+            transfer.failed = Some(NakReason::TooLarge);
+            return None;
+        }
+        if data.len() != transfer.request.packet_size as usize && data.len() != remaining {
+            transfer.failed = Some(NakReason::BadChunk);
+            return None;
+        }
+        if let Err(reason) = self.target.write(data) {
+            transfer.failed = Some(reason);
+            return None;
+        }
+        transfer.hasher.update(data);
+        transfer.verifier.absorb(data);
+        transfer.received += data.len();
+        transfer.next_sequence += 1;
+        transfer.rewound = false;
+
+        let complete = transfer.received == transfer.request.image_size as usize;
+        (complete || transfer.next_sequence % PROGRESS_INTERVAL == 0)
+            .then(|| with_offset(OTA_CONTROL_PROGRESS, transfer.received))
+    }
+
+    fn done(&mut self) -> Result<(), NakReason> {
+        let State::Updating(transfer) = std::mem::replace(&mut self.state, State::Idle) else {
+            return Err(NakReason::NotUpdating);
+        };
+        let Transfer {
+            request,
+            hasher,
+            verifier,
+            received,
+            failed,
+            ..
+        } = *transfer;
+        let checked = match failed {
+            Some(reason) => Err(reason),
+            None if received != request.image_size as usize => Err(NakReason::SizeMismatch),
+            None if hasher.finalize()[..] != request.digest => Err(NakReason::DigestMismatch),
+            None if verifier.verify().is_err() => Err(NakReason::BadSignature),
+            None => Ok(()),
+        };
+        if let Err(reason) = checked {
+            self.target.abort();
+            return Err(reason);
+        }
+        self.target.end()?;
+        self.state = State::Available;
+        Ok(())
+    }
+
+    /// a write to the apply update characteristic, returns the value to notify. only a checked and
+    /// signed image is applied, the sensor should restart after UPDATE_SUCCESS
+    pub fn apply(&mut self) -> u8 {
+        if !matches!(self.state, State::Available) {
+            return NONE_ATTEMPTED;
+        }
+        self.state = State::Idle;
+        match self.target.apply() {
+            Ok(()) => UPDATE_SUCCESS,
+            Err(_) => UPDATE_FAIL,
+        }
+    }
+
+    /// abandons an update in progress
+    pub fn cancel(&mut self) {
+        if self.is_updating() {
+            self.target.abort();
+        }
+        self.state = State::Idle;
+    }
+}
+
+/// keeps the image in memory, for tests and the simulator
+#[derive(Debug, Clone, Default)]
+pub struct MemoryTarget {
+    capacity: usize,
+    image: Vec<u8>,
+    /// the last image applied
+    pub applied: Option<Vec<u8>>,
+}
+
+impl MemoryTarget {
+    pub fn new(capacity: usize) -> Self {
+        MemoryTarget {
+            capacity,
+            ..MemoryTarget::default()
+        }
+    }
+}
+
+impl OtaTarget for MemoryTarget {
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn begin(&mut self, image_size: usize) -> Result<(), NakReason> {
+        self.image = Vec::with_capacity(image_size);
+        Ok(())
+    }
+
+    fn write(&mut self, data: &[u8]) -> Result<(), NakReason> {
+        self.image.extend_from_slice(data);
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<(), NakReason> {
+        Ok(())
+    }
+
+    fn abort(&mut self) {
+        self.image.clear();
+    }
+
+    fn apply(&mut self) -> Result<(), NakReason> {
+        self.applied = Some(std::mem::take(&mut self.image));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ota::signature::{public_key, sign, split_signed, SEED_SIZE};
+
+    const SEED: [u8; SEED_SIZE] = [3; SEED_SIZE];
+
+    fn image() -> Vec<u8> {
+        (0..1000).map(|i| (i * 31) as u8).collect()
+    }
+
+    fn signed_request(image: &[u8], seed: &[u8; SEED_SIZE]) -> OtaRequest {
+        let signed = sign(image, seed);
+        let (_, signature) = split_signed(&signed).unwrap();
+        OtaRequest::for_image(image, signature, 244)
+    }
+
+    fn update() -> OtaUpdate<MemoryTarget> {
+        OtaUpdate::new(MemoryTarget::new(4096), public_key(&SEED))
+    }
+
+    fn progress(offset: usize) -> Option<Vec<u8>> {
+        Some(with_offset(OTA_CONTROL_PROGRESS, offset))
+    }
+
+    // requests the update, returns the offset the sensor acked
+    fn start(update: &mut OtaUpdate<MemoryTarget>, request: OtaRequest) -> u32 {
+        next_offset(&update.control(&request.to_bytes()).unwrap()).unwrap()
+    }
+
+    fn send(update: &mut OtaUpdate<MemoryTarget>, request: OtaRequest, image: &[u8]) -> Vec<u8> {
+        assert_eq!(start(update, request), 0);
+        for (sequence, packet) in image.chunks(request.packet_size as usize).enumerate() {
+            update.data(&chunk(sequence as u32, packet));
+        }
+        update.control(&[OTA_CONTROL_DONE]).unwrap()
+    }
+
+    #[test]
+    fn checks_and_applies_an_image() {
+        let mut update = update();
+        let image = image();
+        assert_eq!(update.apply(), NONE_ATTEMPTED);
+
+        let done = send(&mut update, signed_request(&image, &SEED), &image);
+
+        assert_eq!(done, vec![OTA_CONTROL_DONE_ACK]);
+        assert_eq!(update.apply(), UPDATE_SUCCESS);
+        assert_eq!(update.target().applied, Some(image));
+        assert_eq!(update.apply(), NONE_ATTEMPTED);
+    }
+
+    #[test]
+    fn rejects_a_corrupted_image() {
+        let mut update = update();
+        let mut image = image();
+        let request = signed_request(&image, &SEED);
+        image[500] ^= 1;
+
+        let done = send(&mut update, request, &image);
+
+        assert_eq!(
+            done,
+            vec![OTA_CONTROL_DONE_NAK, NakReason::DigestMismatch as u8]
+        );
+        assert_eq!(update.apply(), NONE_ATTEMPTED);
+    }
+
+    #[test]
+    fn rejects_unsigned_images() {
+        let mut update = update();
+        let image = image();
+        let mut other = image.clone();
+        other[0] ^= 1;
+
+        let other_key = send(&mut update, signed_request(&image, &[4; SEED_SIZE]), &image);
+        // a signature of another image with the digest of this one
+        let mut request = signed_request(&other, &SEED);
+        request.digest = Sha256::digest(&image).into();
+        let other_image = send(&mut update, request, &image);
+
+        let nak = vec![OTA_CONTROL_DONE_NAK, NakReason::BadSignature as u8];
+        assert_eq!(other_key, nak);
+        assert_eq!(other_image, nak);
+        assert_eq!(update.apply(), NONE_ATTEMPTED);
+        assert_eq!(update.target().applied, None);
+    }
+
+    #[test]
+    fn checks_the_size() {
+        let mut update = update();
+        let image = image();
+        let request = signed_request(&image, &SEED);
+
+        let short = send(&mut update, request, &image[..732]);
+        let mut long = image.clone();
+        long.push(0);
+        let long = send(&mut update, request, &long);
+        start(&mut update, request);
+        update.data(&chunk(0, &image[..100]));
+        let uneven = update.control(&[OTA_CONTROL_DONE]).unwrap();
+
+        assert_eq!(
+            short,
+            vec![OTA_CONTROL_DONE_NAK, NakReason::SizeMismatch as u8]
+        );
+        assert_eq!(long, vec![OTA_CONTROL_DONE_NAK, NakReason::TooLarge as u8]);
+        assert_eq!(
+            uneven,
+            vec![OTA_CONTROL_DONE_NAK, NakReason::BadChunk as u8]
+        );
+    }
+
+    #[test]
+    fn reports_progress() {
+        let mut update = update();
+        let image = image();
+        let mut request = signed_request(&image, &SEED);
+        request.packet_size = 10;
+        start(&mut update, request);
+
+        let notified: Vec<_> = image
+            .chunks(10)
+            .enumerate()
+            .filter_map(|(sequence, packet)| update.data(&chunk(sequence as u32, packet)))
+            .collect();
+
+        let expected: Vec<_> = (1..=6)
+            .map(|i| i * PROGRESS_INTERVAL as usize * 10)
+            .chain([1000])
+            .map(|offset| progress(offset).unwrap())
+            .collect();
+        assert_eq!(notified, expected);
+    }
+
+    #[test]
+    fn sends_the_phone_back_to_a_missing_chunk() {
+        let mut update = update();
+        let image = image();
+        let mut request = signed_request(&image, &SEED);
+        request.packet_size = 100;
+        start(&mut update, request);
+        let send = |update: &mut OtaUpdate<MemoryTarget>, sequence: usize| {
+            update.data(&chunk(sequence as u32, &image[sequence * 100..][..100]))
+        };
+
+        assert_eq!(send(&mut update, 0), None);
+        assert_eq!(send(&mut update, 2), progress(100));
+        assert_eq!(send(&mut update, 3), None);
+        assert_eq!(send(&mut update, 0), None);
+        let rest: Vec<_> = (1..10)
+            .map(|sequence| send(&mut update, sequence))
+            .collect();
+
+        assert_eq!(rest.last(), Some(&progress(1000)));
+        assert_eq!(update.received(), 1000);
+        assert_eq!(
+            update.control(&[OTA_CONTROL_DONE]),
+            Some(vec![OTA_CONTROL_DONE_ACK])
+        );
+    }
+
+    #[test]
+    fn resumes_after_a_reconnect() {
+        let mut update = update();
+        let image = image();
+        let request = signed_request(&image, &SEED);
+        start(&mut update, request);
+        update.data(&chunk(0, &image[..244]));
+        update.data(&chunk(1, &image[244..488]));
+
+        // the phone comes back with a smaller mtu
+        let mut resumed = request;
+        resumed.packet_size = 100;
+        let offset = start(&mut update, resumed) as usize;
+        for (sequence, packet) in image[offset..].chunks(100).enumerate() {
+            update.data(&chunk(sequence as u32, packet));
+        }
+
+        assert_eq!(offset, 488);
+        assert_eq!(
+            update.control(&[OTA_CONTROL_DONE]),
+            Some(vec![OTA_CONTROL_DONE_ACK])
+        );
+        assert_eq!(update.apply(), UPDATE_SUCCESS);
+        assert_eq!(update.target().applied, Some(image));
+    }
+
+    #[test]
+    fn rejects_bad_requests() {
+        let mut update = update();
+        let mut request = signed_request(&image(), &SEED);
+        let bytes = request.to_bytes();
+
+        assert_eq!(OtaRequest::from_bytes(&bytes), Ok(request));
+        assert_eq!(
+            update.control(&bytes[..3]),
+            Some(vec![
+                OTA_CONTROL_REQUEST_NAK,
+                NakReason::InvalidRequest as u8
+            ])
+        );
+        request.image_size = 5000;
+        assert_eq!(
+            update.control(&request.to_bytes()),
+            Some(vec![OTA_CONTROL_REQUEST_NAK, NakReason::TooLarge as u8])
+        );
+        assert_eq!(
+            update.control(&[OTA_CONTROL_DONE]),
+            Some(vec![OTA_CONTROL_DONE_NAK, NakReason::NotUpdating as u8])
+        );
+        assert_eq!(
+            NakReason::from_code(NakReason::InvalidImage as u8),
+            Some(NakReason::InvalidImage)
+        );
+    }
+}
@@ -1,5 +1,5 @@
-// This file is generated by rust-protobuf 3.2.0. Do not edit
-// .proto file is parsed by protoc --rust-out=...
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// .proto file is parsed by protoc --rs_out=...
 // @generated
 
 // https://github.com/rust-lang/rust-clippy/issues/702
@@ -9,7 +9,6 @@
 #![allow(unused_attributes)]
 #![cfg_attr(rustfmt, rustfmt::skip)]
 
-#![allow(box_pointers)]
 #![allow(dead_code)]
 #![allow(missing_docs)]
 #![allow(non_camel_case_types)]
@@ -23,12 +22,12 @@
 
 /// Generated files are compatible only with the same version
 /// of protobuf runtime.
-const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_2_0;
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_7_2;
 
 ///
 /// End Algorthims
-#[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:Timed)
+#[derive(PartialEq,Clone,Default,Debug)]
 pub struct Timed {
     // message fields
     // @@protoc_insertion_point(field:Timed.duration)
@@ -149,8 +148,8 @@ impl ::protobuf::reflect::ProtobufValue for Timed {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-#[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:Counted)
+#[derive(PartialEq,Clone,Default,Debug)]
 pub struct Counted {
     // message fields
     // @@protoc_insertion_point(field:Counted.counts)
@@ -273,9 +272,16 @@ impl ::protobuf::reflect::ProtobufValue for Counted {
 
 ///
 /// Start Algorithims
-#[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:SpikeStart)
+#[derive(PartialEq,Clone,Default,Debug)]
 pub struct SpikeStart {
+    // message fields
+    // @@protoc_insertion_point(field:SpikeStart.threshold)
+    pub threshold: u32,
+    // @@protoc_insertion_point(field:SpikeStart.releaseThreshold)
+    pub releaseThreshold: u32,
+    // @@protoc_insertion_point(field:SpikeStart.refractoryPeriod)
+    pub refractoryPeriod: u32,
     // special fields
     // @@protoc_insertion_point(special_field:SpikeStart.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -293,8 +299,23 @@ impl SpikeStart {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "threshold",
+            |m: &SpikeStart| { &m.threshold },
+            |m: &mut SpikeStart| { &mut m.threshold },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "releaseThreshold",
+            |m: &SpikeStart| { &m.releaseThreshold },
+            |m: &mut SpikeStart| { &mut m.releaseThreshold },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "refractoryPeriod",
+            |m: &SpikeStart| { &m.refractoryPeriod },
+            |m: &mut SpikeStart| { &mut m.refractoryPeriod },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SpikeStart>(
             "SpikeStart",
             fields,
@@ -313,6 +334,15 @@ impl ::protobuf::Message for SpikeStart {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
+                8 => {
+                    self.threshold = is.read_uint32()?;
+                },
+                16 => {
+                    self.releaseThreshold = is.read_uint32()?;
+                },
+                24 => {
+                    self.refractoryPeriod = is.read_uint32()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -325,12 +355,30 @@ impl ::protobuf::Message for SpikeStart {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
+        if self.threshold != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.threshold);
+        }
+        if self.releaseThreshold != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.releaseThreshold);
+        }
+        if self.refractoryPeriod != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.refractoryPeriod);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.threshold != 0 {
+            os.write_uint32(1, self.threshold)?;
+        }
+        if self.releaseThreshold != 0 {
+            os.write_uint32(2, self.releaseThreshold)?;
+        }
+        if self.refractoryPeriod != 0 {
+            os.write_uint32(3, self.refractoryPeriod)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -348,11 +396,17 @@ impl ::protobuf::Message for SpikeStart {
     }
 
     fn clear(&mut self) {
+        self.threshold = 0;
+        self.releaseThreshold = 0;
+        self.refractoryPeriod = 0;
         self.special_fields.clear();
     }
 
     fn default_instance() -> &'static SpikeStart {
         static instance: SpikeStart = SpikeStart {
+            threshold: 0,
+            releaseThreshold: 0,
+            refractoryPeriod: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -378,8 +432,8 @@ impl ::protobuf::reflect::ProtobufValue for SpikeStart {
 
 ///
 /// Configurations
-#[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:Bouncing)
+#[derive(PartialEq,Clone,Default,Debug)]
 pub struct Bouncing {
     // message fields
     // @@protoc_insertion_point(field:Bouncing.jumps)
@@ -502,8 +556,8 @@ impl ::protobuf::reflect::ProtobufValue for Bouncing {
 
 ///
 /// Outputs
-#[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:BouningRep)
+#[derive(PartialEq,Clone,Default,Debug)]
 pub struct BouningRep {
     // message fields
     // @@protoc_insertion_point(field:BouningRep.averageHeight)
@@ -661,8 +715,8 @@ impl ::protobuf::reflect::ProtobufValue for BouningRep {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-#[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:BounceMeasurment)
+#[derive(PartialEq,Clone,Default,Debug)]
 pub struct BounceMeasurment {
     // message fields
     // @@protoc_insertion_point(field:BounceMeasurment.groundContactTime)
@@ -802,9 +856,12 @@ impl ::protobuf::reflect::ProtobufValue for BounceMeasurment {
 }
 
 ///  Algorithm algorithim configuration
-#[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:AlgorithimConfiguration)
+#[derive(PartialEq,Clone,Default,Debug)]
 pub struct AlgorithimConfiguration {
+    // message fields
+    // @@protoc_insertion_point(field:AlgorithimConfiguration.heightModel)
+    pub heightModel: ::protobuf::EnumOrUnknown<HeightModel>,
     // message oneof groups
     pub endAlgorithim: ::std::option::Option<algorithim_configuration::EndAlgorithim>,
     pub startAlgorthim: ::std::option::Option<algorithim_configuration::StartAlgorthim>,
@@ -874,6 +931,55 @@ impl AlgorithimConfiguration {
         }
     }
 
+    // .Counted counted = 4;
+
+    pub fn counted(&self) -> &Counted {
+        match self.endAlgorithim {
+            ::std::option::Option::Some(algorithim_configuration::EndAlgorithim::Counted(ref v)) => v,
+            _ => <Counted as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_counted(&mut self) {
+        self.endAlgorithim = ::std::option::Option::None;
+    }
+
+    pub fn has_counted(&self) -> bool {
+        match self.endAlgorithim {
+            ::std::option::Option::Some(algorithim_configuration::EndAlgorithim::Counted(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counted(&mut self, v: Counted) {
+        self.endAlgorithim = ::std::option::Option::Some(algorithim_configuration::EndAlgorithim::Counted(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_counted(&mut self) -> &mut Counted {
+        if let ::std::option::Option::Some(algorithim_configuration::EndAlgorithim::Counted(_)) = self.endAlgorithim {
+        } else {
+            self.endAlgorithim = ::std::option::Option::Some(algorithim_configuration::EndAlgorithim::Counted(Counted::new()));
+        }
+        match self.endAlgorithim {
+            ::std::option::Option::Some(algorithim_configuration::EndAlgorithim::Counted(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_counted(&mut self) -> Counted {
+        if self.has_counted() {
+            match self.endAlgorithim.take() {
+                ::std::option::Option::Some(algorithim_configuration::EndAlgorithim::Counted(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Counted::new()
+        }
+    }
+
     // .SpikeStart spikeStart = 2;
 
     pub fn spikeStart(&self) -> &SpikeStart {
@@ -973,7 +1079,7 @@ impl AlgorithimConfiguration {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(3);
         fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Timed>(
             "timed",
@@ -982,6 +1088,13 @@ impl AlgorithimConfiguration {
             AlgorithimConfiguration::mut_timed,
             AlgorithimConfiguration::set_timed,
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Counted>(
+            "counted",
+            AlgorithimConfiguration::has_counted,
+            AlgorithimConfiguration::counted,
+            AlgorithimConfiguration::mut_counted,
+            AlgorithimConfiguration::set_counted,
+        ));
         fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SpikeStart>(
             "spikeStart",
             AlgorithimConfiguration::has_spikeStart,
@@ -996,6 +1109,11 @@ impl AlgorithimConfiguration {
             AlgorithimConfiguration::mut_bouncing,
             AlgorithimConfiguration::set_bouncing,
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "heightModel",
+            |m: &AlgorithimConfiguration| { &m.heightModel },
+            |m: &mut AlgorithimConfiguration| { &mut m.heightModel },
+        ));
         oneofs.push(algorithim_configuration::EndAlgorithim::generated_oneof_descriptor_data());
         oneofs.push(algorithim_configuration::StartAlgorthim::generated_oneof_descriptor_data());
         oneofs.push(algorithim_configuration::Configuration::generated_oneof_descriptor_data());
@@ -1020,12 +1138,18 @@ impl ::protobuf::Message for AlgorithimConfiguration {
                 10 => {
                     self.endAlgorithim = ::std::option::Option::Some(algorithim_configuration::EndAlgorithim::Timed(is.read_message()?));
                 },
+                34 => {
+                    self.endAlgorithim = ::std::option::Option::Some(algorithim_configuration::EndAlgorithim::Counted(is.read_message()?));
+                },
                 18 => {
                     self.startAlgorthim = ::std::option::Option::Some(algorithim_configuration::StartAlgorthim::SpikeStart(is.read_message()?));
                 },
                 26 => {
                     self.configuration = ::std::option::Option::Some(algorithim_configuration::Configuration::Bouncing(is.read_message()?));
                 },
+                40 => {
+                    self.heightModel = is.read_enum_or_unknown()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1038,12 +1162,19 @@ impl ::protobuf::Message for AlgorithimConfiguration {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
+        if self.heightModel != ::protobuf::EnumOrUnknown::new(HeightModel::FLIGHT_TIME) {
+            my_size += ::protobuf::rt::int32_size(5, self.heightModel.value());
+        }
         if let ::std::option::Option::Some(ref v) = self.endAlgorithim {
             match v {
                 &algorithim_configuration::EndAlgorithim::Timed(ref v) => {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
                 },
+                &algorithim_configuration::EndAlgorithim::Counted(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
             };
         }
         if let ::std::option::Option::Some(ref v) = self.startAlgorthim {
@@ -1068,11 +1199,17 @@ impl ::protobuf::Message for AlgorithimConfiguration {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.heightModel != ::protobuf::EnumOrUnknown::new(HeightModel::FLIGHT_TIME) {
+            os.write_enum(5, ::protobuf::EnumOrUnknown::value(&self.heightModel))?;
+        }
         if let ::std::option::Option::Some(ref v) = self.endAlgorithim {
             match v {
                 &algorithim_configuration::EndAlgorithim::Timed(ref v) => {
                     ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
                 },
+                &algorithim_configuration::EndAlgorithim::Counted(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
             };
         }
         if let ::std::option::Option::Some(ref v) = self.startAlgorthim {
@@ -1106,14 +1243,17 @@ impl ::protobuf::Message for AlgorithimConfiguration {
     }
 
     fn clear(&mut self) {
+        self.endAlgorithim = ::std::option::Option::None;
         self.endAlgorithim = ::std::option::Option::None;
         self.startAlgorthim = ::std::option::Option::None;
         self.configuration = ::std::option::Option::None;
+        self.heightModel = ::protobuf::EnumOrUnknown::new(HeightModel::FLIGHT_TIME);
         self.special_fields.clear();
     }
 
     fn default_instance() -> &'static AlgorithimConfiguration {
         static instance: AlgorithimConfiguration = AlgorithimConfiguration {
+            heightModel: ::protobuf::EnumOrUnknown::from_i32(0),
             endAlgorithim: ::std::option::Option::None,
             startAlgorthim: ::std::option::Option::None,
             configuration: ::std::option::Option::None,
@@ -1149,6 +1289,8 @@ pub mod algorithim_configuration {
     pub enum EndAlgorithim {
         // @@protoc_insertion_point(oneof_field:AlgorithimConfiguration.timed)
         Timed(super::Timed),
+        // @@protoc_insertion_point(oneof_field:AlgorithimConfiguration.counted)
+        Counted(super::Counted),
     }
 
     impl ::protobuf::Oneof for EndAlgorithim {
@@ -1217,8 +1359,8 @@ pub mod algorithim_configuration {
 }
 
 ///  Measuremnts
-#[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:Rep)
+#[derive(PartialEq,Clone,Default,Debug)]
 pub struct Rep {
     // message oneof groups
     pub rep: ::std::option::Option<rep::Rep>,
@@ -1427,155 +1569,68 @@ pub mod rep {
     }
 }
 
-///
-/// transfer Container
+///  turns streaming of the raw imu samples on or off
+// @@protoc_insertion_point(message:RawStream)
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:Message)
-pub struct Message {
-    // message oneof groups
-    pub content: ::std::option::Option<message::Content>,
+pub struct RawStream {
+    // message fields
+    // @@protoc_insertion_point(field:RawStream.enabled)
+    pub enabled: bool,
+    // @@protoc_insertion_point(field:RawStream.decimation)
+    pub decimation: u32,
+    // @@protoc_insertion_point(field:RawStream.batchSize)
+    pub batchSize: u32,
+    // @@protoc_insertion_point(field:RawStream.includeMag)
+    pub includeMag: bool,
     // special fields
-    // @@protoc_insertion_point(special_field:Message.special_fields)
+    // @@protoc_insertion_point(special_field:RawStream.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a Message {
-    fn default() -> &'a Message {
-        <Message as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RawStream {
+    fn default() -> &'a RawStream {
+        <RawStream as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Message {
-    pub fn new() -> Message {
+impl RawStream {
+    pub fn new() -> RawStream {
         ::std::default::Default::default()
     }
 
-    // .AlgorithimConfiguration Algorithim = 1;
-
-    pub fn Algorithim(&self) -> &AlgorithimConfiguration {
-        match self.content {
-            ::std::option::Option::Some(message::Content::Algorithim(ref v)) => v,
-            _ => <AlgorithimConfiguration as ::protobuf::Message>::default_instance(),
-        }
-    }
-
-    pub fn clear_Algorithim(&mut self) {
-        self.content = ::std::option::Option::None;
-    }
-
-    pub fn has_Algorithim(&self) -> bool {
-        match self.content {
-            ::std::option::Option::Some(message::Content::Algorithim(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_Algorithim(&mut self, v: AlgorithimConfiguration) {
-        self.content = ::std::option::Option::Some(message::Content::Algorithim(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_Algorithim(&mut self) -> &mut AlgorithimConfiguration {
-        if let ::std::option::Option::Some(message::Content::Algorithim(_)) = self.content {
-        } else {
-            self.content = ::std::option::Option::Some(message::Content::Algorithim(AlgorithimConfiguration::new()));
-        }
-        match self.content {
-            ::std::option::Option::Some(message::Content::Algorithim(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_Algorithim(&mut self) -> AlgorithimConfiguration {
-        if self.has_Algorithim() {
-            match self.content.take() {
-                ::std::option::Option::Some(message::Content::Algorithim(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            AlgorithimConfiguration::new()
-        }
-    }
-
-    // .Rep rep = 2;
-
-    pub fn rep(&self) -> &Rep {
-        match self.content {
-            ::std::option::Option::Some(message::Content::Rep(ref v)) => v,
-            _ => <Rep as ::protobuf::Message>::default_instance(),
-        }
-    }
-
-    pub fn clear_rep(&mut self) {
-        self.content = ::std::option::Option::None;
-    }
-
-    pub fn has_rep(&self) -> bool {
-        match self.content {
-            ::std::option::Option::Some(message::Content::Rep(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_rep(&mut self, v: Rep) {
-        self.content = ::std::option::Option::Some(message::Content::Rep(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_rep(&mut self) -> &mut Rep {
-        if let ::std::option::Option::Some(message::Content::Rep(_)) = self.content {
-        } else {
-            self.content = ::std::option::Option::Some(message::Content::Rep(Rep::new()));
-        }
-        match self.content {
-            ::std::option::Option::Some(message::Content::Rep(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_rep(&mut self) -> Rep {
-        if self.has_rep() {
-            match self.content.take() {
-                ::std::option::Option::Some(message::Content::Rep(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Rep::new()
-        }
-    }
-
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
-        let mut oneofs = ::std::vec::Vec::with_capacity(1);
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AlgorithimConfiguration>(
-            "Algorithim",
-            Message::has_Algorithim,
-            Message::Algorithim,
-            Message::mut_Algorithim,
-            Message::set_Algorithim,
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "enabled",
+            |m: &RawStream| { &m.enabled },
+            |m: &mut RawStream| { &mut m.enabled },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Rep>(
-            "rep",
-            Message::has_rep,
-            Message::rep,
-            Message::mut_rep,
-            Message::set_rep,
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "decimation",
+            |m: &RawStream| { &m.decimation },
+            |m: &mut RawStream| { &mut m.decimation },
         ));
-        oneofs.push(message::Content::generated_oneof_descriptor_data());
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Message>(
-            "Message",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "batchSize",
+            |m: &RawStream| { &m.batchSize },
+            |m: &mut RawStream| { &mut m.batchSize },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "includeMag",
+            |m: &RawStream| { &m.includeMag },
+            |m: &mut RawStream| { &mut m.includeMag },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RawStream>(
+            "RawStream",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Message {
-    const NAME: &'static str = "Message";
+impl ::protobuf::Message for RawStream {
+    const NAME: &'static str = "RawStream";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1584,11 +1639,17 @@ impl ::protobuf::Message for Message {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.content = ::std::option::Option::Some(message::Content::Algorithim(is.read_message()?));
+                8 => {
+                    self.enabled = is.read_bool()?;
                 },
-                18 => {
-                    self.content = ::std::option::Option::Some(message::Content::Rep(is.read_message()?));
+                16 => {
+                    self.decimation = is.read_uint32()?;
+                },
+                24 => {
+                    self.batchSize = is.read_uint32()?;
+                },
+                32 => {
+                    self.includeMag = is.read_bool()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1602,17 +1663,17 @@ impl ::protobuf::Message for Message {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.content {
-            match v {
-                &message::Content::Algorithim(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &message::Content::Rep(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-            };
+        if self.enabled != false {
+            my_size += 1 + 1;
+        }
+        if self.decimation != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.decimation);
+        }
+        if self.batchSize != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.batchSize);
+        }
+        if self.includeMag != false {
+            my_size += 1 + 1;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1620,15 +1681,17 @@ impl ::protobuf::Message for Message {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let ::std::option::Option::Some(ref v) = self.content {
-            match v {
-                &message::Content::Algorithim(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-                },
-                &message::Content::Rep(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-                },
-            };
+        if self.enabled != false {
+            os.write_bool(1, self.enabled)?;
+        }
+        if self.decimation != 0 {
+            os.write_uint32(2, self.decimation)?;
+        }
+        if self.batchSize != 0 {
+            os.write_uint32(3, self.batchSize)?;
+        }
+        if self.includeMag != false {
+            os.write_bool(4, self.includeMag)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1642,18 +1705,2826 @@ impl ::protobuf::Message for Message {
         &mut self.special_fields
     }
 
-    fn new() -> Message {
-        Message::new()
+    fn new() -> RawStream {
+        RawStream::new()
     }
 
     fn clear(&mut self) {
+        self.enabled = false;
+        self.decimation = 0;
+        self.batchSize = 0;
+        self.includeMag = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RawStream {
+        static instance: RawStream = RawStream {
+            enabled: false,
+            decimation: 0,
+            batchSize: 0,
+            includeMag: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RawStream {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RawStream").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RawStream {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RawStream {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  a batch of raw samples, the vectors are interleaved x y z
+// @@protoc_insertion_point(message:SampleBatch)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SampleBatch {
+    // message fields
+    // @@protoc_insertion_point(field:SampleBatch.sequence)
+    pub sequence: u32,
+    // @@protoc_insertion_point(field:SampleBatch.startTimestamp)
+    pub startTimestamp: i64,
+    // @@protoc_insertion_point(field:SampleBatch.timestampDeltas)
+    pub timestampDeltas: ::std::vec::Vec<u32>,
+    // @@protoc_insertion_point(field:SampleBatch.accel)
+    pub accel: ::std::vec::Vec<i32>,
+    // @@protoc_insertion_point(field:SampleBatch.mag)
+    pub mag: ::std::vec::Vec<i32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:SampleBatch.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SampleBatch {
+    fn default() -> &'a SampleBatch {
+        <SampleBatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SampleBatch {
+    pub fn new() -> SampleBatch {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sequence",
+            |m: &SampleBatch| { &m.sequence },
+            |m: &mut SampleBatch| { &mut m.sequence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "startTimestamp",
+            |m: &SampleBatch| { &m.startTimestamp },
+            |m: &mut SampleBatch| { &mut m.startTimestamp },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "timestampDeltas",
+            |m: &SampleBatch| { &m.timestampDeltas },
+            |m: &mut SampleBatch| { &mut m.timestampDeltas },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "accel",
+            |m: &SampleBatch| { &m.accel },
+            |m: &mut SampleBatch| { &mut m.accel },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "mag",
+            |m: &SampleBatch| { &m.mag },
+            |m: &mut SampleBatch| { &mut m.mag },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SampleBatch>(
+            "SampleBatch",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SampleBatch {
+    const NAME: &'static str = "SampleBatch";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.sequence = is.read_uint32()?;
+                },
+                16 => {
+                    self.startTimestamp = is.read_int64()?;
+                },
+                26 => {
+                    is.read_repeated_packed_uint32_into(&mut self.timestampDeltas)?;
+                },
+                24 => {
+                    self.timestampDeltas.push(is.read_uint32()?);
+                },
+                34 => {
+                    is.read_repeated_packed_sint32_into(&mut self.accel)?;
+                },
+                32 => {
+                    self.accel.push(is.read_sint32()?);
+                },
+                42 => {
+                    is.read_repeated_packed_sint32_into(&mut self.mag)?;
+                },
+                40 => {
+                    self.mag.push(is.read_sint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.sequence);
+        }
+        if self.startTimestamp != 0 {
+            my_size += ::protobuf::rt::int64_size(2, self.startTimestamp);
+        }
+        my_size += ::protobuf::rt::vec_packed_uint32_size(3, &self.timestampDeltas);
+        my_size += ::protobuf::rt::vec_packed_sint32_size(4, &self.accel);
+        my_size += ::protobuf::rt::vec_packed_sint32_size(5, &self.mag);
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.sequence != 0 {
+            os.write_uint32(1, self.sequence)?;
+        }
+        if self.startTimestamp != 0 {
+            os.write_int64(2, self.startTimestamp)?;
+        }
+        os.write_repeated_packed_uint32(3, &self.timestampDeltas)?;
+        os.write_repeated_packed_sint32(4, &self.accel)?;
+        os.write_repeated_packed_sint32(5, &self.mag)?;
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SampleBatch {
+        SampleBatch::new()
+    }
+
+    fn clear(&mut self) {
+        self.sequence = 0;
+        self.startTimestamp = 0;
+        self.timestampDeltas.clear();
+        self.accel.clear();
+        self.mag.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SampleBatch {
+        static instance: SampleBatch = SampleBatch {
+            sequence: 0,
+            startTimestamp: 0,
+            timestampDeltas: ::std::vec::Vec::new(),
+            accel: ::std::vec::Vec::new(),
+            mag: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SampleBatch {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SampleBatch").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SampleBatch {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SampleBatch {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  configures the imu, zero fields use the default
+// @@protoc_insertion_point(message:SensorConfiguration)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SensorConfiguration {
+    // message fields
+    // @@protoc_insertion_point(field:SensorConfiguration.accelOdr)
+    pub accelOdr: u32,
+    // @@protoc_insertion_point(field:SensorConfiguration.magOdr)
+    pub magOdr: u32,
+    // @@protoc_insertion_point(field:SensorConfiguration.accelRange)
+    pub accelRange: ::protobuf::EnumOrUnknown<AccelRange>,
+    // @@protoc_insertion_point(field:SensorConfiguration.accelMode)
+    pub accelMode: ::protobuf::EnumOrUnknown<AccelMode>,
+    // special fields
+    // @@protoc_insertion_point(special_field:SensorConfiguration.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SensorConfiguration {
+    fn default() -> &'a SensorConfiguration {
+        <SensorConfiguration as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SensorConfiguration {
+    pub fn new() -> SensorConfiguration {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "accelOdr",
+            |m: &SensorConfiguration| { &m.accelOdr },
+            |m: &mut SensorConfiguration| { &mut m.accelOdr },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "magOdr",
+            |m: &SensorConfiguration| { &m.magOdr },
+            |m: &mut SensorConfiguration| { &mut m.magOdr },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "accelRange",
+            |m: &SensorConfiguration| { &m.accelRange },
+            |m: &mut SensorConfiguration| { &mut m.accelRange },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "accelMode",
+            |m: &SensorConfiguration| { &m.accelMode },
+            |m: &mut SensorConfiguration| { &mut m.accelMode },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SensorConfiguration>(
+            "SensorConfiguration",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SensorConfiguration {
+    const NAME: &'static str = "SensorConfiguration";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.accelOdr = is.read_uint32()?;
+                },
+                16 => {
+                    self.magOdr = is.read_uint32()?;
+                },
+                24 => {
+                    self.accelRange = is.read_enum_or_unknown()?;
+                },
+                32 => {
+                    self.accelMode = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.accelOdr != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.accelOdr);
+        }
+        if self.magOdr != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.magOdr);
+        }
+        if self.accelRange != ::protobuf::EnumOrUnknown::new(AccelRange::RANGE_DEFAULT) {
+            my_size += ::protobuf::rt::int32_size(3, self.accelRange.value());
+        }
+        if self.accelMode != ::protobuf::EnumOrUnknown::new(AccelMode::MODE_DEFAULT) {
+            my_size += ::protobuf::rt::int32_size(4, self.accelMode.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.accelOdr != 0 {
+            os.write_uint32(1, self.accelOdr)?;
+        }
+        if self.magOdr != 0 {
+            os.write_uint32(2, self.magOdr)?;
+        }
+        if self.accelRange != ::protobuf::EnumOrUnknown::new(AccelRange::RANGE_DEFAULT) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.accelRange))?;
+        }
+        if self.accelMode != ::protobuf::EnumOrUnknown::new(AccelMode::MODE_DEFAULT) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.accelMode))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SensorConfiguration {
+        SensorConfiguration::new()
+    }
+
+    fn clear(&mut self) {
+        self.accelOdr = 0;
+        self.magOdr = 0;
+        self.accelRange = ::protobuf::EnumOrUnknown::new(AccelRange::RANGE_DEFAULT);
+        self.accelMode = ::protobuf::EnumOrUnknown::new(AccelMode::MODE_DEFAULT);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SensorConfiguration {
+        static instance: SensorConfiguration = SensorConfiguration {
+            accelOdr: 0,
+            magOdr: 0,
+            accelRange: ::protobuf::EnumOrUnknown::from_i32(0),
+            accelMode: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SensorConfiguration {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SensorConfiguration").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SensorConfiguration {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SensorConfiguration {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:Calibrate)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Calibrate {
+    // message fields
+    // @@protoc_insertion_point(field:Calibrate.step)
+    pub step: ::protobuf::EnumOrUnknown<CalibrationStep>,
+    // special fields
+    // @@protoc_insertion_point(special_field:Calibrate.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Calibrate {
+    fn default() -> &'a Calibrate {
+        <Calibrate as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Calibrate {
+    pub fn new() -> Calibrate {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "step",
+            |m: &Calibrate| { &m.step },
+            |m: &mut Calibrate| { &mut m.step },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Calibrate>(
+            "Calibrate",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Calibrate {
+    const NAME: &'static str = "Calibrate";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.step = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.step != ::protobuf::EnumOrUnknown::new(CalibrationStep::CALIBRATION_STATUS) {
+            my_size += ::protobuf::rt::int32_size(1, self.step.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.step != ::protobuf::EnumOrUnknown::new(CalibrationStep::CALIBRATION_STATUS) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.step))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Calibrate {
+        Calibrate::new()
+    }
+
+    fn clear(&mut self) {
+        self.step = ::protobuf::EnumOrUnknown::new(CalibrationStep::CALIBRATION_STATUS);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Calibrate {
+        static instance: Calibrate = Calibrate {
+            step: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Calibrate {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Calibrate").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Calibrate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Calibrate {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  sent by the sensor after every Calibrate, before the response
+// @@protoc_insertion_point(message:CalibrationStatus)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CalibrationStatus {
+    // message fields
+    // @@protoc_insertion_point(field:CalibrationStatus.accelCalibrated)
+    pub accelCalibrated: bool,
+    // @@protoc_insertion_point(field:CalibrationStatus.magCalibrated)
+    pub magCalibrated: bool,
+    // @@protoc_insertion_point(field:CalibrationStatus.missingPoses)
+    pub missingPoses: ::std::vec::Vec<::protobuf::EnumOrUnknown<Pose>>,
+    // @@protoc_insertion_point(field:CalibrationStatus.magSamples)
+    pub magSamples: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:CalibrationStatus.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CalibrationStatus {
+    fn default() -> &'a CalibrationStatus {
+        <CalibrationStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CalibrationStatus {
+    pub fn new() -> CalibrationStatus {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "accelCalibrated",
+            |m: &CalibrationStatus| { &m.accelCalibrated },
+            |m: &mut CalibrationStatus| { &mut m.accelCalibrated },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "magCalibrated",
+            |m: &CalibrationStatus| { &m.magCalibrated },
+            |m: &mut CalibrationStatus| { &mut m.magCalibrated },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "missingPoses",
+            |m: &CalibrationStatus| { &m.missingPoses },
+            |m: &mut CalibrationStatus| { &mut m.missingPoses },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "magSamples",
+            |m: &CalibrationStatus| { &m.magSamples },
+            |m: &mut CalibrationStatus| { &mut m.magSamples },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CalibrationStatus>(
+            "CalibrationStatus",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CalibrationStatus {
+    const NAME: &'static str = "CalibrationStatus";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.accelCalibrated = is.read_bool()?;
+                },
+                16 => {
+                    self.magCalibrated = is.read_bool()?;
+                },
+                24 => {
+                    self.missingPoses.push(is.read_enum_or_unknown()?);
+                },
+                26 => {
+                    ::protobuf::rt::read_repeated_packed_enum_or_unknown_into(is, &mut self.missingPoses)?
+                },
+                32 => {
+                    self.magSamples = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.accelCalibrated != false {
+            my_size += 1 + 1;
+        }
+        if self.magCalibrated != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::vec_packed_enum_or_unknown_size(3, &self.missingPoses);
+        if self.magSamples != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.magSamples);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.accelCalibrated != false {
+            os.write_bool(1, self.accelCalibrated)?;
+        }
+        if self.magCalibrated != false {
+            os.write_bool(2, self.magCalibrated)?;
+        }
+        os.write_repeated_packed_enum_or_unknown(3, &self.missingPoses)?;
+        if self.magSamples != 0 {
+            os.write_uint32(4, self.magSamples)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CalibrationStatus {
+        CalibrationStatus::new()
+    }
+
+    fn clear(&mut self) {
+        self.accelCalibrated = false;
+        self.magCalibrated = false;
+        self.missingPoses.clear();
+        self.magSamples = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CalibrationStatus {
+        static instance: CalibrationStatus = CalibrationStatus {
+            accelCalibrated: false,
+            magCalibrated: false,
+            missingPoses: ::std::vec::Vec::new(),
+            magSamples: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CalibrationStatus {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CalibrationStatus").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CalibrationStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CalibrationStatus {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  a session stored on the sensor, ids count up in the order the sessions were recorded
+// @@protoc_insertion_point(message:StoredSession)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct StoredSession {
+    // message fields
+    // @@protoc_insertion_point(field:StoredSession.id)
+    pub id: u32,
+    // @@protoc_insertion_point(field:StoredSession.size)
+    pub size: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:StoredSession.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a StoredSession {
+    fn default() -> &'a StoredSession {
+        <StoredSession as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StoredSession {
+    pub fn new() -> StoredSession {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &StoredSession| { &m.id },
+            |m: &mut StoredSession| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &StoredSession| { &m.size },
+            |m: &mut StoredSession| { &mut m.size },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<StoredSession>(
+            "StoredSession",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for StoredSession {
+    const NAME: &'static str = "StoredSession";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                16 => {
+                    self.size = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.size);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        if self.size != 0 {
+            os.write_uint32(2, self.size)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> StoredSession {
+        StoredSession::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.size = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static StoredSession {
+        static instance: StoredSession = StoredSession {
+            id: 0,
+            size: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for StoredSession {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("StoredSession").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for StoredSession {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StoredSession {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  lists the stored sessions with an id of at least from, oldest first, answered with a SessionList
+// @@protoc_insertion_point(message:ListSessions)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ListSessions {
+    // message fields
+    // @@protoc_insertion_point(field:ListSessions.from)
+    pub from: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:ListSessions.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListSessions {
+    fn default() -> &'a ListSessions {
+        <ListSessions as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListSessions {
+    pub fn new() -> ListSessions {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "from",
+            |m: &ListSessions| { &m.from },
+            |m: &mut ListSessions| { &mut m.from },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListSessions>(
+            "ListSessions",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListSessions {
+    const NAME: &'static str = "ListSessions";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.from = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.from != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.from);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.from != 0 {
+            os.write_uint32(1, self.from)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListSessions {
+        ListSessions::new()
+    }
+
+    fn clear(&mut self) {
+        self.from = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListSessions {
+        static instance: ListSessions = ListSessions {
+            from: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListSessions {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListSessions").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListSessions {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListSessions {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:SessionList)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SessionList {
+    // message fields
+    // @@protoc_insertion_point(field:SessionList.sessions)
+    pub sessions: ::std::vec::Vec<StoredSession>,
+    // @@protoc_insertion_point(field:SessionList.more)
+    pub more: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:SessionList.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SessionList {
+    fn default() -> &'a SessionList {
+        <SessionList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SessionList {
+    pub fn new() -> SessionList {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "sessions",
+            |m: &SessionList| { &m.sessions },
+            |m: &mut SessionList| { &mut m.sessions },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "more",
+            |m: &SessionList| { &m.more },
+            |m: &mut SessionList| { &mut m.more },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SessionList>(
+            "SessionList",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SessionList {
+    const NAME: &'static str = "SessionList";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.sessions.push(is.read_message()?);
+                },
+                16 => {
+                    self.more = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.sessions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.more != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.sessions {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.more != false {
+            os.write_bool(2, self.more)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SessionList {
+        SessionList::new()
+    }
+
+    fn clear(&mut self) {
+        self.sessions.clear();
+        self.more = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SessionList {
+        static instance: SessionList = SessionList {
+            sessions: ::std::vec::Vec::new(),
+            more: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SessionList {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SessionList").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SessionList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SessionList {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  asks for the bytes of a stored session from offset on, answered with a SessionChunk
+// @@protoc_insertion_point(message:DownloadSession)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DownloadSession {
+    // message fields
+    // @@protoc_insertion_point(field:DownloadSession.id)
+    pub id: u32,
+    // @@protoc_insertion_point(field:DownloadSession.offset)
+    pub offset: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:DownloadSession.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DownloadSession {
+    fn default() -> &'a DownloadSession {
+        <DownloadSession as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DownloadSession {
+    pub fn new() -> DownloadSession {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &DownloadSession| { &m.id },
+            |m: &mut DownloadSession| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "offset",
+            |m: &DownloadSession| { &m.offset },
+            |m: &mut DownloadSession| { &mut m.offset },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DownloadSession>(
+            "DownloadSession",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DownloadSession {
+    const NAME: &'static str = "DownloadSession";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                16 => {
+                    self.offset = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.offset);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        if self.offset != 0 {
+            os.write_uint32(2, self.offset)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DownloadSession {
+        DownloadSession::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.offset = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DownloadSession {
+        static instance: DownloadSession = DownloadSession {
+            id: 0,
+            offset: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DownloadSession {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DownloadSession").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DownloadSession {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DownloadSession {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  part of a stored session, the whole session is the Rep it was sent as
+// @@protoc_insertion_point(message:SessionChunk)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SessionChunk {
+    // message fields
+    // @@protoc_insertion_point(field:SessionChunk.id)
+    pub id: u32,
+    // @@protoc_insertion_point(field:SessionChunk.offset)
+    pub offset: u32,
+    // @@protoc_insertion_point(field:SessionChunk.size)
+    pub size: u32,
+    // @@protoc_insertion_point(field:SessionChunk.data)
+    pub data: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:SessionChunk.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SessionChunk {
+    fn default() -> &'a SessionChunk {
+        <SessionChunk as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SessionChunk {
+    pub fn new() -> SessionChunk {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &SessionChunk| { &m.id },
+            |m: &mut SessionChunk| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "offset",
+            |m: &SessionChunk| { &m.offset },
+            |m: &mut SessionChunk| { &mut m.offset },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &SessionChunk| { &m.size },
+            |m: &mut SessionChunk| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data",
+            |m: &SessionChunk| { &m.data },
+            |m: &mut SessionChunk| { &mut m.data },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SessionChunk>(
+            "SessionChunk",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SessionChunk {
+    const NAME: &'static str = "SessionChunk";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                16 => {
+                    self.offset = is.read_uint32()?;
+                },
+                24 => {
+                    self.size = is.read_uint32()?;
+                },
+                34 => {
+                    self.data = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.offset);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.size);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        if self.offset != 0 {
+            os.write_uint32(2, self.offset)?;
+        }
+        if self.size != 0 {
+            os.write_uint32(3, self.size)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(4, &self.data)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SessionChunk {
+        SessionChunk::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.offset = 0;
+        self.size = 0;
+        self.data.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SessionChunk {
+        static instance: SessionChunk = SessionChunk {
+            id: 0,
+            offset: 0,
+            size: 0,
+            data: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SessionChunk {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SessionChunk").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SessionChunk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SessionChunk {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  erases every stored session
+// @@protoc_insertion_point(message:EraseSessions)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct EraseSessions {
+    // special fields
+    // @@protoc_insertion_point(special_field:EraseSessions.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a EraseSessions {
+    fn default() -> &'a EraseSessions {
+        <EraseSessions as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EraseSessions {
+    pub fn new() -> EraseSessions {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<EraseSessions>(
+            "EraseSessions",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for EraseSessions {
+    const NAME: &'static str = "EraseSessions";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> EraseSessions {
+        EraseSessions::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static EraseSessions {
+        static instance: EraseSessions = EraseSessions {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for EraseSessions {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("EraseSessions").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for EraseSessions {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EraseSessions {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  what the sensor keeps across restarts, stored behind a version byte
+// @@protoc_insertion_point(message:Settings)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Settings {
+    // message fields
+    // @@protoc_insertion_point(field:Settings.algorithim)
+    pub algorithim: ::protobuf::MessageField<AlgorithimConfiguration>,
+    // @@protoc_insertion_point(field:Settings.sensor)
+    pub sensor: ::protobuf::MessageField<SensorConfiguration>,
+    // special fields
+    // @@protoc_insertion_point(special_field:Settings.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Settings {
+    fn default() -> &'a Settings {
+        <Settings as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Settings {
+    pub fn new() -> Settings {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, AlgorithimConfiguration>(
+            "algorithim",
+            |m: &Settings| { &m.algorithim },
+            |m: &mut Settings| { &mut m.algorithim },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, SensorConfiguration>(
+            "sensor",
+            |m: &Settings| { &m.sensor },
+            |m: &mut Settings| { &mut m.sensor },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Settings>(
+            "Settings",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Settings {
+    const NAME: &'static str = "Settings";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.algorithim)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sensor)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.algorithim.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.sensor.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.algorithim.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.sensor.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Settings {
+        Settings::new()
+    }
+
+    fn clear(&mut self) {
+        self.algorithim.clear();
+        self.sensor.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Settings {
+        static instance: Settings = Settings {
+            algorithim: ::protobuf::MessageField::none(),
+            sensor: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Settings {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Settings").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Settings {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Settings {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  sent by the sensor for every message it receives
+// @@protoc_insertion_point(message:Response)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Response {
+    // message fields
+    // @@protoc_insertion_point(field:Response.requestId)
+    pub requestId: u32,
+    // @@protoc_insertion_point(field:Response.success)
+    pub success: bool,
+    // @@protoc_insertion_point(field:Response.error)
+    pub error: ::protobuf::EnumOrUnknown<ErrorCode>,
+    // special fields
+    // @@protoc_insertion_point(special_field:Response.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Response {
+    fn default() -> &'a Response {
+        <Response as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Response {
+    pub fn new() -> Response {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "requestId",
+            |m: &Response| { &m.requestId },
+            |m: &mut Response| { &mut m.requestId },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "success",
+            |m: &Response| { &m.success },
+            |m: &mut Response| { &mut m.success },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &Response| { &m.error },
+            |m: &mut Response| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Response>(
+            "Response",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Response {
+    const NAME: &'static str = "Response";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.requestId = is.read_uint32()?;
+                },
+                16 => {
+                    self.success = is.read_bool()?;
+                },
+                24 => {
+                    self.error = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.requestId != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.requestId);
+        }
+        if self.success != false {
+            my_size += 1 + 1;
+        }
+        if self.error != ::protobuf::EnumOrUnknown::new(ErrorCode::NONE) {
+            my_size += ::protobuf::rt::int32_size(3, self.error.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.requestId != 0 {
+            os.write_uint32(1, self.requestId)?;
+        }
+        if self.success != false {
+            os.write_bool(2, self.success)?;
+        }
+        if self.error != ::protobuf::EnumOrUnknown::new(ErrorCode::NONE) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.error))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Response {
+        Response::new()
+    }
+
+    fn clear(&mut self) {
+        self.requestId = 0;
+        self.success = false;
+        self.error = ::protobuf::EnumOrUnknown::new(ErrorCode::NONE);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Response {
+        static instance: Response = Response {
+            requestId: 0,
+            success: false,
+            error: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Response {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Response").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Response {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Response {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///
+/// transfer Container
+// @@protoc_insertion_point(message:Message)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Message {
+    // message fields
+    // @@protoc_insertion_point(field:Message.requestId)
+    pub requestId: u32,
+    // message oneof groups
+    pub content: ::std::option::Option<message::Content>,
+    // special fields
+    // @@protoc_insertion_point(special_field:Message.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Message {
+    fn default() -> &'a Message {
+        <Message as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Message {
+    pub fn new() -> Message {
+        ::std::default::Default::default()
+    }
+
+    // .AlgorithimConfiguration Algorithim = 1;
+
+    pub fn Algorithim(&self) -> &AlgorithimConfiguration {
+        match self.content {
+            ::std::option::Option::Some(message::Content::Algorithim(ref v)) => v,
+            _ => <AlgorithimConfiguration as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_Algorithim(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_Algorithim(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::Algorithim(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_Algorithim(&mut self, v: AlgorithimConfiguration) {
+        self.content = ::std::option::Option::Some(message::Content::Algorithim(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_Algorithim(&mut self) -> &mut AlgorithimConfiguration {
+        if let ::std::option::Option::Some(message::Content::Algorithim(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::Algorithim(AlgorithimConfiguration::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::Algorithim(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_Algorithim(&mut self) -> AlgorithimConfiguration {
+        if self.has_Algorithim() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::Algorithim(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AlgorithimConfiguration::new()
+        }
+    }
+
+    // .Rep rep = 2;
+
+    pub fn rep(&self) -> &Rep {
+        match self.content {
+            ::std::option::Option::Some(message::Content::Rep(ref v)) => v,
+            _ => <Rep as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_rep(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_rep(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::Rep(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rep(&mut self, v: Rep) {
+        self.content = ::std::option::Option::Some(message::Content::Rep(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rep(&mut self) -> &mut Rep {
+        if let ::std::option::Option::Some(message::Content::Rep(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::Rep(Rep::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::Rep(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_rep(&mut self) -> Rep {
+        if self.has_rep() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::Rep(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Rep::new()
+        }
+    }
+
+    // .Response response = 4;
+
+    pub fn response(&self) -> &Response {
+        match self.content {
+            ::std::option::Option::Some(message::Content::Response(ref v)) => v,
+            _ => <Response as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_response(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_response(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::Response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_response(&mut self, v: Response) {
+        self.content = ::std::option::Option::Some(message::Content::Response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_response(&mut self) -> &mut Response {
+        if let ::std::option::Option::Some(message::Content::Response(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::Response(Response::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::Response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_response(&mut self) -> Response {
+        if self.has_response() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::Response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Response::new()
+        }
+    }
+
+    // .RawStream rawStream = 5;
+
+    pub fn rawStream(&self) -> &RawStream {
+        match self.content {
+            ::std::option::Option::Some(message::Content::RawStream(ref v)) => v,
+            _ => <RawStream as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_rawStream(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_rawStream(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::RawStream(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rawStream(&mut self, v: RawStream) {
+        self.content = ::std::option::Option::Some(message::Content::RawStream(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rawStream(&mut self) -> &mut RawStream {
+        if let ::std::option::Option::Some(message::Content::RawStream(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::RawStream(RawStream::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::RawStream(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_rawStream(&mut self) -> RawStream {
+        if self.has_rawStream() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::RawStream(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RawStream::new()
+        }
+    }
+
+    // .SampleBatch sampleBatch = 6;
+
+    pub fn sampleBatch(&self) -> &SampleBatch {
+        match self.content {
+            ::std::option::Option::Some(message::Content::SampleBatch(ref v)) => v,
+            _ => <SampleBatch as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_sampleBatch(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_sampleBatch(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::SampleBatch(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sampleBatch(&mut self, v: SampleBatch) {
+        self.content = ::std::option::Option::Some(message::Content::SampleBatch(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sampleBatch(&mut self) -> &mut SampleBatch {
+        if let ::std::option::Option::Some(message::Content::SampleBatch(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::SampleBatch(SampleBatch::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::SampleBatch(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_sampleBatch(&mut self) -> SampleBatch {
+        if self.has_sampleBatch() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::SampleBatch(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SampleBatch::new()
+        }
+    }
+
+    // .SensorConfiguration sensorConfiguration = 7;
+
+    pub fn sensorConfiguration(&self) -> &SensorConfiguration {
+        match self.content {
+            ::std::option::Option::Some(message::Content::SensorConfiguration(ref v)) => v,
+            _ => <SensorConfiguration as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_sensorConfiguration(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_sensorConfiguration(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::SensorConfiguration(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sensorConfiguration(&mut self, v: SensorConfiguration) {
+        self.content = ::std::option::Option::Some(message::Content::SensorConfiguration(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sensorConfiguration(&mut self) -> &mut SensorConfiguration {
+        if let ::std::option::Option::Some(message::Content::SensorConfiguration(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::SensorConfiguration(SensorConfiguration::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::SensorConfiguration(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_sensorConfiguration(&mut self) -> SensorConfiguration {
+        if self.has_sensorConfiguration() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::SensorConfiguration(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SensorConfiguration::new()
+        }
+    }
+
+    // .Calibrate calibrate = 8;
+
+    pub fn calibrate(&self) -> &Calibrate {
+        match self.content {
+            ::std::option::Option::Some(message::Content::Calibrate(ref v)) => v,
+            _ => <Calibrate as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_calibrate(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_calibrate(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::Calibrate(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_calibrate(&mut self, v: Calibrate) {
+        self.content = ::std::option::Option::Some(message::Content::Calibrate(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_calibrate(&mut self) -> &mut Calibrate {
+        if let ::std::option::Option::Some(message::Content::Calibrate(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::Calibrate(Calibrate::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::Calibrate(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_calibrate(&mut self) -> Calibrate {
+        if self.has_calibrate() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::Calibrate(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Calibrate::new()
+        }
+    }
+
+    // .CalibrationStatus calibrationStatus = 9;
+
+    pub fn calibrationStatus(&self) -> &CalibrationStatus {
+        match self.content {
+            ::std::option::Option::Some(message::Content::CalibrationStatus(ref v)) => v,
+            _ => <CalibrationStatus as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_calibrationStatus(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_calibrationStatus(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::CalibrationStatus(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_calibrationStatus(&mut self, v: CalibrationStatus) {
+        self.content = ::std::option::Option::Some(message::Content::CalibrationStatus(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_calibrationStatus(&mut self) -> &mut CalibrationStatus {
+        if let ::std::option::Option::Some(message::Content::CalibrationStatus(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::CalibrationStatus(CalibrationStatus::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::CalibrationStatus(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_calibrationStatus(&mut self) -> CalibrationStatus {
+        if self.has_calibrationStatus() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::CalibrationStatus(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CalibrationStatus::new()
+        }
+    }
+
+    // .ListSessions listSessions = 10;
+
+    pub fn listSessions(&self) -> &ListSessions {
+        match self.content {
+            ::std::option::Option::Some(message::Content::ListSessions(ref v)) => v,
+            _ => <ListSessions as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_listSessions(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_listSessions(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::ListSessions(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_listSessions(&mut self, v: ListSessions) {
+        self.content = ::std::option::Option::Some(message::Content::ListSessions(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_listSessions(&mut self) -> &mut ListSessions {
+        if let ::std::option::Option::Some(message::Content::ListSessions(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::ListSessions(ListSessions::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::ListSessions(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_listSessions(&mut self) -> ListSessions {
+        if self.has_listSessions() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::ListSessions(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ListSessions::new()
+        }
+    }
+
+    // .SessionList sessionList = 11;
+
+    pub fn sessionList(&self) -> &SessionList {
+        match self.content {
+            ::std::option::Option::Some(message::Content::SessionList(ref v)) => v,
+            _ => <SessionList as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_sessionList(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_sessionList(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::SessionList(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sessionList(&mut self, v: SessionList) {
+        self.content = ::std::option::Option::Some(message::Content::SessionList(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sessionList(&mut self) -> &mut SessionList {
+        if let ::std::option::Option::Some(message::Content::SessionList(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::SessionList(SessionList::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::SessionList(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_sessionList(&mut self) -> SessionList {
+        if self.has_sessionList() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::SessionList(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SessionList::new()
+        }
+    }
+
+    // .DownloadSession downloadSession = 12;
+
+    pub fn downloadSession(&self) -> &DownloadSession {
+        match self.content {
+            ::std::option::Option::Some(message::Content::DownloadSession(ref v)) => v,
+            _ => <DownloadSession as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_downloadSession(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_downloadSession(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::DownloadSession(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_downloadSession(&mut self, v: DownloadSession) {
+        self.content = ::std::option::Option::Some(message::Content::DownloadSession(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_downloadSession(&mut self) -> &mut DownloadSession {
+        if let ::std::option::Option::Some(message::Content::DownloadSession(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::DownloadSession(DownloadSession::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::DownloadSession(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_downloadSession(&mut self) -> DownloadSession {
+        if self.has_downloadSession() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::DownloadSession(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DownloadSession::new()
+        }
+    }
+
+    // .SessionChunk sessionChunk = 13;
+
+    pub fn sessionChunk(&self) -> &SessionChunk {
+        match self.content {
+            ::std::option::Option::Some(message::Content::SessionChunk(ref v)) => v,
+            _ => <SessionChunk as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_sessionChunk(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_sessionChunk(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::SessionChunk(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sessionChunk(&mut self, v: SessionChunk) {
+        self.content = ::std::option::Option::Some(message::Content::SessionChunk(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sessionChunk(&mut self) -> &mut SessionChunk {
+        if let ::std::option::Option::Some(message::Content::SessionChunk(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::SessionChunk(SessionChunk::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::SessionChunk(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_sessionChunk(&mut self) -> SessionChunk {
+        if self.has_sessionChunk() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::SessionChunk(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SessionChunk::new()
+        }
+    }
+
+    // .EraseSessions eraseSessions = 14;
+
+    pub fn eraseSessions(&self) -> &EraseSessions {
+        match self.content {
+            ::std::option::Option::Some(message::Content::EraseSessions(ref v)) => v,
+            _ => <EraseSessions as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_eraseSessions(&mut self) {
+        self.content = ::std::option::Option::None;
+    }
+
+    pub fn has_eraseSessions(&self) -> bool {
+        match self.content {
+            ::std::option::Option::Some(message::Content::EraseSessions(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_eraseSessions(&mut self, v: EraseSessions) {
+        self.content = ::std::option::Option::Some(message::Content::EraseSessions(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_eraseSessions(&mut self) -> &mut EraseSessions {
+        if let ::std::option::Option::Some(message::Content::EraseSessions(_)) = self.content {
+        } else {
+            self.content = ::std::option::Option::Some(message::Content::EraseSessions(EraseSessions::new()));
+        }
+        match self.content {
+            ::std::option::Option::Some(message::Content::EraseSessions(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_eraseSessions(&mut self) -> EraseSessions {
+        if self.has_eraseSessions() {
+            match self.content.take() {
+                ::std::option::Option::Some(message::Content::EraseSessions(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            EraseSessions::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(14);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AlgorithimConfiguration>(
+            "Algorithim",
+            Message::has_Algorithim,
+            Message::Algorithim,
+            Message::mut_Algorithim,
+            Message::set_Algorithim,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Rep>(
+            "rep",
+            Message::has_rep,
+            Message::rep,
+            Message::mut_rep,
+            Message::set_rep,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Response>(
+            "response",
+            Message::has_response,
+            Message::response,
+            Message::mut_response,
+            Message::set_response,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, RawStream>(
+            "rawStream",
+            Message::has_rawStream,
+            Message::rawStream,
+            Message::mut_rawStream,
+            Message::set_rawStream,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SampleBatch>(
+            "sampleBatch",
+            Message::has_sampleBatch,
+            Message::sampleBatch,
+            Message::mut_sampleBatch,
+            Message::set_sampleBatch,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SensorConfiguration>(
+            "sensorConfiguration",
+            Message::has_sensorConfiguration,
+            Message::sensorConfiguration,
+            Message::mut_sensorConfiguration,
+            Message::set_sensorConfiguration,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Calibrate>(
+            "calibrate",
+            Message::has_calibrate,
+            Message::calibrate,
+            Message::mut_calibrate,
+            Message::set_calibrate,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, CalibrationStatus>(
+            "calibrationStatus",
+            Message::has_calibrationStatus,
+            Message::calibrationStatus,
+            Message::mut_calibrationStatus,
+            Message::set_calibrationStatus,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ListSessions>(
+            "listSessions",
+            Message::has_listSessions,
+            Message::listSessions,
+            Message::mut_listSessions,
+            Message::set_listSessions,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SessionList>(
+            "sessionList",
+            Message::has_sessionList,
+            Message::sessionList,
+            Message::mut_sessionList,
+            Message::set_sessionList,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DownloadSession>(
+            "downloadSession",
+            Message::has_downloadSession,
+            Message::downloadSession,
+            Message::mut_downloadSession,
+            Message::set_downloadSession,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SessionChunk>(
+            "sessionChunk",
+            Message::has_sessionChunk,
+            Message::sessionChunk,
+            Message::mut_sessionChunk,
+            Message::set_sessionChunk,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, EraseSessions>(
+            "eraseSessions",
+            Message::has_eraseSessions,
+            Message::eraseSessions,
+            Message::mut_eraseSessions,
+            Message::set_eraseSessions,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "requestId",
+            |m: &Message| { &m.requestId },
+            |m: &mut Message| { &mut m.requestId },
+        ));
+        oneofs.push(message::Content::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Message>(
+            "Message",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Message {
+    const NAME: &'static str = "Message";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.content = ::std::option::Option::Some(message::Content::Algorithim(is.read_message()?));
+                },
+                18 => {
+                    self.content = ::std::option::Option::Some(message::Content::Rep(is.read_message()?));
+                },
+                34 => {
+                    self.content = ::std::option::Option::Some(message::Content::Response(is.read_message()?));
+                },
+                42 => {
+                    self.content = ::std::option::Option::Some(message::Content::RawStream(is.read_message()?));
+                },
+                50 => {
+                    self.content = ::std::option::Option::Some(message::Content::SampleBatch(is.read_message()?));
+                },
+                58 => {
+                    self.content = ::std::option::Option::Some(message::Content::SensorConfiguration(is.read_message()?));
+                },
+                66 => {
+                    self.content = ::std::option::Option::Some(message::Content::Calibrate(is.read_message()?));
+                },
+                74 => {
+                    self.content = ::std::option::Option::Some(message::Content::CalibrationStatus(is.read_message()?));
+                },
+                82 => {
+                    self.content = ::std::option::Option::Some(message::Content::ListSessions(is.read_message()?));
+                },
+                90 => {
+                    self.content = ::std::option::Option::Some(message::Content::SessionList(is.read_message()?));
+                },
+                98 => {
+                    self.content = ::std::option::Option::Some(message::Content::DownloadSession(is.read_message()?));
+                },
+                106 => {
+                    self.content = ::std::option::Option::Some(message::Content::SessionChunk(is.read_message()?));
+                },
+                114 => {
+                    self.content = ::std::option::Option::Some(message::Content::EraseSessions(is.read_message()?));
+                },
+                24 => {
+                    self.requestId = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.requestId != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.requestId);
+        }
+        if let ::std::option::Option::Some(ref v) = self.content {
+            match v {
+                &message::Content::Algorithim(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &message::Content::Rep(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &message::Content::Response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &message::Content::RawStream(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &message::Content::SampleBatch(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &message::Content::SensorConfiguration(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &message::Content::Calibrate(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &message::Content::CalibrationStatus(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &message::Content::ListSessions(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &message::Content::SessionList(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &message::Content::DownloadSession(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &message::Content::SessionChunk(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &message::Content::EraseSessions(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.requestId != 0 {
+            os.write_uint32(3, self.requestId)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.content {
+            match v {
+                &message::Content::Algorithim(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &message::Content::Rep(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &message::Content::Response(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+                &message::Content::RawStream(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+                &message::Content::SampleBatch(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+                },
+                &message::Content::SensorConfiguration(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+                },
+                &message::Content::Calibrate(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(8, v, os)?;
+                },
+                &message::Content::CalibrationStatus(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
+                },
+                &message::Content::ListSessions(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+                },
+                &message::Content::SessionList(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(11, v, os)?;
+                },
+                &message::Content::DownloadSession(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(12, v, os)?;
+                },
+                &message::Content::SessionChunk(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(13, v, os)?;
+                },
+                &message::Content::EraseSessions(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(14, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Message {
+        Message::new()
+    }
+
+    fn clear(&mut self) {
+        self.content = ::std::option::Option::None;
+        self.content = ::std::option::Option::None;
+        self.content = ::std::option::Option::None;
+        self.content = ::std::option::Option::None;
+        self.content = ::std::option::Option::None;
+        self.content = ::std::option::Option::None;
+        self.content = ::std::option::Option::None;
+        self.content = ::std::option::Option::None;
+        self.content = ::std::option::Option::None;
+        self.content = ::std::option::Option::None;
+        self.content = ::std::option::Option::None;
+        self.content = ::std::option::Option::None;
         self.content = ::std::option::Option::None;
-        self.content = ::std::option::Option::None;
+        self.requestId = 0;
         self.special_fields.clear();
     }
 
     fn default_instance() -> &'static Message {
         static instance: Message = Message {
+            requestId: 0,
             content: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
@@ -1689,6 +4560,28 @@ pub mod message {
         Algorithim(super::AlgorithimConfiguration),
         // @@protoc_insertion_point(oneof_field:Message.rep)
         Rep(super::Rep),
+        // @@protoc_insertion_point(oneof_field:Message.response)
+        Response(super::Response),
+        // @@protoc_insertion_point(oneof_field:Message.rawStream)
+        RawStream(super::RawStream),
+        // @@protoc_insertion_point(oneof_field:Message.sampleBatch)
+        SampleBatch(super::SampleBatch),
+        // @@protoc_insertion_point(oneof_field:Message.sensorConfiguration)
+        SensorConfiguration(super::SensorConfiguration),
+        // @@protoc_insertion_point(oneof_field:Message.calibrate)
+        Calibrate(super::Calibrate),
+        // @@protoc_insertion_point(oneof_field:Message.calibrationStatus)
+        CalibrationStatus(super::CalibrationStatus),
+        // @@protoc_insertion_point(oneof_field:Message.listSessions)
+        ListSessions(super::ListSessions),
+        // @@protoc_insertion_point(oneof_field:Message.sessionList)
+        SessionList(super::SessionList),
+        // @@protoc_insertion_point(oneof_field:Message.downloadSession)
+        DownloadSession(super::DownloadSession),
+        // @@protoc_insertion_point(oneof_field:Message.sessionChunk)
+        SessionChunk(super::SessionChunk),
+        // @@protoc_insertion_point(oneof_field:Message.eraseSessions)
+        EraseSessions(super::EraseSessions),
     }
 
     impl ::protobuf::Oneof for Content {
@@ -1708,84 +4601,939 @@ pub mod message {
     }
 }
 
+///  how the height of every bounce is worked out
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:HeightModel)
+pub enum HeightModel {
+    // @@protoc_insertion_point(enum_value:HeightModel.FLIGHT_TIME)
+    FLIGHT_TIME = 0,
+    // @@protoc_insertion_point(enum_value:HeightModel.TAKEOFF_VELOCITY)
+    TAKEOFF_VELOCITY = 1,
+}
+
+impl ::protobuf::Enum for HeightModel {
+    const NAME: &'static str = "HeightModel";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<HeightModel> {
+        match value {
+            0 => ::std::option::Option::Some(HeightModel::FLIGHT_TIME),
+            1 => ::std::option::Option::Some(HeightModel::TAKEOFF_VELOCITY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<HeightModel> {
+        match str {
+            "FLIGHT_TIME" => ::std::option::Option::Some(HeightModel::FLIGHT_TIME),
+            "TAKEOFF_VELOCITY" => ::std::option::Option::Some(HeightModel::TAKEOFF_VELOCITY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [HeightModel] = &[
+        HeightModel::FLIGHT_TIME,
+        HeightModel::TAKEOFF_VELOCITY,
+    ];
+}
+
+impl ::protobuf::EnumFull for HeightModel {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("HeightModel").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for HeightModel {
+    fn default() -> Self {
+        HeightModel::FLIGHT_TIME
+    }
+}
+
+impl HeightModel {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<HeightModel>("HeightModel")
+    }
+}
+
+///
+/// Sensor
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:AccelRange)
+pub enum AccelRange {
+    // @@protoc_insertion_point(enum_value:AccelRange.RANGE_DEFAULT)
+    RANGE_DEFAULT = 0,
+    // @@protoc_insertion_point(enum_value:AccelRange.RANGE_2G)
+    RANGE_2G = 1,
+    // @@protoc_insertion_point(enum_value:AccelRange.RANGE_4G)
+    RANGE_4G = 2,
+    // @@protoc_insertion_point(enum_value:AccelRange.RANGE_8G)
+    RANGE_8G = 3,
+    // @@protoc_insertion_point(enum_value:AccelRange.RANGE_16G)
+    RANGE_16G = 4,
+}
+
+impl ::protobuf::Enum for AccelRange {
+    const NAME: &'static str = "AccelRange";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<AccelRange> {
+        match value {
+            0 => ::std::option::Option::Some(AccelRange::RANGE_DEFAULT),
+            1 => ::std::option::Option::Some(AccelRange::RANGE_2G),
+            2 => ::std::option::Option::Some(AccelRange::RANGE_4G),
+            3 => ::std::option::Option::Some(AccelRange::RANGE_8G),
+            4 => ::std::option::Option::Some(AccelRange::RANGE_16G),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<AccelRange> {
+        match str {
+            "RANGE_DEFAULT" => ::std::option::Option::Some(AccelRange::RANGE_DEFAULT),
+            "RANGE_2G" => ::std::option::Option::Some(AccelRange::RANGE_2G),
+            "RANGE_4G" => ::std::option::Option::Some(AccelRange::RANGE_4G),
+            "RANGE_8G" => ::std::option::Option::Some(AccelRange::RANGE_8G),
+            "RANGE_16G" => ::std::option::Option::Some(AccelRange::RANGE_16G),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [AccelRange] = &[
+        AccelRange::RANGE_DEFAULT,
+        AccelRange::RANGE_2G,
+        AccelRange::RANGE_4G,
+        AccelRange::RANGE_8G,
+        AccelRange::RANGE_16G,
+    ];
+}
+
+impl ::protobuf::EnumFull for AccelRange {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("AccelRange").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for AccelRange {
+    fn default() -> Self {
+        AccelRange::RANGE_DEFAULT
+    }
+}
+
+impl AccelRange {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<AccelRange>("AccelRange")
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:AccelMode)
+pub enum AccelMode {
+    // @@protoc_insertion_point(enum_value:AccelMode.MODE_DEFAULT)
+    MODE_DEFAULT = 0,
+    // @@protoc_insertion_point(enum_value:AccelMode.LOW_POWER)
+    LOW_POWER = 1,
+    // @@protoc_insertion_point(enum_value:AccelMode.NORMAL)
+    NORMAL = 2,
+    // @@protoc_insertion_point(enum_value:AccelMode.HIGH_RESOLUTION)
+    HIGH_RESOLUTION = 3,
+}
+
+impl ::protobuf::Enum for AccelMode {
+    const NAME: &'static str = "AccelMode";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<AccelMode> {
+        match value {
+            0 => ::std::option::Option::Some(AccelMode::MODE_DEFAULT),
+            1 => ::std::option::Option::Some(AccelMode::LOW_POWER),
+            2 => ::std::option::Option::Some(AccelMode::NORMAL),
+            3 => ::std::option::Option::Some(AccelMode::HIGH_RESOLUTION),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<AccelMode> {
+        match str {
+            "MODE_DEFAULT" => ::std::option::Option::Some(AccelMode::MODE_DEFAULT),
+            "LOW_POWER" => ::std::option::Option::Some(AccelMode::LOW_POWER),
+            "NORMAL" => ::std::option::Option::Some(AccelMode::NORMAL),
+            "HIGH_RESOLUTION" => ::std::option::Option::Some(AccelMode::HIGH_RESOLUTION),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [AccelMode] = &[
+        AccelMode::MODE_DEFAULT,
+        AccelMode::LOW_POWER,
+        AccelMode::NORMAL,
+        AccelMode::HIGH_RESOLUTION,
+    ];
+}
+
+impl ::protobuf::EnumFull for AccelMode {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("AccelMode").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for AccelMode {
+    fn default() -> Self {
+        AccelMode::MODE_DEFAULT
+    }
+}
+
+impl AccelMode {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<AccelMode>("AccelMode")
+    }
+}
+
+///
+/// Calibration
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:CalibrationStep)
+pub enum CalibrationStep {
+    // @@protoc_insertion_point(enum_value:CalibrationStep.CALIBRATION_STATUS)
+    CALIBRATION_STATUS = 0,
+    // @@protoc_insertion_point(enum_value:CalibrationStep.ACCEL_START)
+    ACCEL_START = 1,
+    // @@protoc_insertion_point(enum_value:CalibrationStep.ACCEL_CAPTURE)
+    ACCEL_CAPTURE = 2,
+    // @@protoc_insertion_point(enum_value:CalibrationStep.MAG_START)
+    MAG_START = 3,
+    // @@protoc_insertion_point(enum_value:CalibrationStep.CALIBRATION_FINISH)
+    CALIBRATION_FINISH = 4,
+    // @@protoc_insertion_point(enum_value:CalibrationStep.CALIBRATION_CANCEL)
+    CALIBRATION_CANCEL = 5,
+    // @@protoc_insertion_point(enum_value:CalibrationStep.CALIBRATION_RESET)
+    CALIBRATION_RESET = 6,
+}
+
+impl ::protobuf::Enum for CalibrationStep {
+    const NAME: &'static str = "CalibrationStep";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CalibrationStep> {
+        match value {
+            0 => ::std::option::Option::Some(CalibrationStep::CALIBRATION_STATUS),
+            1 => ::std::option::Option::Some(CalibrationStep::ACCEL_START),
+            2 => ::std::option::Option::Some(CalibrationStep::ACCEL_CAPTURE),
+            3 => ::std::option::Option::Some(CalibrationStep::MAG_START),
+            4 => ::std::option::Option::Some(CalibrationStep::CALIBRATION_FINISH),
+            5 => ::std::option::Option::Some(CalibrationStep::CALIBRATION_CANCEL),
+            6 => ::std::option::Option::Some(CalibrationStep::CALIBRATION_RESET),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<CalibrationStep> {
+        match str {
+            "CALIBRATION_STATUS" => ::std::option::Option::Some(CalibrationStep::CALIBRATION_STATUS),
+            "ACCEL_START" => ::std::option::Option::Some(CalibrationStep::ACCEL_START),
+            "ACCEL_CAPTURE" => ::std::option::Option::Some(CalibrationStep::ACCEL_CAPTURE),
+            "MAG_START" => ::std::option::Option::Some(CalibrationStep::MAG_START),
+            "CALIBRATION_FINISH" => ::std::option::Option::Some(CalibrationStep::CALIBRATION_FINISH),
+            "CALIBRATION_CANCEL" => ::std::option::Option::Some(CalibrationStep::CALIBRATION_CANCEL),
+            "CALIBRATION_RESET" => ::std::option::Option::Some(CalibrationStep::CALIBRATION_RESET),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [CalibrationStep] = &[
+        CalibrationStep::CALIBRATION_STATUS,
+        CalibrationStep::ACCEL_START,
+        CalibrationStep::ACCEL_CAPTURE,
+        CalibrationStep::MAG_START,
+        CalibrationStep::CALIBRATION_FINISH,
+        CalibrationStep::CALIBRATION_CANCEL,
+        CalibrationStep::CALIBRATION_RESET,
+    ];
+}
+
+impl ::protobuf::EnumFull for CalibrationStep {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("CalibrationStep").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for CalibrationStep {
+    fn default() -> Self {
+        CalibrationStep::CALIBRATION_STATUS
+    }
+}
+
+impl CalibrationStep {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<CalibrationStep>("CalibrationStep")
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:Pose)
+pub enum Pose {
+    // @@protoc_insertion_point(enum_value:Pose.X_UP)
+    X_UP = 0,
+    // @@protoc_insertion_point(enum_value:Pose.X_DOWN)
+    X_DOWN = 1,
+    // @@protoc_insertion_point(enum_value:Pose.Y_UP)
+    Y_UP = 2,
+    // @@protoc_insertion_point(enum_value:Pose.Y_DOWN)
+    Y_DOWN = 3,
+    // @@protoc_insertion_point(enum_value:Pose.Z_UP)
+    Z_UP = 4,
+    // @@protoc_insertion_point(enum_value:Pose.Z_DOWN)
+    Z_DOWN = 5,
+}
+
+impl ::protobuf::Enum for Pose {
+    const NAME: &'static str = "Pose";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Pose> {
+        match value {
+            0 => ::std::option::Option::Some(Pose::X_UP),
+            1 => ::std::option::Option::Some(Pose::X_DOWN),
+            2 => ::std::option::Option::Some(Pose::Y_UP),
+            3 => ::std::option::Option::Some(Pose::Y_DOWN),
+            4 => ::std::option::Option::Some(Pose::Z_UP),
+            5 => ::std::option::Option::Some(Pose::Z_DOWN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<Pose> {
+        match str {
+            "X_UP" => ::std::option::Option::Some(Pose::X_UP),
+            "X_DOWN" => ::std::option::Option::Some(Pose::X_DOWN),
+            "Y_UP" => ::std::option::Option::Some(Pose::Y_UP),
+            "Y_DOWN" => ::std::option::Option::Some(Pose::Y_DOWN),
+            "Z_UP" => ::std::option::Option::Some(Pose::Z_UP),
+            "Z_DOWN" => ::std::option::Option::Some(Pose::Z_DOWN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [Pose] = &[
+        Pose::X_UP,
+        Pose::X_DOWN,
+        Pose::Y_UP,
+        Pose::Y_DOWN,
+        Pose::Z_UP,
+        Pose::Z_DOWN,
+    ];
+}
+
+impl ::protobuf::EnumFull for Pose {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("Pose").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for Pose {
+    fn default() -> Self {
+        Pose::X_UP
+    }
+}
+
+impl Pose {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Pose>("Pose")
+    }
+}
+
+///
+/// Responses
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:ErrorCode)
+pub enum ErrorCode {
+    // @@protoc_insertion_point(enum_value:ErrorCode.NONE)
+    NONE = 0,
+    // @@protoc_insertion_point(enum_value:ErrorCode.PARSE_ERROR)
+    PARSE_ERROR = 1,
+    // @@protoc_insertion_point(enum_value:ErrorCode.UNSUPPORTED)
+    UNSUPPORTED = 2,
+    // @@protoc_insertion_point(enum_value:ErrorCode.NO_START_ALGORITHIM)
+    NO_START_ALGORITHIM = 3,
+    // @@protoc_insertion_point(enum_value:ErrorCode.OUTPUT_FAILED)
+    OUTPUT_FAILED = 4,
+    // @@protoc_insertion_point(enum_value:ErrorCode.INVALID_ARGUMENT)
+    INVALID_ARGUMENT = 5,
+    // @@protoc_insertion_point(enum_value:ErrorCode.UNSUPPORTED_SENSOR_CONFIGURATION)
+    UNSUPPORTED_SENSOR_CONFIGURATION = 6,
+    // @@protoc_insertion_point(enum_value:ErrorCode.CALIBRATION_FAILED)
+    CALIBRATION_FAILED = 7,
+    // @@protoc_insertion_point(enum_value:ErrorCode.SESSION_NOT_FOUND)
+    SESSION_NOT_FOUND = 8,
+    // @@protoc_insertion_point(enum_value:ErrorCode.STORAGE_FAILED)
+    STORAGE_FAILED = 9,
+}
+
+impl ::protobuf::Enum for ErrorCode {
+    const NAME: &'static str = "ErrorCode";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ErrorCode> {
+        match value {
+            0 => ::std::option::Option::Some(ErrorCode::NONE),
+            1 => ::std::option::Option::Some(ErrorCode::PARSE_ERROR),
+            2 => ::std::option::Option::Some(ErrorCode::UNSUPPORTED),
+            3 => ::std::option::Option::Some(ErrorCode::NO_START_ALGORITHIM),
+            4 => ::std::option::Option::Some(ErrorCode::OUTPUT_FAILED),
+            5 => ::std::option::Option::Some(ErrorCode::INVALID_ARGUMENT),
+            6 => ::std::option::Option::Some(ErrorCode::UNSUPPORTED_SENSOR_CONFIGURATION),
+            7 => ::std::option::Option::Some(ErrorCode::CALIBRATION_FAILED),
+            8 => ::std::option::Option::Some(ErrorCode::SESSION_NOT_FOUND),
+            9 => ::std::option::Option::Some(ErrorCode::STORAGE_FAILED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<ErrorCode> {
+        match str {
+            "NONE" => ::std::option::Option::Some(ErrorCode::NONE),
+            "PARSE_ERROR" => ::std::option::Option::Some(ErrorCode::PARSE_ERROR),
+            "UNSUPPORTED" => ::std::option::Option::Some(ErrorCode::UNSUPPORTED),
+            "NO_START_ALGORITHIM" => ::std::option::Option::Some(ErrorCode::NO_START_ALGORITHIM),
+            "OUTPUT_FAILED" => ::std::option::Option::Some(ErrorCode::OUTPUT_FAILED),
+            "INVALID_ARGUMENT" => ::std::option::Option::Some(ErrorCode::INVALID_ARGUMENT),
+            "UNSUPPORTED_SENSOR_CONFIGURATION" => ::std::option::Option::Some(ErrorCode::UNSUPPORTED_SENSOR_CONFIGURATION),
+            "CALIBRATION_FAILED" => ::std::option::Option::Some(ErrorCode::CALIBRATION_FAILED),
+            "SESSION_NOT_FOUND" => ::std::option::Option::Some(ErrorCode::SESSION_NOT_FOUND),
+            "STORAGE_FAILED" => ::std::option::Option::Some(ErrorCode::STORAGE_FAILED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [ErrorCode] = &[
+        ErrorCode::NONE,
+        ErrorCode::PARSE_ERROR,
+        ErrorCode::UNSUPPORTED,
+        ErrorCode::NO_START_ALGORITHIM,
+        ErrorCode::OUTPUT_FAILED,
+        ErrorCode::INVALID_ARGUMENT,
+        ErrorCode::UNSUPPORTED_SENSOR_CONFIGURATION,
+        ErrorCode::CALIBRATION_FAILED,
+        ErrorCode::SESSION_NOT_FOUND,
+        ErrorCode::STORAGE_FAILED,
+    ];
+}
+
+impl ::protobuf::EnumFull for ErrorCode {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("ErrorCode").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for ErrorCode {
+    fn default() -> Self {
+        ErrorCode::NONE
+    }
+}
+
+impl ErrorCode {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<ErrorCode>("ErrorCode")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n'definitions/algorithim/algorithim.proto\"#\n\x05Timed\x12\x1a\n\x08du\
     ration\x18\x01\x20\x01(\rR\x08duration\"!\n\x07Counted\x12\x16\n\x06coun\
-    ts\x18\x01\x20\x01(\rR\x06counts\"\x0c\n\nSpikeStart\"\x20\n\x08Bouncing\
-    \x12\x14\n\x05jumps\x18\x01\x20\x01(\rR\x05jumps\"\xa5\x01\n\nBouningRep\
-    \x12$\n\raverageHeight\x18\x01\x20\x01(\rR\raverageHeight\x12:\n\x18aver\
-    ageGroundContactTime\x18\x02\x20\x01(\rR\x18averageGroundContactTime\x12\
-    5\n\x0cmeasurements\x18\x03\x20\x03(\x0b2\x11.BounceMeasurmentR\x0cmeasu\
-    rements\"X\n\x10BounceMeasurment\x12,\n\x11groundContactTime\x18\x01\x20\
-    \x01(\rR\x11groundContactTime\x12\x16\n\x06height\x18\x02\x20\x01(\rR\
-    \x06height\"\xc5\x01\n\x17AlgorithimConfiguration\x12\x1e\n\x05timed\x18\
-    \x01\x20\x01(\x0b2\x06.TimedH\0R\x05timed\x12-\n\nspikeStart\x18\x02\x20\
-    \x01(\x0b2\x0b.SpikeStartH\x01R\nspikeStart\x12'\n\x08bouncing\x18\x03\
-    \x20\x01(\x0b2\t.BouncingH\x02R\x08bouncingB\x0f\n\rendAlgorithimB\x10\n\
-    \x0estartAlgorthimB\x0f\n\rconfiguration\";\n\x03Rep\x12-\n\nbouningRep\
-    \x18\x01\x20\x01(\x0b2\x0b.BouningRepH\0R\nbouningRepB\x05\n\x03rep\"j\n\
-    \x07Message\x12:\n\nAlgorithim\x18\x01\x20\x01(\x0b2\x18.AlgorithimConfi\
-    gurationH\0R\nAlgorithim\x12\x18\n\x03rep\x18\x02\x20\x01(\x0b2\x04.RepH\
-    \0R\x03repB\t\n\x07contentJ\xca\n\n\x06\x12\x04\0\0;\x01\n\x08\n\x01\x0c\
-    \x12\x03\0\0\x12\n\x1b\n\x02\x04\0\x12\x03\x05\0&\x1a\x10\nEnd\x20Algort\
-    hims\n\n\n\n\x03\x04\0\x01\x12\x03\x05\x08\r\n\x0b\n\x04\x04\0\x02\0\x12\
-    \x03\x05\x10$\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x05\x10\x16\n\x0c\n\
-    \x05\x04\0\x02\0\x01\x12\x03\x05\x17\x1f\n\x0c\n\x05\x04\0\x02\0\x03\x12\
-    \x03\x05\"#\n\t\n\x02\x04\x01\x12\x03\x07\0&\n\n\n\x03\x04\x01\x01\x12\
-    \x03\x07\x08\x0f\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x07\x12$\n\x0c\n\x05\
-    \x04\x01\x02\0\x05\x12\x03\x07\x12\x18\n\x0c\n\x05\x04\x01\x02\0\x01\x12\
-    \x03\x07\x19\x1f\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x07\"#\n\x1e\n\
-    \x02\x04\x02\x12\x03\x0c\0\x15\x1a\x13\nStart\x20Algorithims\n\n\n\n\x03\
-    \x04\x02\x01\x12\x03\x0c\x08\x12\n\x1c\n\x02\x04\x03\x12\x04\x11\0\x13\
-    \x01\x1a\x10\nConfigurations\n\n\n\n\x03\x04\x03\x01\x12\x03\x11\x08\x10\
-    \n-\n\x04\x04\x03\x02\0\x12\x03\x12\x02\x13\"\x20\x20number\x20of\x20jum\
-    ps\x20to\x20be\x20measured\n\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x12\
-    \x02\x08\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x12\t\x0e\n\x0c\n\x05\x04\
-    \x03\x02\0\x03\x12\x03\x12\x11\x12\n\x15\n\x02\x04\x04\x12\x04\x18\0\x1c\
-    \x01\x1a\t\nOutputs\n\n\n\n\x03\x04\x04\x01\x12\x03\x18\x08\x12\n\x0b\n\
-    \x04\x04\x04\x02\0\x12\x03\x19\x02\x1b\n\x0c\n\x05\x04\x04\x02\0\x05\x12\
-    \x03\x19\x02\x08\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x19\t\x16\n\x0c\n\
-    \x05\x04\x04\x02\0\x03\x12\x03\x19\x19\x1a\n\x0b\n\x04\x04\x04\x02\x01\
-    \x12\x03\x1a\x02&\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x1a\x02\x08\n\
-    \x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x1a\t!\n\x0c\n\x05\x04\x04\x02\
-    \x01\x03\x12\x03\x1a$%\n\x0b\n\x04\x04\x04\x02\x02\x12\x03\x1b\x02-\n\
-    \x0c\n\x05\x04\x04\x02\x02\x04\x12\x03\x1b\x02\n\n\x0c\n\x05\x04\x04\x02\
-    \x02\x06\x12\x03\x1b\x0b\x1b\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\x1b\
-    \x1c(\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03\x1b+,\n\n\n\x02\x04\x05\
-    \x12\x04\x1e\0!\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1e\x08\x18\n\x0b\n\
-    \x04\x04\x05\x02\0\x12\x03\x1f\x02\x1f\n\x0c\n\x05\x04\x05\x02\0\x05\x12\
-    \x03\x1f\x02\x08\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1f\t\x1a\n\x0c\n\
-    \x05\x04\x05\x02\0\x03\x12\x03\x1f\x1d\x1e\n\x0b\n\x04\x04\x05\x02\x01\
-    \x12\x03\x20\x02\x14\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03\x20\x02\x08\
-    \n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03\x20\t\x0f\n\x0c\n\x05\x04\x05\
-    \x02\x01\x03\x12\x03\x20\x12\x13\nJ\n\x02\x04\x06\x12\x04(\0,\x01\x1a$\
-    \x20Algorithm\x20algorithim\x20configuration\n2\x18\nData\x20transfer\
-    \x20messages\n\n\n\n\x03\x04\x06\x01\x12\x03(\x08\x1f\n\x0b\n\x04\x04\
-    \x06\x08\0\x12\x03)\x02*\n\x0c\n\x05\x04\x06\x08\0\x01\x12\x03)\x08\x15\
-    \n\x0b\n\x04\x04\x06\x02\0\x12\x03)\x18(\n\x0c\n\x05\x04\x06\x02\0\x06\
-    \x12\x03)\x18\x1d\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03)\x1e#\n\x0c\n\
-    \x05\x04\x06\x02\0\x03\x12\x03)&'\n\x0b\n\x04\x04\x06\x08\x01\x12\x03*\
-    \x025\n\x0c\n\x05\x04\x06\x08\x01\x01\x12\x03*\x08\x16\n\x0b\n\x04\x04\
-    \x06\x02\x01\x12\x03*\x193\n\x0c\n\x05\x04\x06\x02\x01\x06\x12\x03*\x19#\
-    \n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03*$.\n\x0c\n\x05\x04\x06\x02\x01\
-    \x03\x12\x03*12\n\x0b\n\x04\x04\x06\x08\x02\x12\x03+\x020\n\x0c\n\x05\
-    \x04\x06\x08\x02\x01\x12\x03+\x08\x15\n\x0b\n\x04\x04\x06\x02\x02\x12\
-    \x03+\x18.\n\x0c\n\x05\x04\x06\x02\x02\x06\x12\x03+\x18\x20\n\x0c\n\x05\
-    \x04\x06\x02\x02\x01\x12\x03+!)\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03+\
-    ,-\n\x19\n\x02\x04\x07\x12\x04/\01\x01\x1a\r\x20Measuremnts\n\n\n\n\x03\
-    \x04\x07\x01\x12\x03/\x08\x0b\n\x0b\n\x04\x04\x07\x08\0\x12\x030\x02*\n\
-    \x0c\n\x05\x04\x07\x08\0\x01\x12\x030\x08\x0b\n\x0b\n\x04\x04\x07\x02\0\
-    \x12\x030\x0e(\n\x0c\n\x05\x04\x07\x02\0\x06\x12\x030\x0e\x18\n\x0c\n\
-    \x05\x04\x07\x02\0\x01\x12\x030\x19#\n\x0c\n\x05\x04\x07\x02\0\x03\x12\
-    \x030&'\n\x20\n\x02\x04\x08\x12\x046\0;\x01\x1a\x14\ntransfer\x20Contain\
-    er\n\n\n\n\x03\x04\x08\x01\x12\x036\x08\x0f\n\x0c\n\x04\x04\x08\x08\0\
-    \x12\x047\x02:\x03\n\x0c\n\x05\x04\x08\x08\0\x01\x12\x037\x08\x0f\n\x0b\
-    \n\x04\x04\x08\x02\0\x12\x038\x04+\n\x0c\n\x05\x04\x08\x02\0\x06\x12\x03\
-    8\x04\x1b\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x038\x1c&\n\x0c\n\x05\x04\
-    \x08\x02\0\x03\x12\x038)*\n\x0b\n\x04\x04\x08\x02\x01\x12\x039\x04\x10\n\
-    \x0c\n\x05\x04\x08\x02\x01\x06\x12\x039\x04\x07\n\x0c\n\x05\x04\x08\x02\
-    \x01\x01\x12\x039\x08\x0b\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x039\x0e\
-    \x0fb\x06proto3\
+    ts\x18\x01\x20\x01(\rR\x06counts\"\x82\x01\n\nSpikeStart\x12\x1c\n\tthre\
+    shold\x18\x01\x20\x01(\rR\tthreshold\x12*\n\x10releaseThreshold\x18\x02\
+    \x20\x01(\rR\x10releaseThreshold\x12*\n\x10refractoryPeriod\x18\x03\x20\
+    \x01(\rR\x10refractoryPeriod\"\x20\n\x08Bouncing\x12\x14\n\x05jumps\x18\
+    \x01\x20\x01(\rR\x05jumps\"\xa5\x01\n\nBouningRep\x12$\n\raverageHeight\
+    \x18\x01\x20\x01(\rR\raverageHeight\x12:\n\x18averageGroundContactTime\
+    \x18\x02\x20\x01(\rR\x18averageGroundContactTime\x125\n\x0cmeasurements\
+    \x18\x03\x20\x03(\x0b2\x11.BounceMeasurmentR\x0cmeasurements\"X\n\x10Bou\
+    nceMeasurment\x12,\n\x11groundContactTime\x18\x01\x20\x01(\rR\x11groundC\
+    ontactTime\x12\x16\n\x06height\x18\x02\x20\x01(\rR\x06height\"\x9b\x02\n\
+    \x17AlgorithimConfiguration\x12\x1e\n\x05timed\x18\x01\x20\x01(\x0b2\x06\
+    .TimedH\0R\x05timed\x12$\n\x07counted\x18\x04\x20\x01(\x0b2\x08.CountedH\
+    \0R\x07counted\x12-\n\nspikeStart\x18\x02\x20\x01(\x0b2\x0b.SpikeStartH\
+    \x01R\nspikeStart\x12'\n\x08bouncing\x18\x03\x20\x01(\x0b2\t.BouncingH\
+    \x02R\x08bouncing\x12.\n\x0bheightModel\x18\x05\x20\x01(\x0e2\x0c.Height\
+    ModelR\x0bheightModelB\x0f\n\rendAlgorithimB\x10\n\x0estartAlgorthimB\
+    \x0f\n\rconfiguration\";\n\x03Rep\x12-\n\nbouningRep\x18\x01\x20\x01(\
+    \x0b2\x0b.BouningRepH\0R\nbouningRepB\x05\n\x03rep\"\x83\x01\n\tRawStrea\
+    m\x12\x18\n\x07enabled\x18\x01\x20\x01(\x08R\x07enabled\x12\x1e\n\ndecim\
+    ation\x18\x02\x20\x01(\rR\ndecimation\x12\x1c\n\tbatchSize\x18\x03\x20\
+    \x01(\rR\tbatchSize\x12\x1e\n\nincludeMag\x18\x04\x20\x01(\x08R\ninclude\
+    Mag\"\xa3\x01\n\x0bSampleBatch\x12\x1a\n\x08sequence\x18\x01\x20\x01(\rR\
+    \x08sequence\x12&\n\x0estartTimestamp\x18\x02\x20\x01(\x03R\x0estartTime\
+    stamp\x12(\n\x0ftimestampDeltas\x18\x03\x20\x03(\rR\x0ftimestampDeltas\
+    \x12\x14\n\x05accel\x18\x04\x20\x03(\x11R\x05accel\x12\x10\n\x03mag\x18\
+    \x05\x20\x03(\x11R\x03mag\"\xa0\x01\n\x13SensorConfiguration\x12\x1a\n\
+    \x08accelOdr\x18\x01\x20\x01(\rR\x08accelOdr\x12\x16\n\x06magOdr\x18\x02\
+    \x20\x01(\rR\x06magOdr\x12+\n\naccelRange\x18\x03\x20\x01(\x0e2\x0b.Acce\
+    lRangeR\naccelRange\x12(\n\taccelMode\x18\x04\x20\x01(\x0e2\n.AccelModeR\
+    \taccelMode\"1\n\tCalibrate\x12$\n\x04step\x18\x01\x20\x01(\x0e2\x10.Cal\
+    ibrationStepR\x04step\"\xae\x01\n\x11CalibrationStatus\x12(\n\x0faccelCa\
+    librated\x18\x01\x20\x01(\x08R\x0faccelCalibrated\x12$\n\rmagCalibrated\
+    \x18\x02\x20\x01(\x08R\rmagCalibrated\x12)\n\x0cmissingPoses\x18\x03\x20\
+    \x03(\x0e2\x05.PoseR\x0cmissingPoses\x12\x1e\n\nmagSamples\x18\x04\x20\
+    \x01(\rR\nmagSamples\"3\n\rStoredSession\x12\x0e\n\x02id\x18\x01\x20\x01\
+    (\rR\x02id\x12\x12\n\x04size\x18\x02\x20\x01(\rR\x04size\"\"\n\x0cListSe\
+    ssions\x12\x12\n\x04from\x18\x01\x20\x01(\rR\x04from\"M\n\x0bSessionList\
+    \x12*\n\x08sessions\x18\x01\x20\x03(\x0b2\x0e.StoredSessionR\x08sessions\
+    \x12\x12\n\x04more\x18\x02\x20\x01(\x08R\x04more\"9\n\x0fDownloadSession\
+    \x12\x0e\n\x02id\x18\x01\x20\x01(\rR\x02id\x12\x16\n\x06offset\x18\x02\
+    \x20\x01(\rR\x06offset\"^\n\x0cSessionChunk\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\rR\x02id\x12\x16\n\x06offset\x18\x02\x20\x01(\rR\x06offset\x12\x12\
+    \n\x04size\x18\x03\x20\x01(\rR\x04size\x12\x12\n\x04data\x18\x04\x20\x01\
+    (\x0cR\x04data\"\x0f\n\rEraseSessions\"r\n\x08Settings\x128\n\nalgorithi\
+    m\x18\x01\x20\x01(\x0b2\x18.AlgorithimConfigurationR\nalgorithim\x12,\n\
+    \x06sensor\x18\x02\x20\x01(\x0b2\x14.SensorConfigurationR\x06sensor\"d\n\
+    \x08Response\x12\x1c\n\trequestId\x18\x01\x20\x01(\rR\trequestId\x12\x18\
+    \n\x07success\x18\x02\x20\x01(\x08R\x07success\x12\x20\n\x05error\x18\
+    \x03\x20\x01(\x0e2\n.ErrorCodeR\x05error\"\xdb\x05\n\x07Message\x12:\n\n\
+    Algorithim\x18\x01\x20\x01(\x0b2\x18.AlgorithimConfigurationH\0R\nAlgori\
+    thim\x12\x18\n\x03rep\x18\x02\x20\x01(\x0b2\x04.RepH\0R\x03rep\x12'\n\
+    \x08response\x18\x04\x20\x01(\x0b2\t.ResponseH\0R\x08response\x12*\n\tra\
+    wStream\x18\x05\x20\x01(\x0b2\n.RawStreamH\0R\trawStream\x120\n\x0bsampl\
+    eBatch\x18\x06\x20\x01(\x0b2\x0c.SampleBatchH\0R\x0bsampleBatch\x12H\n\
+    \x13sensorConfiguration\x18\x07\x20\x01(\x0b2\x14.SensorConfigurationH\0\
+    R\x13sensorConfiguration\x12*\n\tcalibrate\x18\x08\x20\x01(\x0b2\n.Calib\
+    rateH\0R\tcalibrate\x12B\n\x11calibrationStatus\x18\t\x20\x01(\x0b2\x12.\
+    CalibrationStatusH\0R\x11calibrationStatus\x123\n\x0clistSessions\x18\n\
+    \x20\x01(\x0b2\r.ListSessionsH\0R\x0clistSessions\x120\n\x0bsessionList\
+    \x18\x0b\x20\x01(\x0b2\x0c.SessionListH\0R\x0bsessionList\x12<\n\x0fdown\
+    loadSession\x18\x0c\x20\x01(\x0b2\x10.DownloadSessionH\0R\x0fdownloadSes\
+    sion\x123\n\x0csessionChunk\x18\r\x20\x01(\x0b2\r.SessionChunkH\0R\x0cse\
+    ssionChunk\x126\n\reraseSessions\x18\x0e\x20\x01(\x0b2\x0e.EraseSessions\
+    H\0R\reraseSessions\x12\x1c\n\trequestId\x18\x03\x20\x01(\rR\trequestIdB\
+    \t\n\x07content*4\n\x0bHeightModel\x12\x0f\n\x0bFLIGHT_TIME\x10\0\x12\
+    \x14\n\x10TAKEOFF_VELOCITY\x10\x01*X\n\nAccelRange\x12\x11\n\rRANGE_DEFA\
+    ULT\x10\0\x12\x0c\n\x08RANGE_2G\x10\x01\x12\x0c\n\x08RANGE_4G\x10\x02\
+    \x12\x0c\n\x08RANGE_8G\x10\x03\x12\r\n\tRANGE_16G\x10\x04*M\n\tAccelMode\
+    \x12\x10\n\x0cMODE_DEFAULT\x10\0\x12\r\n\tLOW_POWER\x10\x01\x12\n\n\x06N\
+    ORMAL\x10\x02\x12\x13\n\x0fHIGH_RESOLUTION\x10\x03*\xa3\x01\n\x0fCalibra\
+    tionStep\x12\x16\n\x12CALIBRATION_STATUS\x10\0\x12\x0f\n\x0bACCEL_START\
+    \x10\x01\x12\x11\n\rACCEL_CAPTURE\x10\x02\x12\r\n\tMAG_START\x10\x03\x12\
+    \x16\n\x12CALIBRATION_FINISH\x10\x04\x12\x16\n\x12CALIBRATION_CANCEL\x10\
+    \x05\x12\x15\n\x11CALIBRATION_RESET\x10\x06*H\n\x04Pose\x12\x08\n\x04X_U\
+    P\x10\0\x12\n\n\x06X_DOWN\x10\x01\x12\x08\n\x04Y_UP\x10\x02\x12\n\n\x06Y\
+    _DOWN\x10\x03\x12\x08\n\x04Z_UP\x10\x04\x12\n\n\x06Z_DOWN\x10\x05*\xe2\
+    \x01\n\tErrorCode\x12\x08\n\x04NONE\x10\0\x12\x0f\n\x0bPARSE_ERROR\x10\
+    \x01\x12\x0f\n\x0bUNSUPPORTED\x10\x02\x12\x17\n\x13NO_START_ALGORITHIM\
+    \x10\x03\x12\x11\n\rOUTPUT_FAILED\x10\x04\x12\x14\n\x10INVALID_ARGUMENT\
+    \x10\x05\x12$\n\x20UNSUPPORTED_SENSOR_CONFIGURATION\x10\x06\x12\x16\n\
+    \x12CALIBRATION_FAILED\x10\x07\x12\x15\n\x11SESSION_NOT_FOUND\x10\x08\
+    \x12\x12\n\x0eSTORAGE_FAILED\x10\tJ\xa8F\n\x07\x12\x05\0\0\xee\x01\x01\n\
+    \x08\n\x01\x0c\x12\x03\0\0\x12\n\x1c\n\x02\x04\0\x12\x04\x05\0\x07\x01\
+    \x1a\x10\nEnd\x20Algorthims\n\n\n\n\x03\x04\0\x01\x12\x03\x05\x08\r\n+\n\
+    \x04\x04\0\x02\0\x12\x03\x06\x02\x16\"\x1e\x20ms\x20from\x20the\x20start\
+    \x20of\x20the\x20set\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x06\x02\x08\n\
+    \x0c\n\x05\x04\0\x02\0\x01\x12\x03\x06\t\x11\n\x0c\n\x05\x04\0\x02\0\x03\
+    \x12\x03\x06\x14\x15\n\n\n\x02\x04\x01\x12\x04\t\0\x0b\x01\n\n\n\x03\x04\
+    \x01\x01\x12\x03\t\x08\x0f\n!\n\x04\x04\x01\x02\0\x12\x03\n\x02\x14\"\
+    \x14\x20bounces\x20in\x20the\x20set\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\
+    \x03\n\x02\x08\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\n\t\x0f\n\x0c\n\x05\
+    \x04\x01\x02\0\x03\x12\x03\n\x12\x13\n\x1f\n\x02\x04\x02\x12\x04\x10\0\
+    \x14\x01\x1a\x13\nStart\x20Algorithims\n\n\n\n\x03\x04\x02\x01\x12\x03\
+    \x10\x08\x12\nD\n\x04\x04\x02\x02\0\x12\x03\x11\x02\x17\"7\x20accelerati\
+    on\x20magnitude\x20in\x20milli\x20g\x20that\x20starts\x20the\x20set\n\n\
+    \x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x11\x02\x08\n\x0c\n\x05\x04\x02\x02\
+    \0\x01\x12\x03\x11\t\x12\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x11\x15\
+    \x16\nV\n\x04\x04\x02\x02\x01\x12\x03\x12\x02\x1e\"I\x20magnitude\x20in\
+    \x20milli\x20g\x20the\x20signal\x20has\x20to\x20fall\x20under\x20before\
+    \x20a\x20spike\x20counts\n\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x12\
+    \x02\x08\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x12\t\x19\n\x0c\n\x05\
+    \x04\x02\x02\x01\x03\x12\x03\x12\x1c\x1d\nH\n\x04\x04\x02\x02\x02\x12\
+    \x03\x13\x02\x1e\";\x20ms\x20after\x20the\x20set\x20is\x20armed\x20durin\
+    g\x20which\x20spikes\x20are\x20ignored\n\n\x0c\n\x05\x04\x02\x02\x02\x05\
+    \x12\x03\x13\x02\x08\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x13\t\x19\n\
+    \x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x13\x1c\x1d\n\x1c\n\x02\x04\x03\
+    \x12\x04\x19\0\x1b\x01\x1a\x10\nConfigurations\n\n\n\n\x03\x04\x03\x01\
+    \x12\x03\x19\x08\x10\n-\n\x04\x04\x03\x02\0\x12\x03\x1a\x02\x13\"\x20\
+    \x20number\x20of\x20jumps\x20to\x20be\x20measured\n\n\x0c\n\x05\x04\x03\
+    \x02\0\x05\x12\x03\x1a\x02\x08\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x1a\
+    \t\x0e\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x1a\x11\x12\n\x15\n\x02\x04\
+    \x04\x12\x04\x20\0$\x01\x1a\t\nOutputs\n\n\n\n\x03\x04\x04\x01\x12\x03\
+    \x20\x08\x12\n\x0b\n\x04\x04\x04\x02\0\x12\x03!\x02\x1b\n\x0c\n\x05\x04\
+    \x04\x02\0\x05\x12\x03!\x02\x08\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03!\t\
+    \x16\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03!\x19\x1a\n\x0b\n\x04\x04\x04\
+    \x02\x01\x12\x03\"\x02&\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\"\x02\
+    \x08\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\"\t!\n\x0c\n\x05\x04\x04\
+    \x02\x01\x03\x12\x03\"$%\n\x0b\n\x04\x04\x04\x02\x02\x12\x03#\x02-\n\x0c\
+    \n\x05\x04\x04\x02\x02\x04\x12\x03#\x02\n\n\x0c\n\x05\x04\x04\x02\x02\
+    \x06\x12\x03#\x0b\x1b\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03#\x1c(\n\
+    \x0c\n\x05\x04\x04\x02\x02\x03\x12\x03#+,\n\n\n\x02\x04\x05\x12\x04&\0)\
+    \x01\n\n\n\x03\x04\x05\x01\x12\x03&\x08\x18\n\x11\n\x04\x04\x05\x02\0\
+    \x12\x03'\x02\x1f\"\x04\x20ms\n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03'\
+    \x02\x08\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03'\t\x1a\n\x0c\n\x05\x04\
+    \x05\x02\0\x03\x12\x03'\x1d\x1e\n\x11\n\x04\x04\x05\x02\x01\x12\x03(\x02\
+    \x14\"\x04\x20mm\n\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03(\x02\x08\n\
+    \x0c\n\x05\x04\x05\x02\x01\x01\x12\x03(\t\x0f\n\x0c\n\x05\x04\x05\x02\
+    \x01\x03\x12\x03(\x12\x13\nT\n\x02\x05\0\x12\x040\03\x01\x1a.\x20how\x20\
+    the\x20height\x20of\x20every\x20bounce\x20is\x20worked\x20out\n2\x18\nDa\
+    ta\x20transfer\x20messages\n\n\n\n\x03\x05\0\x01\x12\x030\x05\x10\n'\n\
+    \x04\x05\0\x02\0\x12\x031\x02\x12\"\x1a\x20from\x20the\x20time\x20in\x20\
+    the\x20air\n\n\x0c\n\x05\x05\0\x02\0\x01\x12\x031\x02\r\n\x0c\n\x05\x05\
+    \0\x02\0\x02\x12\x031\x10\x11\nL\n\x04\x05\0\x02\x01\x12\x032\x02\x17\"?\
+    \x20from\x20the\x20vertical\x20velocity\x20integrated\x20over\x20the\x20\
+    ground\x20contact\n\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x032\x02\x12\n\x0c\
+    \n\x05\x05\0\x02\x01\x02\x12\x032\x15\x16\n0\n\x02\x04\x06\x12\x046\0>\
+    \x01\x1a$\x20Algorithm\x20algorithim\x20configuration\n\n\n\n\x03\x04\
+    \x06\x01\x12\x036\x08\x1f\n\x0c\n\x04\x04\x06\x08\0\x12\x047\x02:\x03\n\
+    \x0c\n\x05\x04\x06\x08\0\x01\x12\x037\x08\x15\n\x0b\n\x04\x04\x06\x02\0\
+    \x12\x038\x04\x14\n\x0c\n\x05\x04\x06\x02\0\x06\x12\x038\x04\t\n\x0c\n\
+    \x05\x04\x06\x02\0\x01\x12\x038\n\x0f\n\x0c\n\x05\x04\x06\x02\0\x03\x12\
+    \x038\x12\x13\n\x0b\n\x04\x04\x06\x02\x01\x12\x039\x04\x18\n\x0c\n\x05\
+    \x04\x06\x02\x01\x06\x12\x039\x04\x0b\n\x0c\n\x05\x04\x06\x02\x01\x01\
+    \x12\x039\x0c\x13\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x039\x16\x17\n\x0b\
+    \n\x04\x04\x06\x08\x01\x12\x03;\x025\n\x0c\n\x05\x04\x06\x08\x01\x01\x12\
+    \x03;\x08\x16\n\x0b\n\x04\x04\x06\x02\x02\x12\x03;\x193\n\x0c\n\x05\x04\
+    \x06\x02\x02\x06\x12\x03;\x19#\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03;$\
+    .\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03;12\n\x0b\n\x04\x04\x06\x08\x02\
+    \x12\x03<\x020\n\x0c\n\x05\x04\x06\x08\x02\x01\x12\x03<\x08\x15\n\x0b\n\
+    \x04\x04\x06\x02\x03\x12\x03<\x18.\n\x0c\n\x05\x04\x06\x02\x03\x06\x12\
+    \x03<\x18\x20\n\x0c\n\x05\x04\x06\x02\x03\x01\x12\x03<!)\n\x0c\n\x05\x04\
+    \x06\x02\x03\x03\x12\x03<,-\n\x0b\n\x04\x04\x06\x02\x04\x12\x03=\x02\x1e\
+    \n\x0c\n\x05\x04\x06\x02\x04\x06\x12\x03=\x02\r\n\x0c\n\x05\x04\x06\x02\
+    \x04\x01\x12\x03=\x0e\x19\n\x0c\n\x05\x04\x06\x02\x04\x03\x12\x03=\x1c\
+    \x1d\n\x19\n\x02\x04\x07\x12\x04A\0C\x01\x1a\r\x20Measuremnts\n\n\n\n\
+    \x03\x04\x07\x01\x12\x03A\x08\x0b\n\x0b\n\x04\x04\x07\x08\0\x12\x03B\x02\
+    *\n\x0c\n\x05\x04\x07\x08\0\x01\x12\x03B\x08\x0b\n\x0b\n\x04\x04\x07\x02\
+    \0\x12\x03B\x0e(\n\x0c\n\x05\x04\x07\x02\0\x06\x12\x03B\x0e\x18\n\x0c\n\
+    \x05\x04\x07\x02\0\x01\x12\x03B\x19#\n\x0c\n\x05\x04\x07\x02\0\x03\x12\
+    \x03B&'\nO\n\x02\x04\x08\x12\x04J\0O\x01\x1a2\x20turns\x20streaming\x20o\
+    f\x20the\x20raw\x20imu\x20samples\x20on\x20or\x20off\n2\x0f\nRaw\x20stre\
+    aming\n\n\n\n\x03\x04\x08\x01\x12\x03J\x08\x11\n\x0b\n\x04\x04\x08\x02\0\
+    \x12\x03K\x02\x13\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03K\x02\x06\n\x0c\n\
+    \x05\x04\x08\x02\0\x01\x12\x03K\x07\x0e\n\x0c\n\x05\x04\x08\x02\0\x03\
+    \x12\x03K\x11\x12\n:\n\x04\x04\x08\x02\x01\x12\x03L\x02\x18\"-\x20send\
+    \x20every\x20nth\x20sample,\x200\x20sends\x20every\x20sample\n\n\x0c\n\
+    \x05\x04\x08\x02\x01\x05\x12\x03L\x02\x08\n\x0c\n\x05\x04\x08\x02\x01\
+    \x01\x12\x03L\t\x13\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x03L\x16\x17\n:\
+    \n\x04\x04\x08\x02\x02\x12\x03M\x02\x17\"-\x20samples\x20per\x20SampleBa\
+    tch,\x200\x20uses\x20the\x20default\n\n\x0c\n\x05\x04\x08\x02\x02\x05\
+    \x12\x03M\x02\x08\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x03M\t\x12\n\x0c\n\
+    \x05\x04\x08\x02\x02\x03\x12\x03M\x15\x16\n\x0b\n\x04\x04\x08\x02\x03\
+    \x12\x03N\x02\x16\n\x0c\n\x05\x04\x08\x02\x03\x05\x12\x03N\x02\x06\n\x0c\
+    \n\x05\x04\x08\x02\x03\x01\x12\x03N\x07\x11\n\x0c\n\x05\x04\x08\x02\x03\
+    \x03\x12\x03N\x14\x15\nG\n\x02\x04\t\x12\x04R\0X\x01\x1a;\x20a\x20batch\
+    \x20of\x20raw\x20samples,\x20the\x20vectors\x20are\x20interleaved\x20x\
+    \x20y\x20z\n\n\n\n\x03\x04\t\x01\x12\x03R\x08\x13\nH\n\x04\x04\t\x02\0\
+    \x12\x03S\x02\x16\";\x20counts\x20up\x20by\x20one\x20per\x20batch\x20so\
+    \x20lost\x20batches\x20can\x20be\x20spotted\n\n\x0c\n\x05\x04\t\x02\0\
+    \x05\x12\x03S\x02\x08\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03S\t\x11\n\x0c\n\
+    \x05\x04\t\x02\0\x03\x12\x03S\x14\x15\n\x11\n\x04\x04\t\x02\x01\x12\x03T\
+    \x02\x1b\"\x04\x20us\n\n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03T\x02\x07\n\
+    \x0c\n\x05\x04\t\x02\x01\x01\x12\x03T\x08\x16\n\x0c\n\x05\x04\t\x02\x01\
+    \x03\x12\x03T\x19\x1a\n<\n\x04\x04\t\x02\x02\x12\x03U\x02&\"/\x20us\x20s\
+    ince\x20the\x20previous\x20sample,\x200\x20for\x20the\x20first\n\n\x0c\n\
+    \x05\x04\t\x02\x02\x04\x12\x03U\x02\n\n\x0c\n\x05\x04\t\x02\x02\x05\x12\
+    \x03U\x0b\x11\n\x0c\n\x05\x04\t\x02\x02\x01\x12\x03U\x12!\n\x0c\n\x05\
+    \x04\t\x02\x02\x03\x12\x03U$%\n\x16\n\x04\x04\t\x02\x03\x12\x03V\x02\x1c\
+    \"\t\x20milli\x20g\n\n\x0c\n\x05\x04\t\x02\x03\x04\x12\x03V\x02\n\n\x0c\
+    \n\x05\x04\t\x02\x03\x05\x12\x03V\x0b\x11\n\x0c\n\x05\x04\t\x02\x03\x01\
+    \x12\x03V\x12\x17\n\x0c\n\x05\x04\t\x02\x03\x03\x12\x03V\x1a\x1b\n.\n\
+    \x04\x04\t\x02\x04\x12\x03W\x02\x1a\"!\x20empty\x20unless\x20includeMag\
+    \x20was\x20set\n\n\x0c\n\x05\x04\t\x02\x04\x04\x12\x03W\x02\n\n\x0c\n\
+    \x05\x04\t\x02\x04\x05\x12\x03W\x0b\x11\n\x0c\n\x05\x04\t\x02\x04\x01\
+    \x12\x03W\x12\x15\n\x0c\n\x05\x04\t\x02\x04\x03\x12\x03W\x18\x19\n\x14\n\
+    \x02\x05\x01\x12\x04]\0c\x01\x1a\x08\nSensor\n\n\n\n\x03\x05\x01\x01\x12\
+    \x03]\x05\x0f\n\x0b\n\x04\x05\x01\x02\0\x12\x03^\x02\x14\n\x0c\n\x05\x05\
+    \x01\x02\0\x01\x12\x03^\x02\x0f\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03^\
+    \x12\x13\n\x0b\n\x04\x05\x01\x02\x01\x12\x03_\x02\x0f\n\x0c\n\x05\x05\
+    \x01\x02\x01\x01\x12\x03_\x02\n\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03_\
+    \r\x0e\n\x0b\n\x04\x05\x01\x02\x02\x12\x03`\x02\x0f\n\x0c\n\x05\x05\x01\
+    \x02\x02\x01\x12\x03`\x02\n\n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03`\r\
+    \x0e\n\x0b\n\x04\x05\x01\x02\x03\x12\x03a\x02\x0f\n\x0c\n\x05\x05\x01\
+    \x02\x03\x01\x12\x03a\x02\n\n\x0c\n\x05\x05\x01\x02\x03\x02\x12\x03a\r\
+    \x0e\n\x0b\n\x04\x05\x01\x02\x04\x12\x03b\x02\x10\n\x0c\n\x05\x05\x01\
+    \x02\x04\x01\x12\x03b\x02\x0b\n\x0c\n\x05\x05\x01\x02\x04\x02\x12\x03b\
+    \x0e\x0f\n\n\n\x02\x05\x02\x12\x04e\0j\x01\n\n\n\x03\x05\x02\x01\x12\x03\
+    e\x05\x0e\n\x0b\n\x04\x05\x02\x02\0\x12\x03f\x02\x13\n\x0c\n\x05\x05\x02\
+    \x02\0\x01\x12\x03f\x02\x0e\n\x0c\n\x05\x05\x02\x02\0\x02\x12\x03f\x11\
+    \x12\n\x14\n\x04\x05\x02\x02\x01\x12\x03g\x02\x10\"\x07\x208\x20bit\n\n\
+    \x0c\n\x05\x05\x02\x02\x01\x01\x12\x03g\x02\x0b\n\x0c\n\x05\x05\x02\x02\
+    \x01\x02\x12\x03g\x0e\x0f\n\x15\n\x04\x05\x02\x02\x02\x12\x03h\x02\r\"\
+    \x08\x2010\x20bit\n\n\x0c\n\x05\x05\x02\x02\x02\x01\x12\x03h\x02\x08\n\
+    \x0c\n\x05\x05\x02\x02\x02\x02\x12\x03h\x0b\x0c\n\x15\n\x04\x05\x02\x02\
+    \x03\x12\x03i\x02\x16\"\x08\x2012\x20bit\n\n\x0c\n\x05\x05\x02\x02\x03\
+    \x01\x12\x03i\x02\x11\n\x0c\n\x05\x05\x02\x02\x03\x02\x12\x03i\x14\x15\n\
+    =\n\x02\x04\n\x12\x04m\0r\x01\x1a1\x20configures\x20the\x20imu,\x20zero\
+    \x20fields\x20use\x20the\x20default\n\n\n\n\x03\x04\n\x01\x12\x03m\x08\
+    \x1b\n\x11\n\x04\x04\n\x02\0\x12\x03n\x02\x16\"\x04\x20Hz\n\n\x0c\n\x05\
+    \x04\n\x02\0\x05\x12\x03n\x02\x08\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03n\t\
+    \x11\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03n\x14\x15\n\x11\n\x04\x04\n\x02\
+    \x01\x12\x03o\x02\x14\"\x04\x20Hz\n\n\x0c\n\x05\x04\n\x02\x01\x05\x12\
+    \x03o\x02\x08\n\x0c\n\x05\x04\n\x02\x01\x01\x12\x03o\t\x0f\n\x0c\n\x05\
+    \x04\n\x02\x01\x03\x12\x03o\x12\x13\n\x0b\n\x04\x04\n\x02\x02\x12\x03p\
+    \x02\x1c\n\x0c\n\x05\x04\n\x02\x02\x06\x12\x03p\x02\x0c\n\x0c\n\x05\x04\
+    \n\x02\x02\x01\x12\x03p\r\x17\n\x0c\n\x05\x04\n\x02\x02\x03\x12\x03p\x1a\
+    \x1b\n\x0b\n\x04\x04\n\x02\x03\x12\x03q\x02\x1a\n\x0c\n\x05\x04\n\x02\
+    \x03\x06\x12\x03q\x02\x0b\n\x0c\n\x05\x04\n\x02\x03\x01\x12\x03q\x0c\x15\
+    \n\x0c\n\x05\x04\n\x02\x03\x03\x12\x03q\x18\x19\n\x19\n\x02\x05\x03\x12\
+    \x04w\0\x7f\x01\x1a\r\nCalibration\n\n\n\n\x03\x05\x03\x01\x12\x03w\x05\
+    \x14\n'\n\x04\x05\x03\x02\0\x12\x03x\x02\x19\"\x1a\x20only\x20report\x20\
+    the\x20progress\n\n\x0c\n\x05\x05\x03\x02\0\x01\x12\x03x\x02\x14\n\x0c\n\
+    \x05\x05\x03\x02\0\x02\x12\x03x\x17\x18\n;\n\x04\x05\x03\x02\x01\x12\x03\
+    y\x02\x12\".\x20start\x20the\x20six\x20pose\x20accelerometer\x20calibrat\
+    ion\n\n\x0c\n\x05\x05\x03\x02\x01\x01\x12\x03y\x02\r\n\x0c\n\x05\x05\x03\
+    \x02\x01\x02\x12\x03y\x10\x11\nA\n\x04\x05\x03\x02\x02\x12\x03z\x02\x14\
+    \"4\x20capture\x20the\x20pose\x20the\x20sensor\x20is\x20being\x20held\
+    \x20still\x20in\n\n\x0c\n\x05\x05\x03\x02\x02\x01\x12\x03z\x02\x0f\n\x0c\
+    \n\x05\x05\x03\x02\x02\x02\x12\x03z\x12\x13\nW\n\x04\x05\x03\x02\x03\x12\
+    \x03{\x02\x10\"J\x20start\x20collecting\x20mag\x20readings,\x20turn\x20t\
+    he\x20sensor\x20through\x20every\x20orientation\n\n\x0c\n\x05\x05\x03\
+    \x02\x03\x01\x12\x03{\x02\x0b\n\x0c\n\x05\x05\x03\x02\x03\x02\x12\x03{\
+    \x0e\x0f\n.\n\x04\x05\x03\x02\x04\x12\x03|\x02\x19\"!\x20fit\x20and\x20s\
+    ave\x20what\x20was\x20collected\n\n\x0c\n\x05\x05\x03\x02\x04\x01\x12\
+    \x03|\x02\x14\n\x0c\n\x05\x05\x03\x02\x04\x02\x12\x03|\x17\x18\nE\n\x04\
+    \x05\x03\x02\x05\x12\x03}\x02\x19\"8\x20drop\x20what\x20was\x20collected\
+    ,\x20the\x20saved\x20calibration\x20is\x20kept\n\n\x0c\n\x05\x05\x03\x02\
+    \x05\x01\x12\x03}\x02\x14\n\x0c\n\x05\x05\x03\x02\x05\x02\x12\x03}\x17\
+    \x18\n+\n\x04\x05\x03\x02\x06\x12\x03~\x02\x18\"\x1e\x20forget\x20the\
+    \x20saved\x20calibration\n\n\x0c\n\x05\x05\x03\x02\x06\x01\x12\x03~\x02\
+    \x13\n\x0c\n\x05\x05\x03\x02\x06\x02\x12\x03~\x16\x17\n\x0c\n\x02\x05\
+    \x04\x12\x06\x81\x01\0\x88\x01\x01\n\x0b\n\x03\x05\x04\x01\x12\x04\x81\
+    \x01\x05\t\n\x0c\n\x04\x05\x04\x02\0\x12\x04\x82\x01\x02\x0b\n\r\n\x05\
+    \x05\x04\x02\0\x01\x12\x04\x82\x01\x02\x06\n\r\n\x05\x05\x04\x02\0\x02\
+    \x12\x04\x82\x01\t\n\n\x0c\n\x04\x05\x04\x02\x01\x12\x04\x83\x01\x02\r\n\
+    \r\n\x05\x05\x04\x02\x01\x01\x12\x04\x83\x01\x02\x08\n\r\n\x05\x05\x04\
+    \x02\x01\x02\x12\x04\x83\x01\x0b\x0c\n\x0c\n\x04\x05\x04\x02\x02\x12\x04\
+    \x84\x01\x02\x0b\n\r\n\x05\x05\x04\x02\x02\x01\x12\x04\x84\x01\x02\x06\n\
+    \r\n\x05\x05\x04\x02\x02\x02\x12\x04\x84\x01\t\n\n\x0c\n\x04\x05\x04\x02\
+    \x03\x12\x04\x85\x01\x02\r\n\r\n\x05\x05\x04\x02\x03\x01\x12\x04\x85\x01\
+    \x02\x08\n\r\n\x05\x05\x04\x02\x03\x02\x12\x04\x85\x01\x0b\x0c\n\x0c\n\
+    \x04\x05\x04\x02\x04\x12\x04\x86\x01\x02\x0b\n\r\n\x05\x05\x04\x02\x04\
+    \x01\x12\x04\x86\x01\x02\x06\n\r\n\x05\x05\x04\x02\x04\x02\x12\x04\x86\
+    \x01\t\n\n\x0c\n\x04\x05\x04\x02\x05\x12\x04\x87\x01\x02\r\n\r\n\x05\x05\
+    \x04\x02\x05\x01\x12\x04\x87\x01\x02\x08\n\r\n\x05\x05\x04\x02\x05\x02\
+    \x12\x04\x87\x01\x0b\x0c\n\x0c\n\x02\x04\x0b\x12\x06\x8a\x01\0\x8c\x01\
+    \x01\n\x0b\n\x03\x04\x0b\x01\x12\x04\x8a\x01\x08\x11\n\x0c\n\x04\x04\x0b\
+    \x02\0\x12\x04\x8b\x01\x02\x1b\n\r\n\x05\x04\x0b\x02\0\x06\x12\x04\x8b\
+    \x01\x02\x11\n\r\n\x05\x04\x0b\x02\0\x01\x12\x04\x8b\x01\x12\x16\n\r\n\
+    \x05\x04\x0b\x02\0\x03\x12\x04\x8b\x01\x19\x1a\nM\n\x02\x04\x0c\x12\x06\
+    \x8f\x01\0\x94\x01\x01\x1a?\x20sent\x20by\x20the\x20sensor\x20after\x20e\
+    very\x20Calibrate,\x20before\x20the\x20response\n\n\x0b\n\x03\x04\x0c\
+    \x01\x12\x04\x8f\x01\x08\x19\n\x0c\n\x04\x04\x0c\x02\0\x12\x04\x90\x01\
+    \x02\x1b\n\r\n\x05\x04\x0c\x02\0\x05\x12\x04\x90\x01\x02\x06\n\r\n\x05\
+    \x04\x0c\x02\0\x01\x12\x04\x90\x01\x07\x16\n\r\n\x05\x04\x0c\x02\0\x03\
+    \x12\x04\x90\x01\x19\x1a\n\x0c\n\x04\x04\x0c\x02\x01\x12\x04\x91\x01\x02\
+    \x19\n\r\n\x05\x04\x0c\x02\x01\x05\x12\x04\x91\x01\x02\x06\n\r\n\x05\x04\
+    \x0c\x02\x01\x01\x12\x04\x91\x01\x07\x14\n\r\n\x05\x04\x0c\x02\x01\x03\
+    \x12\x04\x91\x01\x17\x18\nJ\n\x04\x04\x0c\x02\x02\x12\x04\x92\x01\x02!\"\
+    <\x20poses\x20still\x20to\x20capture\x20while\x20calibrating\x20the\x20a\
+    ccelerometer\n\n\r\n\x05\x04\x0c\x02\x02\x04\x12\x04\x92\x01\x02\n\n\r\n\
+    \x05\x04\x0c\x02\x02\x06\x12\x04\x92\x01\x0b\x0f\n\r\n\x05\x04\x0c\x02\
+    \x02\x01\x12\x04\x92\x01\x10\x1c\n\r\n\x05\x04\x0c\x02\x02\x03\x12\x04\
+    \x92\x01\x1f\x20\nP\n\x04\x04\x0c\x02\x03\x12\x04\x93\x01\x02\x18\"B\x20\
+    mag\x20readings\x20collected\x20so\x20far\x20while\x20calibrating\x20the\
+    \x20magnetometer\n\n\r\n\x05\x04\x0c\x02\x03\x05\x12\x04\x93\x01\x02\x08\
+    \n\r\n\x05\x04\x0c\x02\x03\x01\x12\x04\x93\x01\t\x13\n\r\n\x05\x04\x0c\
+    \x02\x03\x03\x12\x04\x93\x01\x16\x17\ns\n\x02\x04\r\x12\x06\x9a\x01\0\
+    \x9d\x01\x01\x1aV\x20a\x20session\x20stored\x20on\x20the\x20sensor,\x20i\
+    ds\x20count\x20up\x20in\x20the\x20order\x20the\x20sessions\x20were\x20re\
+    corded\n2\r\nSession\x20log\n\n\x0b\n\x03\x04\r\x01\x12\x04\x9a\x01\x08\
+    \x15\n\x0c\n\x04\x04\r\x02\0\x12\x04\x9b\x01\x02\x10\n\r\n\x05\x04\r\x02\
+    \0\x05\x12\x04\x9b\x01\x02\x08\n\r\n\x05\x04\r\x02\0\x01\x12\x04\x9b\x01\
+    \t\x0b\n\r\n\x05\x04\r\x02\0\x03\x12\x04\x9b\x01\x0e\x0f\n\x15\n\x04\x04\
+    \r\x02\x01\x12\x04\x9c\x01\x02\x12\"\x07\x20bytes\n\n\r\n\x05\x04\r\x02\
+    \x01\x05\x12\x04\x9c\x01\x02\x08\n\r\n\x05\x04\r\x02\x01\x01\x12\x04\x9c\
+    \x01\t\r\n\r\n\x05\x04\r\x02\x01\x03\x12\x04\x9c\x01\x10\x11\np\n\x02\
+    \x04\x0e\x12\x06\xa0\x01\0\xa2\x01\x01\x1ab\x20lists\x20the\x20stored\
+    \x20sessions\x20with\x20an\x20id\x20of\x20at\x20least\x20from,\x20oldest\
+    \x20first,\x20answered\x20with\x20a\x20SessionList\n\n\x0b\n\x03\x04\x0e\
+    \x01\x12\x04\xa0\x01\x08\x14\n\x0c\n\x04\x04\x0e\x02\0\x12\x04\xa1\x01\
+    \x02\x12\n\r\n\x05\x04\x0e\x02\0\x05\x12\x04\xa1\x01\x02\x08\n\r\n\x05\
+    \x04\x0e\x02\0\x01\x12\x04\xa1\x01\t\r\n\r\n\x05\x04\x0e\x02\0\x03\x12\
+    \x04\xa1\x01\x10\x11\n\x0c\n\x02\x04\x0f\x12\x06\xa4\x01\0\xa7\x01\x01\n\
+    \x0b\n\x03\x04\x0f\x01\x12\x04\xa4\x01\x08\x13\n\x0c\n\x04\x04\x0f\x02\0\
+    \x12\x04\xa5\x01\x02&\n\r\n\x05\x04\x0f\x02\0\x04\x12\x04\xa5\x01\x02\n\
+    \n\r\n\x05\x04\x0f\x02\0\x06\x12\x04\xa5\x01\x0b\x18\n\r\n\x05\x04\x0f\
+    \x02\0\x01\x12\x04\xa5\x01\x19!\n\r\n\x05\x04\x0f\x02\0\x03\x12\x04\xa5\
+    \x01$%\n]\n\x04\x04\x0f\x02\x01\x12\x04\xa6\x01\x02\x10\"O\x20there\x20a\
+    re\x20more\x20sessions\x20after\x20the\x20last\x20one\x20listed,\x20list\
+    \x20again\x20from\x20its\x20id\x20+\x201\n\n\r\n\x05\x04\x0f\x02\x01\x05\
+    \x12\x04\xa6\x01\x02\x06\n\r\n\x05\x04\x0f\x02\x01\x01\x12\x04\xa6\x01\
+    \x07\x0b\n\r\n\x05\x04\x0f\x02\x01\x03\x12\x04\xa6\x01\x0e\x0f\nc\n\x02\
+    \x04\x10\x12\x06\xaa\x01\0\xad\x01\x01\x1aU\x20asks\x20for\x20the\x20byt\
+    es\x20of\x20a\x20stored\x20session\x20from\x20offset\x20on,\x20answered\
+    \x20with\x20a\x20SessionChunk\n\n\x0b\n\x03\x04\x10\x01\x12\x04\xaa\x01\
+    \x08\x17\n\x0c\n\x04\x04\x10\x02\0\x12\x04\xab\x01\x02\x10\n\r\n\x05\x04\
+    \x10\x02\0\x05\x12\x04\xab\x01\x02\x08\n\r\n\x05\x04\x10\x02\0\x01\x12\
+    \x04\xab\x01\t\x0b\n\r\n\x05\x04\x10\x02\0\x03\x12\x04\xab\x01\x0e\x0f\n\
+    \x0c\n\x04\x04\x10\x02\x01\x12\x04\xac\x01\x02\x14\n\r\n\x05\x04\x10\x02\
+    \x01\x05\x12\x04\xac\x01\x02\x08\n\r\n\x05\x04\x10\x02\x01\x01\x12\x04\
+    \xac\x01\t\x0f\n\r\n\x05\x04\x10\x02\x01\x03\x12\x04\xac\x01\x12\x13\nU\
+    \n\x02\x04\x11\x12\x06\xb0\x01\0\xb5\x01\x01\x1aG\x20part\x20of\x20a\x20\
+    stored\x20session,\x20the\x20whole\x20session\x20is\x20the\x20Rep\x20it\
+    \x20was\x20sent\x20as\n\n\x0b\n\x03\x04\x11\x01\x12\x04\xb0\x01\x08\x14\
+    \n\x0c\n\x04\x04\x11\x02\0\x12\x04\xb1\x01\x02\x10\n\r\n\x05\x04\x11\x02\
+    \0\x05\x12\x04\xb1\x01\x02\x08\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\xb1\
+    \x01\t\x0b\n\r\n\x05\x04\x11\x02\0\x03\x12\x04\xb1\x01\x0e\x0f\n\x0c\n\
+    \x04\x04\x11\x02\x01\x12\x04\xb2\x01\x02\x14\n\r\n\x05\x04\x11\x02\x01\
+    \x05\x12\x04\xb2\x01\x02\x08\n\r\n\x05\x04\x11\x02\x01\x01\x12\x04\xb2\
+    \x01\t\x0f\n\r\n\x05\x04\x11\x02\x01\x03\x12\x04\xb2\x01\x12\x13\n$\n\
+    \x04\x04\x11\x02\x02\x12\x04\xb3\x01\x02\x12\"\x16\x20of\x20the\x20whole\
+    \x20session\n\n\r\n\x05\x04\x11\x02\x02\x05\x12\x04\xb3\x01\x02\x08\n\r\
+    \n\x05\x04\x11\x02\x02\x01\x12\x04\xb3\x01\t\r\n\r\n\x05\x04\x11\x02\x02\
+    \x03\x12\x04\xb3\x01\x10\x11\n\x0c\n\x04\x04\x11\x02\x03\x12\x04\xb4\x01\
+    \x02\x11\n\r\n\x05\x04\x11\x02\x03\x05\x12\x04\xb4\x01\x02\x07\n\r\n\x05\
+    \x04\x11\x02\x03\x01\x12\x04\xb4\x01\x08\x0c\n\r\n\x05\x04\x11\x02\x03\
+    \x03\x12\x04\xb4\x01\x0f\x10\n)\n\x02\x04\x12\x12\x04\xb8\x01\0\x18\x1a\
+    \x1d\x20erases\x20every\x20stored\x20session\n\n\x0b\n\x03\x04\x12\x01\
+    \x12\x04\xb8\x01\x08\x15\n_\n\x02\x04\x13\x12\x06\xbe\x01\0\xc1\x01\x01\
+    \x1aE\x20what\x20the\x20sensor\x20keeps\x20across\x20restarts,\x20stored\
+    \x20behind\x20a\x20version\x20byte\n2\n\nSettings\n\n\x0b\n\x03\x04\x13\
+    \x01\x12\x04\xbe\x01\x08\x10\n\x0c\n\x04\x04\x13\x02\0\x12\x04\xbf\x01\
+    \x02)\n\r\n\x05\x04\x13\x02\0\x06\x12\x04\xbf\x01\x02\x19\n\r\n\x05\x04\
+    \x13\x02\0\x01\x12\x04\xbf\x01\x1a$\n\r\n\x05\x04\x13\x02\0\x03\x12\x04\
+    \xbf\x01'(\n\x0c\n\x04\x04\x13\x02\x01\x12\x04\xc0\x01\x02!\n\r\n\x05\
+    \x04\x13\x02\x01\x06\x12\x04\xc0\x01\x02\x15\n\r\n\x05\x04\x13\x02\x01\
+    \x01\x12\x04\xc0\x01\x16\x1c\n\r\n\x05\x04\x13\x02\x01\x03\x12\x04\xc0\
+    \x01\x1f\x20\n\x19\n\x02\x05\x05\x12\x06\xc6\x01\0\xd1\x01\x01\x1a\x0b\n\
+    Responses\n\n\x0b\n\x03\x05\x05\x01\x12\x04\xc6\x01\x05\x0e\n\x0c\n\x04\
+    \x05\x05\x02\0\x12\x04\xc7\x01\x02\x0b\n\r\n\x05\x05\x05\x02\0\x01\x12\
+    \x04\xc7\x01\x02\x06\n\r\n\x05\x05\x05\x02\0\x02\x12\x04\xc7\x01\t\n\n9\
+    \n\x04\x05\x05\x02\x01\x12\x04\xc8\x01\x02\x12\"+\x20the\x20frame\x20or\
+    \x20message\x20could\x20not\x20be\x20decoded\n\n\r\n\x05\x05\x05\x02\x01\
+    \x01\x12\x04\xc8\x01\x02\r\n\r\n\x05\x05\x05\x02\x01\x02\x12\x04\xc8\x01\
+    \x10\x11\n7\n\x04\x05\x05\x02\x02\x12\x04\xc9\x01\x02\x12\")\x20the\x20s\
+    ensor\x20does\x20not\x20accept\x20this\x20message\n\n\r\n\x05\x05\x05\
+    \x02\x02\x01\x12\x04\xc9\x01\x02\r\n\r\n\x05\x05\x05\x02\x02\x02\x12\x04\
+    \xc9\x01\x10\x11\n9\n\x04\x05\x05\x02\x03\x12\x04\xca\x01\x02\x1a\"+\x20\
+    the\x20configuration\x20has\x20no\x20start\x20algorithim\n\n\r\n\x05\x05\
+    \x05\x02\x03\x01\x12\x04\xca\x01\x02\x15\n\r\n\x05\x05\x05\x02\x03\x02\
+    \x12\x04\xca\x01\x18\x19\n4\n\x04\x05\x05\x02\x04\x12\x04\xcb\x01\x02\
+    \x14\"&\x20the\x20sensor\x20could\x20not\x20send\x20its\x20output\n\n\r\
+    \n\x05\x05\x05\x02\x04\x01\x12\x04\xcb\x01\x02\x0f\n\r\n\x05\x05\x05\x02\
+    \x04\x02\x12\x04\xcb\x01\x12\x13\n6\n\x04\x05\x05\x02\x05\x12\x04\xcc\
+    \x01\x02\x17\"(\x20a\x20field\x20of\x20the\x20message\x20is\x20out\x20of\
+    \x20range\n\n\r\n\x05\x05\x05\x02\x05\x01\x12\x04\xcc\x01\x02\x12\n\r\n\
+    \x05\x05\x05\x02\x05\x02\x12\x04\xcc\x01\x15\x16\nE\n\x04\x05\x05\x02\
+    \x06\x12\x04\xcd\x01\x02'\"7\x20the\x20imu\x20can\x20not\x20run\x20with\
+    \x20this\x20combination\x20of\x20settings\n\n\r\n\x05\x05\x05\x02\x06\
+    \x01\x12\x04\xcd\x01\x02\"\n\r\n\x05\x05\x05\x02\x06\x02\x12\x04\xcd\x01\
+    %&\nS\n\x04\x05\x05\x02\x07\x12\x04\xce\x01\x02\x19\"E\x20the\x20sensor\
+    \x20was\x20moving,\x20no\x20calibration\x20was\x20started\x20or\x20the\
+    \x20fit\x20failed\n\n\r\n\x05\x05\x05\x02\x07\x01\x12\x04\xce\x01\x02\
+    \x14\n\r\n\x05\x05\x05\x02\x07\x02\x12\x04\xce\x01\x17\x18\nJ\n\x04\x05\
+    \x05\x02\x08\x12\x04\xcf\x01\x02\x18\"<\x20no\x20stored\x20session\x20ha\
+    s\x20the\x20id,\x20it\x20may\x20have\x20been\x20overwritten\n\n\r\n\x05\
+    \x05\x05\x02\x08\x01\x12\x04\xcf\x01\x02\x13\n\r\n\x05\x05\x05\x02\x08\
+    \x02\x12\x04\xcf\x01\x16\x17\nR\n\x04\x05\x05\x02\t\x12\x04\xd0\x01\x02\
+    \x15\"D\x20the\x20flash\x20could\x20not\x20be\x20read\x20or\x20written,\
+    \x20or\x20there\x20is\x20no\x20session\x20log\n\n\r\n\x05\x05\x05\x02\t\
+    \x01\x12\x04\xd0\x01\x02\x10\n\r\n\x05\x05\x05\x02\t\x02\x12\x04\xd0\x01\
+    \x13\x14\n@\n\x02\x04\x14\x12\x06\xd4\x01\0\xd8\x01\x01\x1a2\x20sent\x20\
+    by\x20the\x20sensor\x20for\x20every\x20message\x20it\x20receives\n\n\x0b\
+    \n\x03\x04\x14\x01\x12\x04\xd4\x01\x08\x10\nV\n\x04\x04\x14\x02\0\x12\
+    \x04\xd5\x01\x02\x17\"H\x20requestId\x20of\x20the\x20message\x20this\x20\
+    responds\x20to,\x200\x20if\x20it\x20could\x20not\x20be\x20parsed\n\n\r\n\
+    \x05\x04\x14\x02\0\x05\x12\x04\xd5\x01\x02\x08\n\r\n\x05\x04\x14\x02\0\
+    \x01\x12\x04\xd5\x01\t\x12\n\r\n\x05\x04\x14\x02\0\x03\x12\x04\xd5\x01\
+    \x15\x16\n\x0c\n\x04\x04\x14\x02\x01\x12\x04\xd6\x01\x02\x13\n\r\n\x05\
+    \x04\x14\x02\x01\x05\x12\x04\xd6\x01\x02\x06\n\r\n\x05\x04\x14\x02\x01\
+    \x01\x12\x04\xd6\x01\x07\x0e\n\r\n\x05\x04\x14\x02\x01\x03\x12\x04\xd6\
+    \x01\x11\x12\n\x0c\n\x04\x04\x14\x02\x02\x12\x04\xd7\x01\x02\x16\n\r\n\
+    \x05\x04\x14\x02\x02\x06\x12\x04\xd7\x01\x02\x0b\n\r\n\x05\x04\x14\x02\
+    \x02\x01\x12\x04\xd7\x01\x0c\x11\n\r\n\x05\x04\x14\x02\x02\x03\x12\x04\
+    \xd7\x01\x14\x15\n\"\n\x02\x04\x15\x12\x06\xdd\x01\0\xee\x01\x01\x1a\x14\
+    \ntransfer\x20Container\n\n\x0b\n\x03\x04\x15\x01\x12\x04\xdd\x01\x08\
+    \x0f\n\x0e\n\x04\x04\x15\x08\0\x12\x06\xde\x01\x02\xec\x01\x03\n\r\n\x05\
+    \x04\x15\x08\0\x01\x12\x04\xde\x01\x08\x0f\n\x0c\n\x04\x04\x15\x02\0\x12\
+    \x04\xdf\x01\x04+\n\r\n\x05\x04\x15\x02\0\x06\x12\x04\xdf\x01\x04\x1b\n\
+    \r\n\x05\x04\x15\x02\0\x01\x12\x04\xdf\x01\x1c&\n\r\n\x05\x04\x15\x02\0\
+    \x03\x12\x04\xdf\x01)*\n\x0c\n\x04\x04\x15\x02\x01\x12\x04\xe0\x01\x04\
+    \x10\n\r\n\x05\x04\x15\x02\x01\x06\x12\x04\xe0\x01\x04\x07\n\r\n\x05\x04\
+    \x15\x02\x01\x01\x12\x04\xe0\x01\x08\x0b\n\r\n\x05\x04\x15\x02\x01\x03\
+    \x12\x04\xe0\x01\x0e\x0f\n\x0c\n\x04\x04\x15\x02\x02\x12\x04\xe1\x01\x04\
+    \x1a\n\r\n\x05\x04\x15\x02\x02\x06\x12\x04\xe1\x01\x04\x0c\n\r\n\x05\x04\
+    \x15\x02\x02\x01\x12\x04\xe1\x01\r\x15\n\r\n\x05\x04\x15\x02\x02\x03\x12\
+    \x04\xe1\x01\x18\x19\n\x0c\n\x04\x04\x15\x02\x03\x12\x04\xe2\x01\x04\x1c\
+    \n\r\n\x05\x04\x15\x02\x03\x06\x12\x04\xe2\x01\x04\r\n\r\n\x05\x04\x15\
+    \x02\x03\x01\x12\x04\xe2\x01\x0e\x17\n\r\n\x05\x04\x15\x02\x03\x03\x12\
+    \x04\xe2\x01\x1a\x1b\n\x0c\n\x04\x04\x15\x02\x04\x12\x04\xe3\x01\x04\x20\
+    \n\r\n\x05\x04\x15\x02\x04\x06\x12\x04\xe3\x01\x04\x0f\n\r\n\x05\x04\x15\
+    \x02\x04\x01\x12\x04\xe3\x01\x10\x1b\n\r\n\x05\x04\x15\x02\x04\x03\x12\
+    \x04\xe3\x01\x1e\x1f\n\x0c\n\x04\x04\x15\x02\x05\x12\x04\xe4\x01\x040\n\
+    \r\n\x05\x04\x15\x02\x05\x06\x12\x04\xe4\x01\x04\x17\n\r\n\x05\x04\x15\
+    \x02\x05\x01\x12\x04\xe4\x01\x18+\n\r\n\x05\x04\x15\x02\x05\x03\x12\x04\
+    \xe4\x01./\n\x0c\n\x04\x04\x15\x02\x06\x12\x04\xe5\x01\x04\x1c\n\r\n\x05\
+    \x04\x15\x02\x06\x06\x12\x04\xe5\x01\x04\r\n\r\n\x05\x04\x15\x02\x06\x01\
+    \x12\x04\xe5\x01\x0e\x17\n\r\n\x05\x04\x15\x02\x06\x03\x12\x04\xe5\x01\
+    \x1a\x1b\n\x0c\n\x04\x04\x15\x02\x07\x12\x04\xe6\x01\x04,\n\r\n\x05\x04\
+    \x15\x02\x07\x06\x12\x04\xe6\x01\x04\x15\n\r\n\x05\x04\x15\x02\x07\x01\
+    \x12\x04\xe6\x01\x16'\n\r\n\x05\x04\x15\x02\x07\x03\x12\x04\xe6\x01*+\n\
+    \x0c\n\x04\x04\x15\x02\x08\x12\x04\xe7\x01\x04#\n\r\n\x05\x04\x15\x02\
+    \x08\x06\x12\x04\xe7\x01\x04\x10\n\r\n\x05\x04\x15\x02\x08\x01\x12\x04\
+    \xe7\x01\x11\x1d\n\r\n\x05\x04\x15\x02\x08\x03\x12\x04\xe7\x01\x20\"\n\
+    \x0c\n\x04\x04\x15\x02\t\x12\x04\xe8\x01\x04!\n\r\n\x05\x04\x15\x02\t\
+    \x06\x12\x04\xe8\x01\x04\x0f\n\r\n\x05\x04\x15\x02\t\x01\x12\x04\xe8\x01\
+    \x10\x1b\n\r\n\x05\x04\x15\x02\t\x03\x12\x04\xe8\x01\x1e\x20\n\x0c\n\x04\
+    \x04\x15\x02\n\x12\x04\xe9\x01\x04)\n\r\n\x05\x04\x15\x02\n\x06\x12\x04\
+    \xe9\x01\x04\x13\n\r\n\x05\x04\x15\x02\n\x01\x12\x04\xe9\x01\x14#\n\r\n\
+    \x05\x04\x15\x02\n\x03\x12\x04\xe9\x01&(\n\x0c\n\x04\x04\x15\x02\x0b\x12\
+    \x04\xea\x01\x04#\n\r\n\x05\x04\x15\x02\x0b\x06\x12\x04\xea\x01\x04\x10\
+    \n\r\n\x05\x04\x15\x02\x0b\x01\x12\x04\xea\x01\x11\x1d\n\r\n\x05\x04\x15\
+    \x02\x0b\x03\x12\x04\xea\x01\x20\"\n\x0c\n\x04\x04\x15\x02\x0c\x12\x04\
+    \xeb\x01\x04%\n\r\n\x05\x04\x15\x02\x0c\x06\x12\x04\xeb\x01\x04\x11\n\r\
+    \n\x05\x04\x15\x02\x0c\x01\x12\x04\xeb\x01\x12\x1f\n\r\n\x05\x04\x15\x02\
+    \x0c\x03\x12\x04\xeb\x01\"$\n@\n\x04\x04\x15\x02\r\x12\x04\xed\x01\x02\
+    \x17\"2\x20chosen\x20by\x20the\x20phone,\x20echoed\x20back\x20in\x20the\
+    \x20response\n\n\r\n\x05\x04\x15\x02\r\x05\x12\x04\xed\x01\x02\x08\n\r\n\
+    \x05\x04\x15\x02\r\x01\x12\x04\xed\x01\t\x12\n\r\n\x05\x04\x15\x02\r\x03\
+    \x12\x04\xed\x01\x15\x16b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -1803,7 +5551,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(9);
+            let mut messages = ::std::vec::Vec::with_capacity(22);
             messages.push(Timed::generated_message_descriptor_data());
             messages.push(Counted::generated_message_descriptor_data());
             messages.push(SpikeStart::generated_message_descriptor_data());
@@ -1812,8 +5560,27 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(BounceMeasurment::generated_message_descriptor_data());
             messages.push(AlgorithimConfiguration::generated_message_descriptor_data());
             messages.push(Rep::generated_message_descriptor_data());
+            messages.push(RawStream::generated_message_descriptor_data());
+            messages.push(SampleBatch::generated_message_descriptor_data());
+            messages.push(SensorConfiguration::generated_message_descriptor_data());
+            messages.push(Calibrate::generated_message_descriptor_data());
+            messages.push(CalibrationStatus::generated_message_descriptor_data());
+            messages.push(StoredSession::generated_message_descriptor_data());
+            messages.push(ListSessions::generated_message_descriptor_data());
+            messages.push(SessionList::generated_message_descriptor_data());
+            messages.push(DownloadSession::generated_message_descriptor_data());
+            messages.push(SessionChunk::generated_message_descriptor_data());
+            messages.push(EraseSessions::generated_message_descriptor_data());
+            messages.push(Settings::generated_message_descriptor_data());
+            messages.push(Response::generated_message_descriptor_data());
             messages.push(Message::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(0);
+            let mut enums = ::std::vec::Vec::with_capacity(6);
+            enums.push(HeightModel::generated_enum_descriptor_data());
+            enums.push(AccelRange::generated_enum_descriptor_data());
+            enums.push(AccelMode::generated_enum_descriptor_data());
+            enums.push(CalibrationStep::generated_enum_descriptor_data());
+            enums.push(Pose::generated_enum_descriptor_data());
+            enums.push(ErrorCode::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
@@ -0,0 +1,126 @@
+/*
+    flash storage
+
+    nor flash is erased a sector at a time, which sets every bit to 1, and writing can only clear
+    bits. the firmware implements Flash over a data partition, MemoryFlash behaves the same way in
+    memory so whatever is built on top can be tested on the host.
+*/
+
+/// bytes erased at once
+pub const SECTOR_SIZE: usize = 4096;
+
+/// value of an erased byte
+pub const ERASED: u8 = 0xFF;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FlashError {
+    /// the access goes past the end of the flash
+    OutOfBounds,
+    /// the flash itself failed
+    Hardware(String),
+}
+
+pub trait Flash {
+    /// size in bytes, a multiple of SECTOR_SIZE
+    fn size(&self) -> usize;
+    fn read(&mut self, offset: usize, buffer: &mut [u8]) -> Result<(), FlashError>;
+    /// clears the bits that are 0 in bytes, the bytes should have been erased first
+    fn write(&mut self, offset: usize, bytes: &[u8]) -> Result<(), FlashError>;
+    fn erase_sector(&mut self, sector: usize) -> Result<(), FlashError>;
+
+    fn sectors(&self) -> usize {
+        self.size() / SECTOR_SIZE
+    }
+}
+
+impl<T: Flash + ?Sized> Flash for Box<T> {
+    fn size(&self) -> usize {
+        (**self).size()
+    }
+
+    fn read(&mut self, offset: usize, buffer: &mut [u8]) -> Result<(), FlashError> {
+        (**self).read(offset, buffer)
+    }
+
+    fn write(&mut self, offset: usize, bytes: &[u8]) -> Result<(), FlashError> {
+        (**self).write(offset, bytes)
+    }
+
+    fn erase_sector(&mut self, sector: usize) -> Result<(), FlashError> {
+        (**self).erase_sector(sector)
+    }
+}
+
+/// flash in memory, for tests and running without hardware
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryFlash {
+    bytes: Vec<u8>,
+}
+
+impl MemoryFlash {
+    /// erased flash of sectors sectors
+    pub fn new(sectors: usize) -> Self {
+        MemoryFlash {
+            bytes: vec![ERASED; sectors * SECTOR_SIZE],
+        }
+    }
+
+    fn range(&self, offset: usize, len: usize) -> Result<std::ops::Range<usize>, FlashError> {
+        match offset.checked_add(len) {
+            Some(end) if end <= self.bytes.len() => Ok(offset..end),
+            _ => Err(FlashError::OutOfBounds),
+        }
+    }
+}
+
+impl Flash for MemoryFlash {
+    fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn read(&mut self, offset: usize, buffer: &mut [u8]) -> Result<(), FlashError> {
+        let range = self.range(offset, buffer.len())?;
+        buffer.copy_from_slice(&self.bytes[range]);
+        Ok(())
+    }
+
+    fn write(&mut self, offset: usize, bytes: &[u8]) -> Result<(), FlashError> {
+        let range = self.range(offset, bytes.len())?;
+        for (stored, byte) in self.bytes[range].iter_mut().zip(bytes) {
+            *stored &= byte;
+        }
+        Ok(())
+    }
+
+    fn erase_sector(&mut self, sector: usize) -> Result<(), FlashError> {
+        let range = self.range(sector * SECTOR_SIZE, SECTOR_SIZE)?;
+        self.bytes[range].fill(ERASED);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn behaves_like_nor_flash() {
+        let mut flash = MemoryFlash::new(2);
+        let mut buffer = [0u8; 2];
+
+        flash.write(10, &[0x0F, 0xAA]).unwrap();
+        flash.write(10, &[0xF0, 0xFF]).unwrap();
+        flash.read(10, &mut buffer).unwrap();
+        assert_eq!(buffer, [0x00, 0xAA]);
+
+        flash.erase_sector(0).unwrap();
+        flash.read(10, &mut buffer).unwrap();
+        assert_eq!(buffer, [ERASED, ERASED]);
+
+        assert_eq!(flash.sectors(), 2);
+        assert_eq!(
+            flash.write(2 * SECTOR_SIZE - 1, &[0, 0]),
+            Err(FlashError::OutOfBounds)
+        );
+    }
+}
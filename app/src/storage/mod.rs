@@ -0,0 +1,2 @@
+pub mod flash;
+pub mod ring_log;
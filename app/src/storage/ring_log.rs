@@ -0,0 +1,365 @@
+use super::flash::{Flash, FlashError, SECTOR_SIZE};
+use crate::transport::framing::crc16;
+
+/*
+    ring log
+
+    an append only log of records on flash, once it is full the oldest sector is erased to make
+    room. every sector starts with a header
+
+        magic u32 | sequence u32 | first id u32
+
+    the sequence counts up by one for every sector started so the newest one can be found after a
+    restart. the first id is the id the sector's first record gets, so ids carry on after a restart
+    even when the newest sector has no records yet, e.g. right after an erase. records follow one
+    after the other and never cross into the next sector
+
+        length u16 | kind u8 | id u32 | crc16 u16 | payload
+
+    everything is little endian and the crc covers the kind, id and payload. a length of 0xFFFF is
+    erased flash, the free space at the end of a sector. ids count up by one per record across the
+    whole log. a record whose crc does not match, e.g. the power went while it was written, is
+    skipped.
+*/
+
+const SECTOR_MAGIC: u32 = u32::from_le_bytes(*b"RSLG");
+const SECTOR_HEADER_SIZE: usize = 12;
+const RECORD_HEADER_SIZE: usize = 9;
+const FREE: u16 = 0xFFFF;
+
+/// largest payload a record can hold
+pub const MAX_RECORD_SIZE: usize = SECTOR_SIZE - SECTOR_HEADER_SIZE - RECORD_HEADER_SIZE;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogError {
+    Flash(FlashError),
+    /// the flash needs at least two sectors, one to write and one to erase
+    TooSmall,
+    /// the payload is over MAX_RECORD_SIZE
+    TooBig,
+    /// no record with the id, it was never written or has been erased
+    NotFound,
+}
+
+impl From<FlashError> for LogError {
+    fn from(err: FlashError) -> Self {
+        LogError::Flash(err)
+    }
+}
+
+/// a record in the log
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    pub id: u32,
+    pub kind: u8,
+    /// payload size in bytes
+    pub size: usize,
+    // flash offset of the payload
+    offset: usize,
+    crc: u16,
+}
+
+pub struct RingLog<F: Flash> {
+    flash: F,
+    // sector being written, its sequence and where the next record goes in it
+    head: usize,
+    sequence: u32,
+    write_offset: usize,
+    next_id: u32,
+}
+
+impl<F: Flash> RingLog<F> {
+    /// picks up the log already on the flash, or starts one if there is none
+    pub fn mount(flash: F) -> Result<Self, LogError> {
+        if flash.sectors() < 2 {
+            return Err(LogError::TooSmall);
+        }
+        let mut log = RingLog {
+            flash,
+            head: 0,
+            sequence: 0,
+            write_offset: SECTOR_HEADER_SIZE,
+            next_id: 0,
+        };
+
+        let sectors = log.sectors_oldest_first()?;
+        let Some(&(head, sequence, _)) = sectors.last() else {
+            log.start_sector(0, 0)?;
+            return Ok(log);
+        };
+        for (sector, _, first_id) in &sectors {
+            let (entries, end) = log.scan(*sector)?;
+            log.next_id = *first_id;
+            if let Some(last) = entries.last() {
+                log.next_id = last.id.wrapping_add(1);
+            }
+            if *sector == head {
+                log.write_offset = end;
+            }
+        }
+        log.head = head;
+        log.sequence = sequence;
+        Ok(log)
+    }
+
+    /// appends a record, returns its id
+    pub fn append(&mut self, kind: u8, payload: &[u8]) -> Result<u32, LogError> {
+        if payload.len() > MAX_RECORD_SIZE {
+            return Err(LogError::TooBig);
+        }
+        if self.write_offset + RECORD_HEADER_SIZE + payload.len() > SECTOR_SIZE {
+            let next = (self.head + 1) % self.flash.sectors();
+            self.start_sector(next, self.sequence.wrapping_add(1))?;
+        }
+
+        let id = self.next_id;
+        let mut record = Vec::with_capacity(RECORD_HEADER_SIZE + payload.len());
+        record.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+        record.push(kind);
+        record.extend_from_slice(&id.to_le_bytes());
+        record.extend_from_slice(&record_crc(kind, id, payload).to_le_bytes());
+        record.extend_from_slice(payload);
+
+        let offset = self.head * SECTOR_SIZE + self.write_offset;
+        if let Err(err) = self.flash.write(offset, &record) {
+            // whatever made it onto the flash can not be written over, carry on in the next sector
+            self.write_offset = SECTOR_SIZE;
+            return Err(err.into());
+        }
+        self.write_offset += record.len();
+        self.next_id = id.wrapping_add(1);
+        Ok(id)
+    }
+
+    /// up to max records with an id from from on, oldest first
+    pub fn entries(&mut self, from: u32, max: usize) -> Result<Vec<Entry>, LogError> {
+        let mut found = Vec::new();
+        for (sector, _, _) in self.sectors_oldest_first()? {
+            for entry in self.scan(sector)?.0 {
+                if found.len() == max {
+                    return Ok(found);
+                }
+                if entry.id >= from && self.verify(&entry)? {
+                    found.push(entry);
+                }
+            }
+        }
+        Ok(found)
+    }
+
+    pub fn find(&mut self, id: u32) -> Result<Entry, LogError> {
+        for (sector, _, _) in self.sectors_oldest_first()? {
+            if let Some(entry) = self.scan(sector)?.0.into_iter().find(|e| e.id == id) {
+                if self.verify(&entry)? {
+                    return Ok(entry);
+                }
+            }
+        }
+        Err(LogError::NotFound)
+    }
+
+    /// reads the payload of entry from offset into buffer, returns how many bytes were read
+    pub fn read(
+        &mut self,
+        entry: &Entry,
+        offset: usize,
+        buffer: &mut [u8],
+    ) -> Result<usize, LogError> {
+        let len = buffer.len().min(entry.size.saturating_sub(offset));
+        self.flash.read(entry.offset + offset, &mut buffer[..len])?;
+        Ok(len)
+    }
+
+    /// erases every record, ids carry on from where they were
+    pub fn erase(&mut self) -> Result<(), LogError> {
+        for (sector, _, _) in self.sectors_oldest_first()? {
+            self.flash.erase_sector(sector)?;
+        }
+        self.start_sector(0, self.sequence.wrapping_add(1))
+    }
+
+    fn start_sector(&mut self, sector: usize, sequence: u32) -> Result<(), LogError> {
+        self.flash.erase_sector(sector)?;
+        let mut header = [0u8; SECTOR_HEADER_SIZE];
+        header[..4].copy_from_slice(&SECTOR_MAGIC.to_le_bytes());
+        header[4..8].copy_from_slice(&sequence.to_le_bytes());
+        header[8..].copy_from_slice(&self.next_id.to_le_bytes());
+        self.flash.write(sector * SECTOR_SIZE, &header)?;
+        self.head = sector;
+        self.sequence = sequence;
+        self.write_offset = SECTOR_HEADER_SIZE;
+        Ok(())
+    }
+
+    // the sectors in use, their sequence and first id
+    fn sectors_oldest_first(&mut self) -> Result<Vec<(usize, u32, u32)>, LogError> {
+        let mut sectors = Vec::new();
+        for sector in 0..self.flash.sectors() {
+            let mut header = [0u8; SECTOR_HEADER_SIZE];
+            self.flash.read(sector * SECTOR_SIZE, &mut header)?;
+            let magic = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+            if magic == SECTOR_MAGIC {
+                let sequence = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+                let first_id = u32::from_le_bytes([header[8], header[9], header[10], header[11]]);
+                sectors.push((sector, sequence, first_id));
+            }
+        }
+        sectors.sort_by_key(|(_, sequence, _)| *sequence);
+        Ok(sectors)
+    }
+
+    // the records of a sector without checking their crc, and where its free space starts
+    fn scan(&mut self, sector: usize) -> Result<(Vec<Entry>, usize), LogError> {
+        let base = sector * SECTOR_SIZE;
+        let mut entries = Vec::new();
+        let mut offset = SECTOR_HEADER_SIZE;
+        while offset + RECORD_HEADER_SIZE <= SECTOR_SIZE {
+            let mut header = [0u8; RECORD_HEADER_SIZE];
+            self.flash.read(base + offset, &mut header)?;
+            let length = u16::from_le_bytes([header[0], header[1]]);
+            if length == FREE {
+                break;
+            }
+            let size = length as usize;
+            if offset + RECORD_HEADER_SIZE + size > SECTOR_SIZE {
+                // a torn header, nothing after it can be trusted
+                offset = SECTOR_SIZE;
+                break;
+            }
+            entries.push(Entry {
+                id: u32::from_le_bytes([header[3], header[4], header[5], header[6]]),
+                kind: header[2],
+                size,
+                offset: base + offset + RECORD_HEADER_SIZE,
+                crc: u16::from_le_bytes([header[7], header[8]]),
+            });
+            offset += RECORD_HEADER_SIZE + size;
+        }
+        Ok((entries, offset))
+    }
+
+    fn verify(&mut self, entry: &Entry) -> Result<bool, LogError> {
+        let mut payload = vec![0u8; entry.size];
+        self.flash.read(entry.offset, &mut payload)?;
+        Ok(record_crc(entry.kind, entry.id, &payload) == entry.crc)
+    }
+}
+
+fn record_crc(kind: u8, id: u32, payload: &[u8]) -> u16 {
+    let mut data = Vec::with_capacity(5 + payload.len());
+    data.push(kind);
+    data.extend_from_slice(&id.to_le_bytes());
+    data.extend_from_slice(payload);
+    crc16(&data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::flash::MemoryFlash;
+
+    fn payload(id: u32, size: usize) -> Vec<u8> {
+        (0..size).map(|i| (i as u32 + id) as u8).collect()
+    }
+
+    fn read_all(log: &mut RingLog<MemoryFlash>, entry: &Entry) -> Vec<u8> {
+        let mut buffer = vec![0u8; entry.size];
+        log.read(entry, 0, &mut buffer).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn appends_and_reads_back() {
+        let mut log = RingLog::mount(MemoryFlash::new(4)).unwrap();
+
+        for id in 0..3 {
+            assert_eq!(log.append(1, &payload(id, 100)), Ok(id));
+        }
+
+        let entries = log.entries(1, 10).unwrap();
+        assert_eq!(entries.iter().map(|e| e.id).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(read_all(&mut log, &entries[1]), payload(2, 100));
+
+        let mut chunk = [0u8; 64];
+        let entry = log.find(0).unwrap();
+        assert_eq!(log.read(&entry, 80, &mut chunk), Ok(20));
+        assert_eq!(chunk[..20], payload(0, 100)[80..]);
+    }
+
+    #[test]
+    fn survives_a_remount() {
+        let mut log = RingLog::mount(MemoryFlash::new(4)).unwrap();
+        for id in 0..50 {
+            log.append(1, &payload(id, 300)).unwrap();
+        }
+
+        let mut log = RingLog::mount(log.flash).unwrap();
+
+        assert_eq!(log.append(1, &payload(50, 300)), Ok(50));
+        let entry = log.find(50).unwrap();
+        assert_eq!(read_all(&mut log, &entry), payload(50, 300));
+    }
+
+    #[test]
+    fn wraps_around_dropping_the_oldest() {
+        let mut log = RingLog::mount(MemoryFlash::new(3)).unwrap();
+        // 13 records of 300 bytes fit a sector
+        for id in 0..100 {
+            log.append(1, &payload(id, 300)).unwrap();
+        }
+
+        let entries = log.entries(0, 100).unwrap();
+        let ids: Vec<u32> = entries.iter().map(|e| e.id).collect();
+        assert_eq!(*ids.last().unwrap(), 99);
+        assert!(ids.windows(2).all(|w| w[1] == w[0] + 1));
+        assert!(ids.len() >= 26 && ids[0] > 0, "{:?}", ids);
+        assert_eq!(log.find(0), Err(LogError::NotFound));
+        for entry in &entries {
+            assert_eq!(read_all(&mut log, entry), payload(entry.id, 300));
+        }
+    }
+
+    #[test]
+    fn skips_torn_records() {
+        let mut log = RingLog::mount(MemoryFlash::new(2)).unwrap();
+        log.append(1, &payload(0, 10)).unwrap();
+        log.append(1, &payload(1, 10)).unwrap();
+        // a bit of the first payload never made it
+        let entry = log.find(0).unwrap();
+        log.flash.write(entry.offset + 1, &[0x00]).unwrap();
+
+        let mut log = RingLog::mount(log.flash).unwrap();
+
+        let ids: Vec<u32> = log.entries(0, 10).unwrap().iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![1]);
+        assert_eq!(log.append(1, &payload(2, 10)), Ok(2));
+    }
+
+    #[test]
+    fn erase_keeps_counting() {
+        let mut log = RingLog::mount(MemoryFlash::new(2)).unwrap();
+        log.append(1, &payload(0, 10)).unwrap();
+
+        log.erase().unwrap();
+
+        assert_eq!(log.entries(0, 10), Ok(Vec::new()));
+        assert_eq!(log.append(1, &payload(1, 10)), Ok(1));
+        assert_eq!(
+            log.append(1, &[0; MAX_RECORD_SIZE + 1]),
+            Err(LogError::TooBig)
+        );
+    }
+
+    #[test]
+    fn erase_keeps_counting_after_a_remount() {
+        let mut log = RingLog::mount(MemoryFlash::new(2)).unwrap();
+        log.append(1, &payload(0, 10)).unwrap();
+        log.append(1, &payload(1, 10)).unwrap();
+        log.erase().unwrap();
+
+        let mut log = RingLog::mount(log.flash).unwrap();
+
+        assert_eq!(log.entries(0, 10), Ok(Vec::new()));
+        assert_eq!(log.append(1, &payload(2, 10)), Ok(2));
+    }
+}
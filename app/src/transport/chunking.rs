@@ -0,0 +1,145 @@
+use super::framing::{FrameDecoder, FrameError};
+
+/*
+    chunking of the outbound byte stream into notifications
+
+    a notification carries at most the negotiated att mtu minus the 3 byte att header, anything
+    longer is silently cut off by the stack. the framed output is split into chunks that fit, each
+    starting with a one byte sequence number that wraps at 256
+
+        [sequence] [up to mtu - 4 bytes of the framed stream]
+
+    the phone appends the chunks in order and runs the frame decoder over them. a gap in the
+    sequence numbers means a notification was lost, the partial frame is thrown away and the frame
+    decoder picks up again at the next frame.
+*/
+
+pub const ATT_HEADER_SIZE: usize = 3;
+pub const CHUNK_HEADER_SIZE: usize = 1;
+/// the att mtu every ble connection starts with before the mtu exchange
+pub const DEFAULT_ATT_MTU: usize = 23;
+
+/// bytes of the stream that fit in one chunk for an mtu
+pub fn chunk_payload_size(mtu: usize) -> usize {
+    mtu.max(DEFAULT_ATT_MTU) - ATT_HEADER_SIZE - CHUNK_HEADER_SIZE
+}
+
+#[derive(Debug, Default)]
+pub struct Chunker {
+    sequence: u8,
+}
+
+impl Chunker {
+    pub fn new() -> Self {
+        Chunker::default()
+    }
+
+    /// splits bytes into chunks that each fit in one notification
+    pub fn chunk(&mut self, bytes: &[u8], mtu: usize) -> Vec<Vec<u8>> {
+        bytes
+            .chunks(chunk_payload_size(mtu))
+            .map(|payload| {
+                let mut chunk = Vec::with_capacity(CHUNK_HEADER_SIZE + payload.len());
+                chunk.push(self.sequence);
+                chunk.extend_from_slice(payload);
+                self.sequence = self.sequence.wrapping_add(1);
+                chunk
+            })
+            .collect()
+    }
+}
+
+/// Reassembler is the receiving end of a Chunker, it turns chunks back into frame payloads
+#[derive(Default)]
+pub struct Reassembler {
+    next_sequence: Option<u8>,
+    lost_chunks: usize,
+    decoder: FrameDecoder,
+}
+
+impl Reassembler {
+    pub fn new() -> Self {
+        Reassembler::default()
+    }
+
+    /// number of chunks that never arrived
+    pub fn lost_chunks(&self) -> usize {
+        self.lost_chunks
+    }
+
+    /// pushes the next notification in, returns every frame it completed
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<Result<Vec<u8>, FrameError>> {
+        let Some((sequence, payload)) = chunk.split_first() else {
+            return Vec::new();
+        };
+
+        if let Some(expected) = self.next_sequence {
+            if *sequence != expected {
+                self.lost_chunks += sequence.wrapping_sub(expected) as usize;
+                // whatever frame was in progress is missing bytes
+                self.decoder.reset();
+            }
+        }
+        self.next_sequence = Some(sequence.wrapping_add(1));
+
+        self.decoder.push(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::framing;
+
+    #[test]
+    fn chunks_fit_the_mtu() {
+        let bytes: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+
+        let chunks = Chunker::new().chunk(&bytes, 256);
+
+        assert_eq!(chunks.len(), 4);
+        assert!(chunks.iter().all(|c| c.len() <= 256 - ATT_HEADER_SIZE));
+        assert_eq!(chunks[0][0], 0);
+        assert_eq!(chunks[3][0], 3);
+    }
+
+    #[test]
+    fn sequence_wraps() {
+        let mut chunker = Chunker::new();
+        let chunks = chunker.chunk(&[1; 300 * 19], DEFAULT_ATT_MTU);
+
+        assert_eq!(chunks[255][0], 255);
+        assert_eq!(chunks[256][0], 0);
+    }
+
+    #[test]
+    fn reassembles_large_frames() {
+        let report: Vec<u8> = (0..2000).map(|i| (i * 7) as u8).collect();
+        let frame = framing::encode(&report);
+        let mut reassembler = Reassembler::new();
+
+        let frames: Vec<_> = Chunker::new()
+            .chunk(&frame, 185)
+            .iter()
+            .flat_map(|chunk| reassembler.push(chunk))
+            .collect();
+
+        assert_eq!(frames, vec![Ok(report)]);
+        assert_eq!(reassembler.lost_chunks(), 0);
+    }
+
+    #[test]
+    fn drops_frames_with_lost_chunks() {
+        let mut bytes = framing::encode(&[9; 100]);
+        framing::encode_into(&[5; 10], &mut bytes);
+        let mut chunks = Chunker::new().chunk(&bytes, DEFAULT_ATT_MTU);
+        chunks.remove(2);
+        let mut reassembler = Reassembler::new();
+
+        let frames: Vec<_> = chunks.iter().flat_map(|c| reassembler.push(c)).collect();
+
+        assert_eq!(reassembler.lost_chunks(), 1);
+        assert_eq!(frames.last(), Some(&Ok(vec![5; 10])));
+        assert!(!frames.contains(&Ok(vec![9; 100])));
+    }
+}
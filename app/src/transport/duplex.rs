@@ -0,0 +1,288 @@
+use std::{
+    collections::VecDeque,
+    io::{self, Read, Write},
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use crate::domain::domain::ReadWrite;
+
+/*
+    duplex byte streams between the app and whatever carries its bytes
+
+    the app reads the phone's framed messages from a ByteStream and writes its own to it, without
+    knowing whether the other end is the ble task, a uart or a test. pipe makes a bounded in memory
+    pair of streams for two threads, e.g. the app and the ble task
+
+        app --(PipeEnd)-- bytes to the phone --(PipeEnd)-- ble
+            <------------ bytes from the phone <-----------
+
+    reads block until there is something to read and wake as soon as the other end writes, so a
+    message goes through in milliseconds instead of waiting for a polling loop. each direction
+    holds at most capacity bytes, a writer waits for the reader to catch up (up to its write
+    timeout) rather than the buffer growing without bound. once one end is dropped the other reads
+    what is left and then gets end of file, its writes fail with BrokenPipe.
+*/
+
+/// bytes buffered in each direction by default
+pub const DEFAULT_CAPACITY: usize = 4096;
+
+/// ByteStream is a duplex stream of bytes, reads block until there is something to read
+pub trait ByteStream: ReadWrite + Send {
+    /// waits up to timeout for something to read, None when nothing arrived in time. Some(0)
+    /// means the other end has gone, like a read returning 0
+    fn read_timeout(&mut self, buffer: &mut [u8], timeout: Duration) -> io::Result<Option<usize>>;
+}
+
+impl<T: ByteStream + ?Sized> ByteStream for Box<T> {
+    fn read_timeout(&mut self, buffer: &mut [u8], timeout: Duration) -> io::Result<Option<usize>> {
+        (**self).read_timeout(buffer, timeout)
+    }
+}
+
+#[derive(Debug)]
+struct Buffer {
+    bytes: VecDeque<u8>,
+    capacity: usize,
+    closed: bool,
+}
+
+// one direction of a pipe
+#[derive(Debug)]
+struct Queue {
+    buffer: Mutex<Buffer>,
+    readable: Condvar,
+    writable: Condvar,
+}
+
+impl Queue {
+    fn new(capacity: usize) -> Self {
+        Queue {
+            buffer: Mutex::new(Buffer {
+                bytes: VecDeque::with_capacity(capacity),
+                capacity,
+                closed: false,
+            }),
+            readable: Condvar::new(),
+            writable: Condvar::new(),
+        }
+    }
+
+    fn close(&self) {
+        self.buffer.lock().unwrap().closed = true;
+        self.readable.notify_all();
+        self.writable.notify_all();
+    }
+
+    // waits on condvar until ready or the deadline passes, None waits for ever
+    fn wait<'a, F>(
+        &self,
+        condvar: &Condvar,
+        mut buffer: MutexGuard<'a, Buffer>,
+        deadline: Option<Instant>,
+        ready: F,
+    ) -> Option<MutexGuard<'a, Buffer>>
+    where
+        F: Fn(&Buffer) -> bool,
+    {
+        while !ready(&buffer) {
+            buffer = match deadline {
+                None => condvar.wait(buffer).unwrap(),
+                Some(deadline) => {
+                    let timeout = deadline.checked_duration_since(Instant::now())?;
+                    condvar.wait_timeout(buffer, timeout).unwrap().0
+                }
+            };
+        }
+        Some(buffer)
+    }
+
+    fn read(&self, out: &mut [u8], deadline: Option<Instant>) -> Option<usize> {
+        let buffer = self.buffer.lock().unwrap();
+        let mut buffer = self.wait(&self.readable, buffer, deadline, |b| {
+            !b.bytes.is_empty() || b.closed || out.is_empty()
+        })?;
+        let len = out.len().min(buffer.bytes.len());
+        for (out, byte) in out.iter_mut().zip(buffer.bytes.drain(..len)) {
+            *out = byte;
+        }
+        self.writable.notify_all();
+        Some(len)
+    }
+
+    fn write(&self, bytes: &[u8], deadline: Option<Instant>) -> io::Result<usize> {
+        let buffer = self.buffer.lock().unwrap();
+        let mut buffer = self
+            .wait(&self.writable, buffer, deadline, |b| {
+                b.bytes.len() < b.capacity || b.closed || bytes.is_empty()
+            })
+            .ok_or(io::ErrorKind::WouldBlock)?;
+        if buffer.closed {
+            return Err(io::ErrorKind::BrokenPipe.into());
+        }
+        let len = bytes.len().min(buffer.capacity - buffer.bytes.len());
+        buffer.bytes.extend(&bytes[..len]);
+        self.readable.notify_all();
+        Ok(len)
+    }
+}
+
+// closes both directions once the last clone of an end is dropped
+#[derive(Debug)]
+struct Closer {
+    incoming: Arc<Queue>,
+    outgoing: Arc<Queue>,
+}
+
+impl Drop for Closer {
+    fn drop(&mut self) {
+        self.incoming.close();
+        self.outgoing.close();
+    }
+}
+
+/// PipeEnd is one end of a pipe, clones share the end so one thread can read while another writes
+#[derive(Debug, Clone)]
+pub struct PipeEnd {
+    incoming: Arc<Queue>,
+    outgoing: Arc<Queue>,
+    write_timeout: Option<Duration>,
+    _closer: Arc<Closer>,
+}
+
+/// a pair of connected ends, capacity bytes are buffered in each direction
+pub fn pipe(capacity: usize) -> (PipeEnd, PipeEnd) {
+    let a = Arc::new(Queue::new(capacity));
+    let b = Arc::new(Queue::new(capacity));
+    let end = |incoming: &Arc<Queue>, outgoing: &Arc<Queue>| PipeEnd {
+        incoming: Arc::clone(incoming),
+        outgoing: Arc::clone(outgoing),
+        write_timeout: None,
+        _closer: Arc::new(Closer {
+            incoming: Arc::clone(incoming),
+            outgoing: Arc::clone(outgoing),
+        }),
+    };
+    (end(&a, &b), end(&b, &a))
+}
+
+impl PipeEnd {
+    /// how long a write waits for room before failing with WouldBlock, None waits for ever
+    pub fn set_write_timeout(&mut self, timeout: Option<Duration>) {
+        self.write_timeout = timeout;
+    }
+
+    /// writes what fits without waiting, for callbacks that must not block
+    pub fn try_write(&self, bytes: &[u8]) -> io::Result<usize> {
+        self.outgoing.write(bytes, Some(Instant::now()))
+    }
+}
+
+impl Read for PipeEnd {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        Ok(self.incoming.read(buffer, None).unwrap_or(0))
+    }
+}
+
+impl Write for PipeEnd {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        let deadline = self.write_timeout.map(|timeout| Instant::now() + timeout);
+        self.outgoing.write(bytes, deadline)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl ByteStream for PipeEnd {
+    fn read_timeout(&mut self, buffer: &mut [u8], timeout: Duration) -> io::Result<Option<usize>> {
+        Ok(self.incoming.read(buffer, Some(Instant::now() + timeout)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn carries_bytes_both_ways() {
+        let (mut app, mut ble) = pipe(16);
+        let mut buffer = [0u8; 16];
+
+        app.write_all(b"rep").unwrap();
+        ble.write_all(b"config").unwrap();
+
+        assert_eq!(ble.read(&mut buffer).unwrap(), 3);
+        assert_eq!(&buffer[..3], b"rep");
+        assert_eq!(app.read(&mut buffer).unwrap(), 6);
+        assert_eq!(&buffer[..6], b"config");
+        assert_eq!(
+            app.read_timeout(&mut buffer, Duration::from_millis(1))
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn wakes_a_blocked_reader() {
+        let (mut app, mut ble) = pipe(16);
+
+        let reader = thread::spawn(move || {
+            let mut buffer = [0u8; 4];
+            let len = ble
+                .read_timeout(&mut buffer, Duration::from_secs(5))
+                .unwrap();
+            (len, buffer)
+        });
+        thread::sleep(Duration::from_millis(10));
+        app.write_all(b"ping").unwrap();
+
+        assert_eq!(reader.join().unwrap(), (Some(4), *b"ping"));
+    }
+
+    #[test]
+    fn writers_wait_for_room() {
+        let (mut app, mut ble) = pipe(4);
+        app.set_write_timeout(Some(Duration::from_millis(10)));
+
+        assert_eq!(app.write(b"too long").unwrap(), 4);
+        assert_eq!(
+            app.write(b"more").unwrap_err().kind(),
+            io::ErrorKind::WouldBlock
+        );
+        assert_eq!(ble.try_write(b"abcdef").unwrap(), 4);
+
+        // a reader on another thread makes room
+        app.set_write_timeout(None);
+        let reader = thread::spawn(move || {
+            let mut bytes = Vec::new();
+            ble.read_to_end(&mut bytes).unwrap();
+            bytes
+        });
+        app.write_all(b" long message").unwrap();
+        drop(app);
+
+        assert_eq!(reader.join().unwrap(), b"too  long message");
+    }
+
+    #[test]
+    fn dropping_an_end_closes_the_pipe() {
+        let (app, mut ble) = pipe(16);
+        let mut clone = app.clone();
+        clone.write_all(b"last").unwrap();
+        drop(app);
+        clone.write_all(b"!").unwrap();
+        drop(clone);
+
+        let mut bytes = Vec::new();
+        ble.read_to_end(&mut bytes).unwrap();
+
+        assert_eq!(bytes, b"last!");
+        assert_eq!(
+            ble.write(b"x").unwrap_err().kind(),
+            io::ErrorKind::BrokenPipe
+        );
+    }
+}
@@ -0,0 +1,265 @@
+/*
+    framing for the byte streams between the phone and the sensor
+
+    a ble write is not a message, a message can be split over several writes and several messages
+    can arrive in one. every message is therefore sent as a frame
+
+        cobs(payload ++ crc16(payload)) ++ 0x00
+
+    cobs (consistent overhead byte stuffing) removes every zero byte from the frame so 0x00 only
+    ever marks the end of one. when the decoder sees a corrupt frame it throws away everything up to
+    the next 0x00 and carries on from there, the crc catches corruption that still decodes.
+*/
+
+pub const FRAME_DELIMITER: u8 = 0x00;
+
+/// largest payload accepted by default, anything longer is dropped
+pub const MAX_PAYLOAD_SIZE: usize = 4096;
+
+const CRC_SIZE: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameError {
+    /// the frame is not valid cobs
+    InvalidEncoding,
+    /// the frame is too short to hold a crc
+    TooShort,
+    /// the crc does not match the payload
+    CrcMismatch,
+    /// the frame went over the maximum size before its delimiter arrived
+    Oversized,
+}
+
+/// crc-16/ccitt-false of the data
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = match crc & 0x8000 {
+                0 => crc << 1,
+                _ => (crc << 1) ^ 0x1021,
+            };
+        }
+    }
+    crc
+}
+
+/// encodes a payload into a frame, including the trailing delimiter
+pub fn encode(payload: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(payload.len() + payload.len() / 254 + CRC_SIZE + 2);
+    encode_into(payload, &mut frame);
+    frame
+}
+
+/// appends the frame for a payload to out, including the trailing delimiter
+pub fn encode_into(payload: &[u8], out: &mut Vec<u8>) {
+    let crc = crc16(payload).to_be_bytes();
+
+    let mut code_index = out.len();
+    let mut code: u8 = 1;
+    out.push(0);
+
+    for byte in payload.iter().chain(crc.iter()) {
+        if *byte == 0 {
+            out[code_index] = code;
+            code_index = out.len();
+            code = 1;
+            out.push(0);
+            continue;
+        }
+
+        out.push(*byte);
+        code += 1;
+        if code == 0xFF {
+            out[code_index] = code;
+            code_index = out.len();
+            code = 1;
+            out.push(0);
+        }
+    }
+    out[code_index] = code;
+    out.push(FRAME_DELIMITER);
+}
+
+/// decodes a frame without its delimiter back into the payload
+pub fn decode(frame: &[u8]) -> Result<Vec<u8>, FrameError> {
+    let mut decoded = Vec::with_capacity(frame.len());
+    let mut i = 0;
+
+    while i < frame.len() {
+        let code = frame[i];
+        if code == 0 {
+            return Err(FrameError::InvalidEncoding);
+        }
+        i += 1;
+
+        let end = i + code as usize - 1;
+        if end > frame.len() {
+            return Err(FrameError::InvalidEncoding);
+        }
+        if frame[i..end].contains(&0) {
+            return Err(FrameError::InvalidEncoding);
+        }
+        decoded.extend_from_slice(&frame[i..end]);
+        i = end;
+
+        if code < 0xFF && i < frame.len() {
+            decoded.push(0);
+        }
+    }
+
+    if decoded.len() < CRC_SIZE {
+        return Err(FrameError::TooShort);
+    }
+    let crc_start = decoded.len() - CRC_SIZE;
+    let crc = u16::from_be_bytes([decoded[crc_start], decoded[crc_start + 1]]);
+    decoded.truncate(crc_start);
+
+    if crc16(&decoded) != crc {
+        return Err(FrameError::CrcMismatch);
+    }
+    Ok(decoded)
+}
+
+/// FrameDecoder pulls frames out of a byte stream that arrives in arbitrary pieces
+pub struct FrameDecoder {
+    buffer: Vec<u8>,
+    max_frame_size: usize,
+    // set when a frame went over the max size, bytes are dropped until the next delimiter
+    discarding: bool,
+}
+
+impl Default for FrameDecoder {
+    fn default() -> Self {
+        FrameDecoder::new()
+    }
+}
+
+impl FrameDecoder {
+    pub fn new() -> Self {
+        FrameDecoder::with_max_payload_size(MAX_PAYLOAD_SIZE)
+    }
+
+    pub fn with_max_payload_size(max_payload_size: usize) -> Self {
+        // worst case cobs overhead is one byte in every 254
+        let max_frame_size = max_payload_size + CRC_SIZE + (max_payload_size + CRC_SIZE) / 254 + 1;
+        FrameDecoder {
+            buffer: Vec::new(),
+            max_frame_size,
+            discarding: false,
+        }
+    }
+
+    /// throws away the frame in progress, e.g. because some of its bytes were lost
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.discarding = false;
+    }
+
+    /// pushes one byte in, returns the result of a frame when its delimiter arrives
+    pub fn push_byte(&mut self, byte: u8) -> Option<Result<Vec<u8>, FrameError>> {
+        if byte == FRAME_DELIMITER {
+            if self.discarding {
+                self.discarding = false;
+                self.buffer.clear();
+                return Some(Err(FrameError::Oversized));
+            }
+            if self.buffer.is_empty() {
+                // back to back delimiters, nothing to decode
+                return None;
+            }
+            let result = decode(&self.buffer);
+            self.buffer.clear();
+            return Some(result);
+        }
+
+        if self.discarding {
+            return None;
+        }
+        if self.buffer.len() >= self.max_frame_size {
+            self.discarding = true;
+            self.buffer.clear();
+            return None;
+        }
+        self.buffer.push(byte);
+        None
+    }
+
+    /// pushes bytes in, returns the results of every frame completed by them in order
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<Result<Vec<u8>, FrameError>> {
+        bytes.iter().filter_map(|b| self.push_byte(*b)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc_check_value() {
+        assert_eq!(crc16(b"123456789"), 0x29B1);
+    }
+
+    #[test]
+    fn round_trip() {
+        let long: Vec<u8> = (0..600).map(|i| (i % 256) as u8).collect();
+        let no_zeros: Vec<u8> = (0..600).map(|i| (i % 255 + 1) as u8).collect();
+
+        for payload in [&[][..], &[0], &[0, 0, 1], &[1, 2, 3], &long, &no_zeros] {
+            let frame = encode(payload);
+            assert_eq!(frame.iter().filter(|b| **b == 0).count(), 1);
+            assert_eq!(decode(&frame[..frame.len() - 1]).unwrap(), payload);
+        }
+    }
+
+    #[test]
+    fn reassembles_fragments() {
+        let frame = encode(b"a message split over three writes");
+        let mut decoder = FrameDecoder::new();
+
+        assert!(decoder.push(&frame[..5]).is_empty());
+        assert!(decoder.push(&frame[5..20]).is_empty());
+        assert_eq!(
+            decoder.push(&frame[20..]),
+            vec![Ok(b"a message split over three writes".to_vec())]
+        );
+    }
+
+    #[test]
+    fn splits_joined_frames() {
+        let mut bytes = encode(b"one");
+        encode_into(b"two", &mut bytes);
+
+        let frames = FrameDecoder::new().push(&bytes);
+
+        assert_eq!(frames, vec![Ok(b"one".to_vec()), Ok(b"two".to_vec())]);
+    }
+
+    #[test]
+    fn resynchronises_after_corruption() {
+        let mut bytes = encode(b"first");
+        bytes[2] ^= 0x40;
+        // half a frame lost in the middle of the stream
+        bytes.extend_from_slice(&encode(b"lost")[3..]);
+        encode_into(b"second", &mut bytes);
+
+        let frames = FrameDecoder::new().push(&bytes);
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0], Err(FrameError::CrcMismatch));
+        assert!(frames[1].is_err());
+        assert_eq!(frames[2], Ok(b"second".to_vec()));
+    }
+
+    #[test]
+    fn drops_oversized_frames() {
+        let mut decoder = FrameDecoder::with_max_payload_size(8);
+        let mut bytes = encode(&[7; 32]);
+        encode_into(&[7; 8], &mut bytes);
+
+        let frames = decoder.push(&bytes);
+
+        assert_eq!(frames, vec![Err(FrameError::Oversized), Ok(vec![7; 8])]);
+    }
+}
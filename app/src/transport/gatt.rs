@@ -0,0 +1,97 @@
+/*
+    the gatt contract of the sensor
+
+    the firmware exposes two services. the ota service takes a firmware image, the messaging service
+    carries the framed protobuf messages. the uuids and opcodes live here so the firmware, the host
+    tools and the simulator agree on them, the esp32 side still spells the uuids out in uuid128!.
+
+    ota
+        control  write an OtaRequest to start, the sensor notifies OTA_CONTROL_REQUEST_ACK with the
+                 offset to carry on from or _NAK. while the image is written it notifies
+                 OTA_CONTROL_PROGRESS with the next offset it expects. write [OTA_CONTROL_DONE] once
+                 the whole image is written, the sensor notifies OTA_CONTROL_DONE_ACK or _NAK. see
+                 ota::update for the request, the progress and the naks
+        data     write the image in numbered chunks of packet size bytes, see ota::update::chunk
+        apply    write anything to boot the new image, the sensor notifies one of the UPDATE_
+                 codes and restarts on success
+
+    messaging
+        byte in  write framed messages, see framing
+        byte out notifies chunks of framed messages, see chunking
+*/
+
+pub const OTA_SERVICE_UUID: &str = "d6f1d96d-594c-4c53-b1c6-244a1dfde6d8";
+pub const MESSAGING_SERVICE_UUID: &str = "6a7e2945-d40e-4891-bdc4-b22494ee0539";
+
+pub const OTA_CONTROL_NOP: u8 = 0x00;
+pub const OTA_CONTROL_REQUEST: u8 = 0x01;
+pub const OTA_CONTROL_REQUEST_ACK: u8 = 0x02;
+pub const OTA_CONTROL_REQUEST_NAK: u8 = 0x03;
+pub const OTA_CONTROL_DONE: u8 = 0x04;
+pub const OTA_CONTROL_DONE_ACK: u8 = 0x05;
+pub const OTA_CONTROL_DONE_NAK: u8 = 0x06;
+pub const OTA_CONTROL_PROGRESS: u8 = 0x07;
+
+pub const UPDATE_SUCCESS: u8 = 0;
+pub const UPDATE_FAIL: u8 = 1;
+pub const NONE_ATTEMPTED: u8 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Characteristic {
+    OtaControl,
+    OtaData,
+    OtaApply,
+    ByteIn,
+    ByteOut,
+}
+
+impl Characteristic {
+    pub const ALL: [Characteristic; 5] = [
+        Characteristic::OtaControl,
+        Characteristic::OtaData,
+        Characteristic::OtaApply,
+        Characteristic::ByteIn,
+        Characteristic::ByteOut,
+    ];
+
+    pub fn uuid(&self) -> &'static str {
+        match self {
+            Characteristic::OtaControl => "7ad671aa-21c0-46a4-b722-270e3ae3d830",
+            Characteristic::OtaData => "23408888-1F40-4CD8-9B89-CA8D45F8A5B0",
+            Characteristic::OtaApply => "3e33db7b-9108-4549-b063-979f55610f0f",
+            Characteristic::ByteIn => "23464575-3164-4dcb-b200-602ebd7cd3f0",
+            Characteristic::ByteOut => "a241328d-fd06-4475-a31a-26328d92eba2",
+        }
+    }
+
+    /// id of the characteristic on a link, see link
+    pub fn id(&self) -> u8 {
+        match self {
+            Characteristic::OtaControl => 1,
+            Characteristic::OtaData => 2,
+            Characteristic::OtaApply => 3,
+            Characteristic::ByteIn => 4,
+            Characteristic::ByteOut => 5,
+        }
+    }
+
+    pub fn from_id(id: u8) -> Option<Self> {
+        Characteristic::ALL.into_iter().find(|c| c.id() == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_round_trip() {
+        for characteristic in Characteristic::ALL {
+            assert_eq!(
+                Characteristic::from_id(characteristic.id()),
+                Some(characteristic)
+            );
+        }
+        assert_eq!(Characteristic::from_id(0), None);
+    }
+}
@@ -0,0 +1,124 @@
+use super::gatt::Characteristic;
+
+/*
+    link format for talking to a sensor over a plain byte stream
+
+    without ble there are no characteristics to write to or get notified on, so the host tools and
+    the simulator send every write and every notification over a socket or pipe as a packet
+
+        [characteristic id] [length u16 be] [value]
+
+    the values are exactly what would be written to or notified on the characteristic, so the
+    ota opcodes, framing and chunking work the same as over ble. a packet with an unknown
+    characteristic is skipped, the length still says where the next one starts.
+*/
+
+pub const HEADER_SIZE: usize = 3;
+/// largest value in one packet
+pub const MAX_VALUE_SIZE: usize = u16::MAX as usize;
+/// port the simulator listens on by default
+pub const DEFAULT_PORT: u16 = 7878;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub characteristic: Characteristic,
+    pub value: Vec<u8>,
+}
+
+impl Packet {
+    pub fn new(characteristic: Characteristic, value: &[u8]) -> Self {
+        Packet {
+            characteristic,
+            value: value.to_vec(),
+        }
+    }
+
+    /// the packet on the wire, values over MAX_VALUE_SIZE are cut off
+    pub fn encode(&self) -> Vec<u8> {
+        let value = &self.value[..self.value.len().min(MAX_VALUE_SIZE)];
+        let mut bytes = Vec::with_capacity(HEADER_SIZE + value.len());
+        bytes.push(self.characteristic.id());
+        bytes.extend_from_slice(&(value.len() as u16).to_be_bytes());
+        bytes.extend_from_slice(value);
+        bytes
+    }
+}
+
+/// LinkDecoder pulls packets out of a byte stream that arrives in arbitrary pieces
+#[derive(Debug, Default)]
+pub struct LinkDecoder {
+    buffer: Vec<u8>,
+    unknown: usize,
+}
+
+impl LinkDecoder {
+    pub fn new() -> Self {
+        LinkDecoder::default()
+    }
+
+    /// number of packets skipped because their characteristic is unknown
+    pub fn unknown_packets(&self) -> usize {
+        self.unknown
+    }
+
+    /// pushes bytes in, returns every packet completed by them in order
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<Packet> {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut packets = Vec::new();
+        let mut start = 0;
+        while let [id, high, low, rest @ ..] = &self.buffer[start..] {
+            let len = u16::from_be_bytes([*high, *low]) as usize;
+            if rest.len() < len {
+                break;
+            }
+            match Characteristic::from_id(*id) {
+                Some(characteristic) => packets.push(Packet::new(characteristic, &rest[..len])),
+                None => self.unknown += 1,
+            }
+            start += HEADER_SIZE + len;
+        }
+        self.buffer.drain(..start);
+        packets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reassembles_split_packets() {
+        let mut bytes = Packet::new(Characteristic::ByteIn, b"hello").encode();
+        bytes.extend(Packet::new(Characteristic::OtaData, &[0; 300]).encode());
+        bytes.extend(Packet::new(Characteristic::OtaApply, &[]).encode());
+        let mut decoder = LinkDecoder::new();
+
+        let first = decoder.push(&bytes[..2]);
+        let second = decoder.push(&bytes[2..20]);
+        let rest = decoder.push(&bytes[20..]);
+
+        assert!(first.is_empty());
+        assert_eq!(second, vec![Packet::new(Characteristic::ByteIn, b"hello")]);
+        assert_eq!(
+            rest,
+            vec![
+                Packet::new(Characteristic::OtaData, &[0; 300]),
+                Packet::new(Characteristic::OtaApply, &[]),
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_unknown_characteristics() {
+        let mut bytes = vec![42, 0, 2, 1, 2];
+        bytes.extend(Packet::new(Characteristic::ByteOut, &[7]).encode());
+        let mut decoder = LinkDecoder::new();
+
+        assert_eq!(
+            decoder.push(&bytes),
+            vec![Packet::new(Characteristic::ByteOut, &[7])]
+        );
+        assert_eq!(decoder.unknown_packets(), 1);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod chunking;
+pub mod duplex;
+pub mod framing;
+pub mod gatt;
+pub mod link;
@@ -0,0 +1,110 @@
+// runs the whole sample -> algorithim -> rep pipeline on the host, the same way the firmware does
+
+use std::time::Duration;
+
+use app::app::app::App;
+use app::domain::imu::{ImuSource, ReplaySource, SyntheticSource};
+use app::rust_proto::algorithim::{self, ErrorCode};
+use app::transport::{
+    duplex::{self, ByteStream, PipeEnd},
+    framing::{self, FrameDecoder},
+};
+use protobuf::Message;
+
+const REBOUND_JUMPS: &str = include_str!("../../app_algorithims/tests/fixtures/rebound_jumps.csv");
+
+struct Sensor {
+    app: App,
+    // the phone's end of the app's link
+    phone: PipeEnd,
+    sent: Vec<u8>,
+}
+
+impl Sensor {
+    fn new() -> Self {
+        let (link, phone) = duplex::pipe(1 << 16);
+        Sensor {
+            app: App::new(Box::new(link)),
+            phone,
+            sent: Vec::new(),
+        }
+    }
+
+    // configures a spike start and a counted end the way the phone does
+    fn configure(&mut self, counts: u32) {
+        let mut conf = algorithim::AlgorithimConfiguration::new();
+        conf.set_spikeStart(algorithim::SpikeStart::new());
+        let mut counted = algorithim::Counted::new();
+        counted.counts = counts;
+        conf.set_counted(counted);
+
+        let mut message = algorithim::Message::new();
+        message.requestId = 1;
+        message.set_Algorithim(conf);
+        self.app
+            .handle_bytes(&framing::encode(&message.write_to_bytes().unwrap()));
+    }
+
+    fn run<S: ImuSource>(&mut self, mut source: S) {
+        self.app.push_source(&mut source).unwrap();
+    }
+
+    fn sent(&mut self) -> Vec<algorithim::Message> {
+        let mut buffer = [0u8; 1024];
+        while let Ok(Some(len @ 1..)) = self.phone.read_timeout(&mut buffer, Duration::ZERO) {
+            self.sent.extend_from_slice(&buffer[..len]);
+        }
+        FrameDecoder::new()
+            .push(&self.sent)
+            .into_iter()
+            .map(|frame| algorithim::Message::parse_from_bytes(&frame.unwrap()).unwrap())
+            .collect()
+    }
+
+    fn reps(&mut self) -> Vec<algorithim::BouningRep> {
+        self.sent()
+            .iter()
+            .filter(|message| message.has_rep())
+            .map(|message| message.rep().bouningRep().clone())
+            .collect()
+    }
+}
+
+#[test]
+fn synthetic_session() {
+    let mut sensor = Sensor::new();
+    sensor.configure(4);
+
+    sensor.run(
+        SyntheticSource::new(10_000)
+            .stand(1_000_000)
+            .rebound_jumps(4, 500_000, 200_000)
+            .stand(1_000_000),
+    );
+
+    let sent = sensor.sent();
+    assert_eq!(sent[0].response().requestId, 1);
+    assert_eq!(sent[0].response().error.enum_value(), Ok(ErrorCode::NONE));
+
+    let reps = sensor.reps();
+    assert_eq!(reps.len(), 1);
+    let rep = &reps[0];
+    assert_eq!(rep.measurements.len(), 4);
+    // 500 ms in the air is 306 mm
+    for measurement in &rep.measurements {
+        assert!((measurement.height as i32 - 306).abs() <= 15);
+    }
+    assert!((rep.averageGroundContactTime as i32 - 200).abs() <= 10);
+}
+
+#[test]
+fn recorded_session() {
+    let mut sensor = Sensor::new();
+    sensor.configure(6);
+
+    sensor.run(ReplaySource::from_csv(REBOUND_JUMPS).unwrap());
+
+    let reps = sensor.reps();
+    assert_eq!(reps.len(), 1);
+    assert_eq!(reps[0].measurements.len(), 6);
+}
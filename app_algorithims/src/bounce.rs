@@ -0,0 +1,386 @@
+use crate::contact::crossing_time;
+use crate::height::{self, HeightModel, VelocityIntegrator};
+use crate::sample::{AccelSample, Vector3, ONE_G};
+
+/*
+    bounce detection
+
+    while the athlete is in the air the accelerometer is in free fall and reads close to 0 g, while
+    they are on the ground it reads 1 g or more. the engine watches the acceleration magnitude (so it
+    does not matter how the sensor is strapped on) and uses two thresholds with hysteresis to find the
+    takeoff and landing of every jump. the edge times are interpolated between the samples either
+    side of the threshold so contact and flight times are not quantised to the sample period.
+
+    a bounce is reported once the athlete lands, it contains the ground contact before the takeoff
+    and the flight that followed it.
+
+    while on the ground the vertical velocity is integrated as well so the height can come from the
+    takeoff velocity instead of the flight time, see the height module. given which way is up, see
+    the orientation module, the acceleration along it is integrated instead of the magnitude.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BounceConfig {
+    /// acceleration magnitude in milli g below which the athlete is considered airborne
+    pub takeoff_threshold: f32,
+    /// acceleration magnitude in milli g above which the athlete is considered on the ground
+    pub landing_threshold: f32,
+    /// flights shorter than this (micro seconds) are noise and are merged back into the contact
+    pub min_flight_time: i64,
+    /// flights longer than this (micro seconds) are not jumps, e.g. the sensor was dropped
+    pub max_flight_time: i64,
+    /// contacts longer than this (micro seconds) are the athlete standing, not a bounce
+    pub max_contact_time: i64,
+    /// how the reported bounce height is worked out
+    pub height_model: HeightModel,
+    /// the athlete is standing still while the magnitude stays this close (milli g) to 1 g ...
+    pub still_band: f32,
+    /// ... for this long (micro seconds), which resets the integrated velocity to zero
+    pub still_time: i64,
+}
+
+impl Default for BounceConfig {
+    fn default() -> Self {
+        BounceConfig {
+            takeoff_threshold: 300.0,
+            landing_threshold: 600.0,
+            min_flight_time: 80_000,
+            max_flight_time: 1_500_000,
+            max_contact_time: 2_000_000,
+            height_model: HeightModel::FlightTime,
+            still_band: 50.0,
+            still_time: 200_000,
+        }
+    }
+}
+
+/// a single detected jump, all times are in micro seconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounce {
+    pub takeoff: i64,
+    pub landing: i64,
+    /// time spent on the ground before the takeoff, none for the first jump out of a standing start
+    pub ground_contact_time: Option<i64>,
+    pub flight_time: i64,
+    /// vertical velocity in m/s the athlete left the ground with
+    pub takeoff_velocity: f32,
+    /// jump height in metres, worked out with the configured height model
+    pub height: f32,
+}
+
+impl Bounce {
+    /// ground contact time in whole ms as the phone gets it, 0 for a standing start
+    pub fn ground_contact_ms(&self) -> u32 {
+        self.ground_contact_time.map_or(0, |us| (us / 1000) as u32)
+    }
+
+    /// height in mm as the phone gets it
+    pub fn height_mm(&self) -> u32 {
+        (self.height * 1000.0).round() as u32
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    Contact,
+    Flight { takeoff: i64, takeoff_velocity: f32 },
+}
+
+pub struct BounceEngine {
+    config: BounceConfig,
+    phase: Phase,
+    // when the current ground contact started, none if we have not seen a landing yet
+    contact_start: Option<i64>,
+    previous: Option<AccelSample>,
+    velocity: VelocityIntegrator,
+    // when the athlete started standing still, none while they are moving
+    still_since: Option<i64>,
+}
+
+impl BounceEngine {
+    pub fn new(config: BounceConfig) -> Self {
+        BounceEngine {
+            config,
+            phase: Phase::Contact,
+            contact_start: None,
+            previous: None,
+            velocity: VelocityIntegrator::new(0.0),
+            still_since: None,
+        }
+    }
+
+    pub fn config(&self) -> &BounceConfig {
+        &self.config
+    }
+
+    /// forget everything seen so far, the next jump is treated as a standing start
+    pub fn reset(&mut self) {
+        self.phase = Phase::Contact;
+        self.contact_start = None;
+        self.previous = None;
+        self.velocity.reset(0.0);
+        self.still_since = None;
+    }
+
+    /// feed the next sample into the engine, returns a bounce when a landing completes one
+    pub fn push(&mut self, sample: AccelSample) -> Option<Bounce> {
+        self.push_oriented(sample, None)
+    }
+
+    /// push with the unit vector pointing up in the sensor's frame, if it is known
+    pub fn push_oriented(&mut self, sample: AccelSample, up: Option<Vector3>) -> Option<Bounce> {
+        // samples that go back in time are dropped, they would corrupt every duration below
+        let previous = match self.previous {
+            Some(previous) if sample.timestamp <= previous.timestamp => return None,
+            previous => previous.unwrap_or(sample),
+        };
+        self.previous = Some(sample);
+
+        let magnitude = sample.magnitude();
+
+        match self.phase {
+            Phase::Contact => {
+                if magnitude < self.config.takeoff_threshold {
+                    self.phase = Phase::Flight {
+                        takeoff: crossing_time(&previous, &sample, self.config.takeoff_threshold),
+                        takeoff_velocity: self.velocity.velocity(),
+                    };
+                    return None;
+                }
+                self.integrate(sample, up);
+                None
+            }
+            Phase::Flight {
+                takeoff,
+                takeoff_velocity,
+            } => {
+                if magnitude <= self.config.landing_threshold {
+                    return None;
+                }
+
+                let landing = crossing_time(&previous, &sample, self.config.landing_threshold);
+                let flight_time = landing - takeoff;
+
+                if flight_time < self.config.min_flight_time {
+                    // a dip in the signal, the athlete never left the ground
+                    self.phase = Phase::Contact;
+                    self.integrate(sample, up);
+                    return None;
+                }
+
+                let contact_start = self.contact_start;
+                self.phase = Phase::Contact;
+                self.contact_start = Some(landing);
+                self.still_since = None;
+                self.velocity.reset(height::landing_velocity(flight_time));
+                self.push_velocity(sample, up);
+
+                if flight_time > self.config.max_flight_time {
+                    return None;
+                }
+
+                let ground_contact_time = contact_start
+                    .map(|start| takeoff - start)
+                    .filter(|contact| *contact <= self.config.max_contact_time);
+
+                let height = match self.config.height_model {
+                    HeightModel::FlightTime => height::flight_time_height(flight_time),
+                    HeightModel::TakeoffVelocity => {
+                        height::takeoff_velocity_height(takeoff_velocity)
+                    }
+                };
+
+                Some(Bounce {
+                    takeoff,
+                    landing,
+                    ground_contact_time,
+                    flight_time,
+                    takeoff_velocity,
+                    height,
+                })
+            }
+        }
+    }
+
+    // integrates a ground contact sample, zeroing the velocity while the athlete stands still
+    fn integrate(&mut self, sample: AccelSample, up: Option<Vector3>) {
+        if (sample.magnitude() - ONE_G).abs() > self.config.still_band {
+            self.still_since = None;
+        } else {
+            let still_since = *self.still_since.get_or_insert(sample.timestamp);
+            if sample.timestamp - still_since >= self.config.still_time {
+                self.velocity.reset(0.0);
+            }
+        }
+        self.push_velocity(sample, up);
+    }
+
+    fn push_velocity(&mut self, sample: AccelSample, up: Option<Vector3>) {
+        match up {
+            Some(up) => self
+                .velocity
+                .push_vertical(sample.timestamp, height::vertical_acceleration(&sample, up)),
+            None => self.velocity.push(sample),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sample::{Vector3, ONE_G};
+
+    const PERIOD: i64 = 10_000; // 100 Hz
+
+    // builds a trace of (milli g, duration in micro seconds) segments sampled at 100 Hz
+    fn trace(segments: &[(f32, i64)]) -> Vec<AccelSample> {
+        let mut samples = Vec::new();
+        let mut timestamp = 0;
+        for (magnitude, duration) in segments {
+            let end = timestamp + duration;
+            while timestamp < end {
+                samples.push(AccelSample::new(
+                    timestamp,
+                    Vector3::new(0.0, 0.0, *magnitude),
+                ));
+                timestamp += PERIOD;
+            }
+        }
+        samples
+    }
+
+    fn run(samples: &[AccelSample]) -> Vec<Bounce> {
+        let mut engine = BounceEngine::new(BounceConfig::default());
+        samples.iter().filter_map(|s| engine.push(*s)).collect()
+    }
+
+    #[test]
+    fn detects_repeated_bounces() {
+        let samples = trace(&[
+            (ONE_G, 1_000_000),
+            (0.0, 400_000),
+            (3.0 * ONE_G, 200_000),
+            (0.0, 500_000),
+            (3.0 * ONE_G, 180_000),
+            (0.0, 450_000),
+            (ONE_G, 500_000),
+        ]);
+
+        let bounces = run(&samples);
+
+        // a step between two samples is placed where the line between them crosses the threshold,
+        // 7 ms into the period for the takeoff threshold and 2 ms into it for the landing one
+        assert_eq!(bounces.len(), 3);
+        assert_eq!(bounces[0].ground_contact_time, None);
+        assert_eq!(bounces[0].flight_time, 395_000);
+        assert_eq!(bounces[1].ground_contact_time, Some(207_000));
+        assert_eq!(bounces[1].flight_time, 493_000);
+        assert_eq!(bounces[2].ground_contact_time, Some(187_000));
+        assert!((bounces[1].height - 0.2979).abs() < 0.001);
+    }
+
+    // samples at 100 Hz of the magnitude going in straight lines between (micro seconds, milli g)
+    // points
+    fn ramps(points: &[(i64, f32)]) -> Vec<AccelSample> {
+        let end = points.last().unwrap().0;
+        (0..end)
+            .step_by(PERIOD as usize)
+            .map(|timestamp| {
+                let i = points.iter().rposition(|p| p.0 <= timestamp).unwrap();
+                let ((t0, m0), (t1, m1)) = (points[i], points[i + 1]);
+                let magnitude = m0 + (m1 - m0) * (timestamp - t0) as f32 / (t1 - t0) as f32;
+                AccelSample::new(timestamp, Vector3::new(0.0, 0.0, magnitude))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ramped_edges_are_timed_between_samples() {
+        // every threshold is crossed 3 ms into a sample period
+        let samples = ramps(&[
+            (0, ONE_G),
+            (1_003_000, ONE_G),
+            (1_103_000, 0.0),
+            (1_503_000, 0.0),
+            (1_603_000, 3.0 * ONE_G),
+            (1_653_000, 3.0 * ONE_G),
+            (1_753_000, 0.0),
+            (2_103_000, 0.0),
+            (2_203_000, 3.0 * ONE_G),
+            (2_500_000, ONE_G),
+        ]);
+
+        let bounces = run(&samples);
+
+        assert_eq!(bounces.len(), 2);
+        assert_eq!(bounces[0].takeoff, 1_073_000);
+        assert_eq!(bounces[0].landing, 1_523_000);
+        assert_eq!(bounces[1].takeoff, 1_743_000);
+        assert_eq!(bounces[1].landing, 2_123_000);
+        assert_eq!(bounces[1].ground_contact_time, Some(220_000));
+        assert_eq!(bounces[1].flight_time, 380_000);
+    }
+
+    #[test]
+    fn ignores_short_dips() {
+        let samples = trace(&[(ONE_G, 500_000), (0.0, 30_000), (ONE_G, 500_000)]);
+
+        assert!(run(&samples).is_empty());
+    }
+
+    #[test]
+    fn ignores_long_free_fall() {
+        let samples = trace(&[(ONE_G, 500_000), (0.0, 2_000_000), (ONE_G, 500_000)]);
+
+        assert!(run(&samples).is_empty());
+    }
+
+    #[test]
+    fn long_contact_is_a_standing_start() {
+        let samples = trace(&[
+            (ONE_G, 500_000),
+            (0.0, 400_000),
+            (ONE_G, 3_000_000),
+            (0.0, 400_000),
+            (ONE_G, 500_000),
+        ]);
+
+        let bounces = run(&samples);
+
+        assert_eq!(bounces.len(), 2);
+        assert_eq!(bounces[1].ground_contact_time, None);
+    }
+
+    #[test]
+    fn takeoff_velocity_along_up() {
+        // strapped on at an angle and pushing off with a g forwards as well as two g up
+        let (sin, cos) = 0.5f32.sin_cos();
+        let up = Vector3::new(0.0, sin, cos);
+        let forward = Vector3::new(0.0, cos, -sin);
+        let mut samples = Vec::new();
+        let mut timestamp = 0;
+        for (accel, duration) in [
+            (up * ONE_G, 500_000),
+            (up * 3.0 * ONE_G + forward * ONE_G, 200_000),
+            (Vector3::default(), 400_000),
+            (up * 3.0 * ONE_G, 100_000),
+        ] {
+            let end = timestamp + duration;
+            while timestamp < end {
+                samples.push(AccelSample::new(timestamp, accel));
+                timestamp += PERIOD;
+            }
+        }
+
+        let takeoff_velocity = |up: Option<Vector3>| {
+            let mut engine = BounceEngine::new(BounceConfig::default());
+            let bounce = samples.iter().find_map(|s| engine.push_oriented(*s, up));
+            bounce.unwrap().takeoff_velocity
+        };
+
+        // 2 g for 0.2 s is 3.9 m/s, the magnitude counts some of the forward push as well
+        let oriented = takeoff_velocity(Some(up));
+        assert!((oriented - 3.92).abs() < 0.15, "{}", oriented);
+        assert!(takeoff_velocity(None) > oriented + 0.2);
+    }
+}
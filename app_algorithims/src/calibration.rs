@@ -0,0 +1,560 @@
+use crate::sample::{Vector3, ONE_G};
+
+/*
+    sensor calibration
+
+    accelerometer: every axis has an offset and a scale error. holding the sensor still with each
+    axis pointing straight up and straight down (six poses) measures +1 g and -1 g on that axis, the
+    offset is halfway between the two and the scale is what stretches the difference to 2 g.
+
+    magnetometer: iron on the board adds a constant field (hard iron, an offset) and distorts the
+    field (soft iron, a matrix). rotated through every orientation the readings lie on an ellipsoid
+    instead of a sphere around the origin. fitting the ellipsoid gives the offset and the matrix
+    that maps it back onto a sphere, the radius of which is kept so readings stay in the same unit.
+*/
+
+pub type Matrix3 = [[f32; 3]; 3];
+
+pub const IDENTITY: Matrix3 = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// version of the byte format of a Calibration
+pub const CALIBRATION_VERSION: u8 = 1;
+const CALIBRATION_SIZE: usize = 1 + 18 * 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccelCalibration {
+    /// milli g
+    pub offset: Vector3,
+    pub scale: Vector3,
+}
+
+impl Default for AccelCalibration {
+    fn default() -> Self {
+        AccelCalibration {
+            offset: Vector3::default(),
+            scale: Vector3::new(1.0, 1.0, 1.0),
+        }
+    }
+}
+
+impl AccelCalibration {
+    pub fn apply(&self, accel: Vector3) -> Vector3 {
+        Vector3::new(
+            (accel.x - self.offset.x) * self.scale.x,
+            (accel.y - self.offset.y) * self.scale.y,
+            (accel.z - self.offset.z) * self.scale.z,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MagCalibration {
+    /// hard iron offset
+    pub offset: Vector3,
+    /// soft iron correction
+    pub soft_iron: Matrix3,
+}
+
+impl Default for MagCalibration {
+    fn default() -> Self {
+        MagCalibration {
+            offset: Vector3::default(),
+            soft_iron: IDENTITY,
+        }
+    }
+}
+
+impl MagCalibration {
+    pub fn apply(&self, mag: Vector3) -> Vector3 {
+        let v = mag - self.offset;
+        let row = |r: [f32; 3]| r[0] * v.x + r[1] * v.y + r[2] * v.z;
+        Vector3::new(
+            row(self.soft_iron[0]),
+            row(self.soft_iron[1]),
+            row(self.soft_iron[2]),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Calibration {
+    pub accel: AccelCalibration,
+    pub mag: MagCalibration,
+}
+
+impl Calibration {
+    /// [version] then every coefficient as a little endian f32
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(CALIBRATION_SIZE);
+        bytes.push(CALIBRATION_VERSION);
+        for value in self.values() {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// None when the bytes are not a calibration of this version
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != CALIBRATION_SIZE || bytes[0] != CALIBRATION_VERSION {
+            return None;
+        }
+        let v: Vec<f32> = bytes[1..]
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+        Some(Calibration {
+            accel: AccelCalibration {
+                offset: Vector3::new(v[0], v[1], v[2]),
+                scale: Vector3::new(v[3], v[4], v[5]),
+            },
+            mag: MagCalibration {
+                offset: Vector3::new(v[6], v[7], v[8]),
+                soft_iron: [
+                    [v[9], v[10], v[11]],
+                    [v[12], v[13], v[14]],
+                    [v[15], v[16], v[17]],
+                ],
+            },
+        })
+    }
+
+    fn values(&self) -> Vec<f32> {
+        let (a, m) = (&self.accel, &self.mag);
+        let mut values = vec![
+            a.offset.x, a.offset.y, a.offset.z, a.scale.x, a.scale.y, a.scale.z, m.offset.x,
+            m.offset.y, m.offset.z,
+        ];
+        values.extend(m.soft_iron.iter().flatten());
+        values
+    }
+}
+
+/// mean of samples taken while the sensor was held still, None if any sample is further than
+/// tolerance from the mean
+pub fn still_mean(samples: &[Vector3], tolerance: f32) -> Option<Vector3> {
+    if samples.is_empty() {
+        return None;
+    }
+    let sum = samples.iter().fold(Vector3::default(), |sum, s| sum + *s);
+    let mean = sum * (1.0 / samples.len() as f32);
+    samples
+        .iter()
+        .all(|s| (*s - mean).magnitude() <= tolerance)
+        .then_some(mean)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pose {
+    XUp,
+    XDown,
+    YUp,
+    YDown,
+    ZUp,
+    ZDown,
+}
+
+impl Pose {
+    pub const ALL: [Pose; 6] = [
+        Pose::XUp,
+        Pose::XDown,
+        Pose::YUp,
+        Pose::YDown,
+        Pose::ZUp,
+        Pose::ZDown,
+    ];
+
+    /// the pose a still reading was taken in, None unless one axis is close to vertical
+    pub fn detect(mean: Vector3) -> Option<Pose> {
+        let axes = [mean.x, mean.y, mean.z];
+        let (axis, value) = axes
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))?;
+        let vertical = value.abs() > 0.7 * ONE_G
+            && axes
+                .iter()
+                .enumerate()
+                .all(|(i, v)| i == axis || v.abs() < 0.3 * ONE_G);
+        if !vertical {
+            return None;
+        }
+        Some(Pose::ALL[axis * 2 + usize::from(*value < 0.0)])
+    }
+}
+
+/// collects the six poses of the accelerometer calibration in any order
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SixPoseCalibrator {
+    poses: [Option<Vector3>; 6],
+}
+
+impl SixPoseCalibrator {
+    pub fn new() -> Self {
+        SixPoseCalibrator::default()
+    }
+
+    /// adds the mean of a still reading, returns the pose it was taken in. a pose taken again
+    /// replaces the earlier one
+    pub fn add(&mut self, mean: Vector3) -> Option<Pose> {
+        let pose = Pose::detect(mean)?;
+        self.poses[Pose::ALL.iter().position(|p| *p == pose)?] = Some(mean);
+        Some(pose)
+    }
+
+    pub fn missing(&self) -> Vec<Pose> {
+        Pose::ALL
+            .iter()
+            .zip(&self.poses)
+            .filter(|(_, mean)| mean.is_none())
+            .map(|(pose, _)| *pose)
+            .collect()
+    }
+
+    /// None until every pose has been added
+    pub fn solve(&self) -> Option<AccelCalibration> {
+        let mut offset = [0.0; 3];
+        let mut scale = [0.0; 3];
+        for axis in 0..3 {
+            let up = component(self.poses[axis * 2]?, axis);
+            let down = component(self.poses[axis * 2 + 1]?, axis);
+            if up <= down {
+                return None;
+            }
+            offset[axis] = (up + down) / 2.0;
+            scale[axis] = 2.0 * ONE_G / (up - down);
+        }
+        Some(AccelCalibration {
+            offset: Vector3::new(offset[0], offset[1], offset[2]),
+            scale: Vector3::new(scale[0], scale[1], scale[2]),
+        })
+    }
+}
+
+fn component(v: Vector3, axis: usize) -> f32 {
+    [v.x, v.y, v.z][axis]
+}
+
+/// fits an ellipsoid to magnetometer readings taken in as many orientations as possible. None when
+/// the readings do not describe an ellipsoid, e.g. the sensor was only turned about one axis
+pub fn fit_ellipsoid(samples: &[Vector3]) -> Option<MagCalibration> {
+    if samples.len() < 9 {
+        return None;
+    }
+
+    // centre and scale the readings so the normal equations stay well conditioned
+    let n = samples.len() as f64;
+    let mean = samples.iter().fold([0.0f64; 3], |m, s| {
+        [
+            m[0] + s.x as f64 / n,
+            m[1] + s.y as f64 / n,
+            m[2] + s.z as f64 / n,
+        ]
+    });
+    let points: Vec<[f64; 3]> = samples
+        .iter()
+        .map(|s| {
+            [
+                s.x as f64 - mean[0],
+                s.y as f64 - mean[1],
+                s.z as f64 - mean[2],
+            ]
+        })
+        .collect();
+    let spread = points
+        .iter()
+        .flat_map(|p| p.iter())
+        .fold(0.0f64, |m, v| m.max(v.abs()));
+    if spread == 0.0 {
+        return None;
+    }
+    let points: Vec<[f64; 3]> = points
+        .iter()
+        .map(|p| [p[0] / spread, p[1] / spread, p[2] / spread])
+        .collect();
+
+    // a x^2 + b y^2 + c z^2 + 2f yz + 2g xz + 2h xy + 2p x + 2q y + 2r z = 1, least squares
+    let mut normal = [[0.0f64; 9]; 9];
+    let mut rhs = [0.0f64; 9];
+    for [x, y, z] in &points {
+        let row = [
+            x * x,
+            y * y,
+            z * z,
+            2.0 * y * z,
+            2.0 * x * z,
+            2.0 * x * y,
+            2.0 * x,
+            2.0 * y,
+            2.0 * z,
+        ];
+        for i in 0..9 {
+            rhs[i] += row[i];
+            for j in 0..9 {
+                normal[i][j] += row[i] * row[j];
+            }
+        }
+    }
+    let p = solve(normal, rhs)?;
+
+    let quadric = [[p[0], p[5], p[4]], [p[5], p[1], p[3]], [p[4], p[3], p[2]]];
+    let linear = [p[6], p[7], p[8]];
+    let centre = mul(&inverse(&quadric)?, &linear).map(|v| -v);
+    let k = 1.0 + dot(&centre, &mul(&quadric, &centre));
+
+    let (values, vectors) = eigen_symmetric(quadric.map(|r| r.map(|v| v / k)));
+    if values.iter().any(|v| *v <= 0.0) {
+        return None;
+    }
+    // keep the geometric mean radius so readings stay the same size
+    let radius = values.iter().product::<f64>().powf(-1.0 / 6.0);
+
+    let mut soft_iron = [[0.0f32; 3]; 3];
+    for (i, row) in soft_iron.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            let sqrt: f64 = (0..3)
+                .map(|e| vectors[i][e] * values[e].sqrt() * vectors[j][e])
+                .sum();
+            *value = (sqrt * radius) as f32;
+        }
+    }
+
+    Some(MagCalibration {
+        offset: Vector3::new(
+            (mean[0] + centre[0] * spread) as f32,
+            (mean[1] + centre[1] * spread) as f32,
+            (mean[2] + centre[2] * spread) as f32,
+        ),
+        soft_iron,
+    })
+}
+
+// gaussian elimination with partial pivoting
+fn solve<const N: usize>(mut a: [[f64; N]; N], mut b: [f64; N]) -> Option<[f64; N]> {
+    for col in 0..N {
+        let pivot = (col..N).max_by(|i, j| a[*i][col].abs().total_cmp(&a[*j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        for row in col + 1..N {
+            let factor = a[row][col] / a[col][col];
+            let pivot_row = a[col];
+            for (value, pivot) in a[row].iter_mut().zip(pivot_row).skip(col) {
+                *value -= factor * pivot;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+    let mut x = [0.0; N];
+    for row in (0..N).rev() {
+        let sum: f64 = (row + 1..N).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+fn inverse(m: &[[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+    let mut inverse = [[0.0; 3]; 3];
+    for col in 0..3 {
+        let mut unit = [0.0; 3];
+        unit[col] = 1.0;
+        let x = solve(*m, unit)?;
+        for row in 0..3 {
+            inverse[row][col] = x[row];
+        }
+    }
+    Some(inverse)
+}
+
+fn mul(m: &[[f64; 3]; 3], v: &[f64; 3]) -> [f64; 3] {
+    m.map(|row| dot(&row, v))
+}
+
+fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+// eigen values and vectors (as columns) of a symmetric matrix by jacobi rotations
+fn eigen_symmetric(mut a: [[f64; 3]; 3]) -> ([f64; 3], [[f64; 3]; 3]) {
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    for _ in 0..50 {
+        let off = a[0][1].powi(2) + a[0][2].powi(2) + a[1][2].powi(2);
+        if off < 1e-24 {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q].abs() < 1e-300 {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let t = if theta == 0.0 { 1.0 } else { t };
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+
+            // a = r^T a r, then v = v r
+            for row in a.iter_mut() {
+                let (rp, rq) = (row[p], row[q]);
+                row[p] = c * rp - s * rq;
+                row[q] = s * rp + c * rq;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            a[p] = std::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = std::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+            for row in v.iter_mut() {
+                let (vp, vq) = (row[p], row[q]);
+                row[p] = c * vp - s * vq;
+                row[q] = s * vp + c * vq;
+            }
+        }
+    }
+    ([a[0][0], a[1][1], a[2][2]], v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // evenly spread directions on a sphere
+    fn directions(count: usize) -> Vec<Vector3> {
+        let golden = std::f32::consts::PI * (3.0 - 5.0f32.sqrt());
+        (0..count)
+            .map(|i| {
+                let z = 1.0 - 2.0 * (i as f32 + 0.5) / count as f32;
+                let r = (1.0 - z * z).sqrt();
+                let theta = golden * i as f32;
+                Vector3::new(r * theta.cos(), r * theta.sin(), z)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn detects_poses() {
+        assert_eq!(
+            Pose::detect(Vector3::new(30.0, -20.0, 990.0)),
+            Some(Pose::ZUp)
+        );
+        assert_eq!(
+            Pose::detect(Vector3::new(-1010.0, 0.0, 40.0)),
+            Some(Pose::XDown)
+        );
+        assert_eq!(Pose::detect(Vector3::new(700.0, 0.0, 700.0)), None);
+    }
+
+    #[test]
+    fn six_pose_calibration() {
+        let offset = Vector3::new(40.0, -25.0, 60.0);
+        let gain = Vector3::new(1.03, 0.97, 1.01);
+        let reading = |g: Vector3| {
+            Vector3::new(
+                g.x * gain.x + offset.x,
+                g.y * gain.y + offset.y,
+                g.z * gain.z + offset.z,
+            )
+        };
+
+        let mut calibrator = SixPoseCalibrator::new();
+        for g in [
+            Vector3::new(0.0, 0.0, ONE_G),
+            Vector3::new(ONE_G, 0.0, 0.0),
+            Vector3::new(0.0, -ONE_G, 0.0),
+            Vector3::new(-ONE_G, 0.0, 0.0),
+            Vector3::new(0.0, ONE_G, 0.0),
+        ] {
+            calibrator.add(reading(g)).unwrap();
+        }
+        assert_eq!(calibrator.missing(), vec![Pose::ZDown]);
+        assert_eq!(calibrator.solve(), None);
+
+        calibrator.add(reading(Vector3::new(0.0, 0.0, -ONE_G)));
+        let calibration = calibrator.solve().unwrap();
+
+        let corrected = calibration.apply(reading(Vector3::new(500.0, -300.0, 800.0)));
+        assert!((corrected - Vector3::new(500.0, -300.0, 800.0)).magnitude() < 0.5);
+    }
+
+    #[test]
+    fn still_mean_rejects_movement() {
+        let still = [
+            Vector3::new(0.0, 0.0, 1000.0),
+            Vector3::new(0.0, 4.0, 996.0),
+        ];
+        let moving = [
+            Vector3::new(0.0, 0.0, 1000.0),
+            Vector3::new(0.0, 200.0, 900.0),
+        ];
+
+        assert_eq!(
+            still_mean(&still, 10.0),
+            Some(Vector3::new(0.0, 2.0, 998.0))
+        );
+        assert_eq!(still_mean(&moving, 10.0), None);
+    }
+
+    #[test]
+    fn ellipsoid_fit_removes_hard_and_soft_iron() {
+        let field = 48_000.0;
+        let offset = Vector3::new(12_000.0, -7_000.0, 3_000.0);
+        // symmetric soft iron distortion
+        let distortion = [[1.2, 0.1, 0.0], [0.1, 0.9, 0.05], [0.0, 0.05, 1.05]];
+        let distortion = MagCalibration {
+            offset: Vector3::default(),
+            soft_iron: distortion,
+        };
+        let mut seed: u32 = 3;
+        let mut noise = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            ((seed >> 16) as f32 / 65536.0 - 0.5) * 300.0
+        };
+        let readings: Vec<Vector3> = directions(300)
+            .into_iter()
+            .map(|d| distortion.apply(d * field) + offset + Vector3::new(noise(), noise(), noise()))
+            .collect();
+
+        let calibration = fit_ellipsoid(&readings).unwrap();
+
+        assert!((calibration.offset - offset).magnitude() < 200.0);
+        let magnitudes: Vec<f32> = readings
+            .iter()
+            .map(|r| calibration.apply(*r).magnitude())
+            .collect();
+        let mean = magnitudes.iter().sum::<f32>() / magnitudes.len() as f32;
+        assert!(magnitudes.iter().all(|m| (m - mean).abs() / mean < 0.02));
+        // the soft iron correction is the inverse of the distortion up to scale
+        let corrected =
+            calibration.apply(distortion.apply(Vector3::new(1.0, 0.0, 0.0) * field) + offset);
+        assert!(corrected.y.abs() / corrected.magnitude() < 0.02);
+    }
+
+    #[test]
+    fn ellipsoid_fit_needs_every_orientation() {
+        // only turned about z, the readings are a circle
+        let readings: Vec<Vector3> = (0..100)
+            .map(|i| {
+                let angle = i as f32 * 0.0628;
+                Vector3::new(angle.cos(), angle.sin(), 0.2) * 40_000.0
+            })
+            .collect();
+
+        assert_eq!(fit_ellipsoid(&readings), None);
+    }
+
+    #[test]
+    fn calibration_round_trip() {
+        let calibration = Calibration {
+            accel: AccelCalibration {
+                offset: Vector3::new(1.0, 2.0, 3.0),
+                scale: Vector3::new(1.01, 0.99, 1.0),
+            },
+            mag: MagCalibration {
+                offset: Vector3::new(-100.0, 50.0, 7.5),
+                soft_iron: [[1.0, 0.1, 0.0], [0.1, 1.1, 0.0], [0.0, 0.0, 0.9]],
+            },
+        };
+        let bytes = calibration.to_bytes();
+
+        assert_eq!(Calibration::from_bytes(&bytes), Some(calibration));
+        assert_eq!(Calibration::from_bytes(&bytes[1..]), None);
+    }
+}
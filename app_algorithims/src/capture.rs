@@ -0,0 +1,215 @@
+use crate::sample::{AccelSample, Vector3};
+
+/*
+    recorded sessions
+
+    a capture is the raw accel and mag stream of a session, stored either as csv for hand edited and
+    synthesised sessions or as a binary file for sessions recorded off the sensor.
+
+    csv, one sample per line, lines starting with # are comments
+
+        timestamp_us,accel_x_mg,accel_y_mg,accel_z_mg[,mag_x,mag_y,mag_z]
+
+    binary, little endian
+
+        "RSCP" [version u8] [flags u8]
+        ([timestamp_us i64] [accel_x f32] [accel_y f32] [accel_z f32] ([mag_x f32] [mag_y f32] [mag_z f32])?)*
+
+    flags bit 0 is set when the records carry the magnetometer.
+*/
+
+pub const CAPTURE_MAGIC: [u8; 4] = *b"RSCP";
+pub const CAPTURE_VERSION: u8 = 1;
+
+const FLAG_MAG: u8 = 0x01;
+const HEADER_SIZE: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureError {
+    /// a csv line that is not a sample, counting from 1
+    InvalidLine {
+        line: usize,
+    },
+    /// the file does not start with the capture magic
+    InvalidMagic,
+    UnsupportedVersion(u8),
+    /// the file ends part way through a record
+    Truncated,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CaptureSample {
+    pub timestamp: i64,
+    pub accel: Vector3,
+    pub mag: Option<Vector3>,
+}
+
+impl CaptureSample {
+    pub fn accel_sample(&self) -> AccelSample {
+        AccelSample::new(self.timestamp, self.accel)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Capture {
+    pub samples: Vec<CaptureSample>,
+}
+
+impl Capture {
+    pub fn from_csv(csv: &str) -> Result<Self, CaptureError> {
+        let mut samples = Vec::new();
+        for (index, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = CaptureError::InvalidLine { line: index + 1 };
+
+            let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+            if fields.len() != 4 && fields.len() != 7 {
+                return Err(error);
+            }
+            let timestamp = fields[0].parse::<i64>().map_err(|_| error)?;
+            let values = fields[1..]
+                .iter()
+                .map(|f| f.parse::<f32>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| error)?;
+
+            samples.push(CaptureSample {
+                timestamp,
+                accel: Vector3::new(values[0], values[1], values[2]),
+                mag: (values.len() == 6).then(|| Vector3::new(values[3], values[4], values[5])),
+            });
+        }
+        Ok(Capture { samples })
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CaptureError> {
+        if bytes.len() < HEADER_SIZE || bytes[..4] != CAPTURE_MAGIC {
+            return Err(CaptureError::InvalidMagic);
+        }
+        if bytes[4] != CAPTURE_VERSION {
+            return Err(CaptureError::UnsupportedVersion(bytes[4]));
+        }
+        let has_mag = bytes[5] & FLAG_MAG != 0;
+        let record_size = if has_mag { 8 + 6 * 4 } else { 8 + 3 * 4 };
+
+        let records = &bytes[HEADER_SIZE..];
+        if !records.len().is_multiple_of(record_size) {
+            return Err(CaptureError::Truncated);
+        }
+
+        let samples = records
+            .chunks_exact(record_size)
+            .map(|record| {
+                let value = |i: usize| {
+                    let start = 8 + i * 4;
+                    f32::from_le_bytes(record[start..start + 4].try_into().unwrap())
+                };
+                CaptureSample {
+                    timestamp: i64::from_le_bytes(record[..8].try_into().unwrap()),
+                    accel: Vector3::new(value(0), value(1), value(2)),
+                    mag: has_mag.then(|| Vector3::new(value(3), value(4), value(5))),
+                }
+            })
+            .collect();
+        Ok(Capture { samples })
+    }
+
+    /// the binary form of the capture, the mag is only stored when every sample has one
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let has_mag = !self.samples.is_empty() && self.samples.iter().all(|s| s.mag.is_some());
+
+        let mut bytes = Vec::with_capacity(HEADER_SIZE + self.samples.len() * 32);
+        bytes.extend_from_slice(&CAPTURE_MAGIC);
+        bytes.push(CAPTURE_VERSION);
+        bytes.push(if has_mag { FLAG_MAG } else { 0 });
+
+        for sample in &self.samples {
+            bytes.extend_from_slice(&sample.timestamp.to_le_bytes());
+            let mut values = vec![sample.accel];
+            if has_mag {
+                values.push(sample.mag.unwrap_or_default());
+            }
+            for v in values {
+                for component in [v.x, v.y, v.z] {
+                    bytes.extend_from_slice(&component.to_le_bytes());
+                }
+            }
+        }
+        bytes
+    }
+
+    pub fn accel_samples(&self) -> impl Iterator<Item = AccelSample> + '_ {
+        self.samples.iter().map(|s| s.accel_sample())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_csv() {
+        let csv = "# timestamp_us,accel_x_mg,accel_y_mg,accel_z_mg,mag_x,mag_y,mag_z\n\
+                   0,1,2,1000\n\
+                   \n\
+                   10000, 3, 4, 980, 200, -10, 30\n";
+
+        let capture = Capture::from_csv(csv).unwrap();
+
+        assert_eq!(capture.samples.len(), 2);
+        assert_eq!(capture.samples[0].accel, Vector3::new(1.0, 2.0, 1000.0));
+        assert_eq!(capture.samples[0].mag, None);
+        assert_eq!(capture.samples[1].timestamp, 10_000);
+        assert_eq!(
+            capture.samples[1].mag,
+            Some(Vector3::new(200.0, -10.0, 30.0))
+        );
+    }
+
+    #[test]
+    fn reports_bad_lines() {
+        assert_eq!(
+            Capture::from_csv("0,1,2,3\n10,1,2\n"),
+            Err(CaptureError::InvalidLine { line: 2 })
+        );
+        assert_eq!(
+            Capture::from_csv("# header\n0,1,x,3\n"),
+            Err(CaptureError::InvalidLine { line: 2 })
+        );
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let csv = "0,1,2,1000,5,6,7\n10000,3.5,-4,980,200,-10,30\n";
+        let capture = Capture::from_csv(csv).unwrap();
+
+        assert_eq!(Capture::from_bytes(&capture.to_bytes()), Ok(capture));
+
+        let without_mag = Capture::from_csv("0,1,2,1000\n").unwrap();
+        let bytes = without_mag.to_bytes();
+        assert_eq!(bytes.len(), HEADER_SIZE + 20);
+        assert_eq!(Capture::from_bytes(&bytes), Ok(without_mag));
+    }
+
+    #[test]
+    fn rejects_bad_binaries() {
+        let mut bytes = Capture::from_csv("0,1,2,1000\n").unwrap().to_bytes();
+
+        assert_eq!(
+            Capture::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(CaptureError::Truncated)
+        );
+        bytes[4] = 9;
+        assert_eq!(
+            Capture::from_bytes(&bytes),
+            Err(CaptureError::UnsupportedVersion(9))
+        );
+        assert_eq!(
+            Capture::from_bytes(b"nope"),
+            Err(CaptureError::InvalidMagic)
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use crate::sample::AccelSample;
+
+/*
+    ground contact timing
+
+    at 100 Hz a landing or takeoff is only known to within 10 ms, which is a large part of a 150 ms
+    reactive contact. the edges are located between the last sample on one side of the threshold and
+    the first sample on the other, and the crossing time is linearly interpolated between the two.
+*/
+
+/// a ground contact, times are in micro seconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroundContact {
+    pub landing: i64,
+    pub takeoff: i64,
+}
+
+impl GroundContact {
+    pub fn duration(&self) -> i64 {
+        self.takeoff - self.landing
+    }
+}
+
+/// interpolated time at which the acceleration magnitude crossed the threshold between two samples,
+/// if the threshold is not between the two magnitudes the nearest sample's time is returned
+pub fn crossing_time(before: &AccelSample, after: &AccelSample, threshold: f32) -> i64 {
+    let from = before.magnitude();
+    let to = after.magnitude();
+
+    if from == to {
+        return after.timestamp;
+    }
+
+    let fraction = ((threshold - from) / (to - from)).clamp(0.0, 1.0);
+    before.timestamp + ((after.timestamp - before.timestamp) as f32 * fraction).round() as i64
+}
+
+/// finds the first landing edge (magnitude rising above landing_threshold) in the samples
+pub fn landing_edge(samples: &[AccelSample], landing_threshold: f32) -> Option<(usize, i64)> {
+    samples
+        .windows(2)
+        .position(|w| w[0].magnitude() <= landing_threshold && w[1].magnitude() > landing_threshold)
+        .map(|i| {
+            (
+                i + 1,
+                crossing_time(&samples[i], &samples[i + 1], landing_threshold),
+            )
+        })
+}
+
+/// finds the first takeoff edge (magnitude falling below takeoff_threshold) in the samples
+pub fn takeoff_edge(samples: &[AccelSample], takeoff_threshold: f32) -> Option<(usize, i64)> {
+    samples
+        .windows(2)
+        .position(|w| w[0].magnitude() >= takeoff_threshold && w[1].magnitude() < takeoff_threshold)
+        .map(|i| {
+            (
+                i + 1,
+                crossing_time(&samples[i], &samples[i + 1], takeoff_threshold),
+            )
+        })
+}
+
+/// first complete ground contact in the samples, a landing followed by a takeoff
+pub fn ground_contact(
+    samples: &[AccelSample],
+    landing_threshold: f32,
+    takeoff_threshold: f32,
+) -> Option<GroundContact> {
+    let (landing_index, landing) = landing_edge(samples, landing_threshold)?;
+    // search for the takeoff from the first sample above the landing threshold onwards
+    let (_, takeoff) = takeoff_edge(&samples[landing_index..], takeoff_threshold)?;
+
+    Some(GroundContact { landing, takeoff })
+}
+
+/// every complete ground contact in the samples, in order
+pub fn ground_contacts(
+    samples: &[AccelSample],
+    landing_threshold: f32,
+    takeoff_threshold: f32,
+) -> Vec<GroundContact> {
+    let mut contacts = Vec::new();
+    let mut rest = samples;
+
+    while let Some((landing_index, landing)) = landing_edge(rest, landing_threshold) {
+        rest = &rest[landing_index..];
+        match takeoff_edge(rest, takeoff_threshold) {
+            Some((takeoff_index, takeoff)) => {
+                contacts.push(GroundContact { landing, takeoff });
+                rest = &rest[takeoff_index..];
+            }
+            None => break,
+        }
+    }
+    contacts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sample::Vector3;
+
+    fn sample(timestamp: i64, magnitude: f32) -> AccelSample {
+        AccelSample::new(timestamp, Vector3::new(0.0, magnitude, 0.0))
+    }
+
+    // samples at 100 Hz of a trapezoid shaped contact that is on the ground from landing to takeoff,
+    // with 20 ms ramps either side
+    fn contact_trace(landing: f32, takeoff: f32) -> Vec<AccelSample> {
+        let ramp = 20.0;
+        (0..60)
+            .map(|i| {
+                let t = i as f32 * 10.0;
+                let magnitude = if t < landing - ramp / 2.0 || t > takeoff + ramp / 2.0 {
+                    0.0
+                } else if t < landing + ramp / 2.0 {
+                    (t - (landing - ramp / 2.0)) / ramp * 2000.0
+                } else if t > takeoff - ramp / 2.0 {
+                    (takeoff + ramp / 2.0 - t) / ramp * 2000.0
+                } else {
+                    2000.0
+                };
+                sample(i * 10_000, magnitude)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn interpolates_crossing() {
+        let before = sample(0, 1000.0);
+        let after = sample(10_000, 0.0);
+
+        assert_eq!(crossing_time(&before, &after, 300.0), 7_000);
+        assert_eq!(
+            crossing_time(&sample(0, 0.0), &sample(10_000, 1000.0), 300.0),
+            3_000
+        );
+    }
+
+    #[test]
+    fn contact_time_is_not_quantised() {
+        let contact = ground_contact(&contact_trace(103.0, 286.4), 1000.0, 1000.0).unwrap();
+
+        assert!((contact.landing - 103_000).abs() <= 100);
+        assert!((contact.takeoff - 286_400).abs() <= 100);
+        assert!((contact.duration() - 183_400).abs() <= 200);
+    }
+
+    #[test]
+    fn finds_every_contact() {
+        let mut samples = contact_trace(50.0, 200.0);
+        let second = contact_trace(100.0, 300.0);
+        let offset = samples.last().unwrap().timestamp + 10_000;
+        samples.extend(
+            second
+                .iter()
+                .map(|s| sample(s.timestamp + offset, s.magnitude())),
+        );
+
+        let contacts = ground_contacts(&samples, 600.0, 300.0);
+
+        assert_eq!(contacts.len(), 2);
+        assert!((contacts[1].landing - offset - 100_000).abs() < 5_000);
+    }
+
+    #[test]
+    fn incomplete_contact() {
+        let samples = contact_trace(300.0, 1000.0);
+
+        assert_eq!(ground_contact(&samples, 600.0, 300.0), None);
+    }
+}
@@ -0,0 +1,47 @@
+/*
+    end conditions
+
+    a set finishes either after a fixed time from when it started or once the athlete has done a
+    number of bounces, whichever the set was configured with.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndCondition {
+    /// the set ends this many micro seconds after it started
+    Timed { duration: i64 },
+    /// the set ends once this many bounces have been measured
+    Counted { counts: u32 },
+}
+
+impl EndCondition {
+    /// elapsed is the time in micro seconds since the set started
+    pub fn is_met(&self, elapsed: i64, bounces: u32) -> bool {
+        match *self {
+            EndCondition::Timed { duration } => elapsed >= duration,
+            EndCondition::Counted { counts } => bounces >= counts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timed() {
+        let end = EndCondition::Timed {
+            duration: 10_000_000,
+        };
+
+        assert!(!end.is_met(9_999_999, 100));
+        assert!(end.is_met(10_000_000, 0));
+    }
+
+    #[test]
+    fn counted() {
+        let end = EndCondition::Counted { counts: 5 };
+
+        assert!(!end.is_met(60_000_000, 4));
+        assert!(end.is_met(0, 5));
+    }
+}
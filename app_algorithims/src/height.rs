@@ -0,0 +1,199 @@
+use crate::sample::{AccelSample, Vector3, ONE_G, STANDARD_GRAVITY};
+
+/*
+    jump height estimation
+
+    there are two ways of getting a jump height out of an accelerometer
+    - flight time: the athlete is a projectile while in the air so h = g * t^2 / 8
+    - takeoff velocity: integrate the net vertical acceleration while on the ground to get the
+      velocity the athlete left the ground with, then h = v^2 / 2g
+
+    flight time is robust but over estimates when the athlete lands with bent knees, takeoff velocity
+    does not care about the landing but drifts with sensor offset errors. which one is used is chosen
+    per session so the two can be compared.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HeightModel {
+    #[default]
+    FlightTime,
+    TakeoffVelocity,
+}
+
+/// jump height in metres from the flight time in micro seconds, h = g * t^2 / 8
+pub fn flight_time_height(flight_time: i64) -> f32 {
+    let seconds = flight_time as f32 / 1_000_000.0;
+    STANDARD_GRAVITY * seconds * seconds / 8.0
+}
+
+/// jump height in metres from the vertical takeoff velocity in m/s, h = v^2 / 2g
+pub fn takeoff_velocity_height(takeoff_velocity: f32) -> f32 {
+    if takeoff_velocity <= 0.0 {
+        return 0.0;
+    }
+    takeoff_velocity * takeoff_velocity / (2.0 * STANDARD_GRAVITY)
+}
+
+/// vertical velocity in m/s (up is positive) the athlete lands with after a flight of this many
+/// micro seconds, assuming they land at the height they took off from
+pub fn landing_velocity(flight_time: i64) -> f32 {
+    -STANDARD_GRAVITY * (flight_time as f32 / 1_000_000.0) / 2.0
+}
+
+/// net vertical acceleration in m/s^2 while on the ground. without an orientation estimate the
+/// magnitude is used, during contact the acceleration is dominated by the vertical component.
+fn net_vertical_acceleration(sample: &AccelSample) -> f32 {
+    (sample.magnitude() - ONE_G) / ONE_G * STANDARD_GRAVITY
+}
+
+/// net vertical acceleration in m/s^2 from the acceleration along up in milli g, see the
+/// orientation module
+pub fn vertical_acceleration(sample: &AccelSample, up: Vector3) -> f32 {
+    (sample.accel.dot(&up) - ONE_G) / ONE_G * STANDARD_GRAVITY
+}
+
+/// integrates net vertical acceleration into vertical velocity one sample at a time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VelocityIntegrator {
+    velocity: f32,
+    // timestamp and net vertical acceleration of the previous sample
+    previous: Option<(i64, f32)>,
+}
+
+impl VelocityIntegrator {
+    pub fn new(initial_velocity: f32) -> Self {
+        VelocityIntegrator {
+            velocity: initial_velocity,
+            previous: None,
+        }
+    }
+
+    /// restart the integration from a known velocity in m/s
+    pub fn reset(&mut self, velocity: f32) {
+        self.velocity = velocity;
+        self.previous = None;
+    }
+
+    /// integrates a sample using its magnitude as the vertical acceleration
+    pub fn push(&mut self, sample: AccelSample) {
+        self.push_vertical(sample.timestamp, net_vertical_acceleration(&sample));
+    }
+
+    /// integrates a net vertical acceleration in m/s^2 taken at timestamp in micro seconds
+    pub fn push_vertical(&mut self, timestamp: i64, acceleration: f32) {
+        if let Some((previous, previous_acceleration)) = self.previous {
+            if timestamp <= previous {
+                return;
+            }
+            // trapezoidal rule between the two samples
+            let dt = (timestamp - previous) as f32 / 1_000_000.0;
+            self.velocity += (previous_acceleration + acceleration) / 2.0 * dt;
+        }
+        self.previous = Some((timestamp, acceleration));
+    }
+
+    /// vertical velocity in m/s, up is positive
+    pub fn velocity(&self) -> f32 {
+        self.velocity
+    }
+}
+
+/// velocity in m/s at the end of a ground contact, the samples should only cover the contact
+pub fn takeoff_velocity(samples: &[AccelSample], initial_velocity: f32) -> f32 {
+    let mut integrator = VelocityIntegrator::new(initial_velocity);
+    for sample in samples {
+        integrator.push(*sample);
+    }
+    integrator.velocity()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bounce::{BounceConfig, BounceEngine};
+    use crate::sample::Vector3;
+
+    // synthesised from a rigid body model until we have force plate captures, see the file header.
+    // the reference heights come from the same model so the tests comparing with them only check
+    // the estimates invert it, they say nothing about how close they are to a real jump
+    const FIXTURE: &str = include_str!("../tests/fixtures/rebound_jumps.csv");
+    const MODEL: &str = include_str!("../tests/fixtures/rebound_jumps.model.csv");
+
+    fn rows(csv: &str) -> impl Iterator<Item = Vec<f64>> + '_ {
+        csv.lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| line.split(',').map(|v| v.trim().parse().unwrap()).collect())
+    }
+
+    fn samples() -> Vec<AccelSample> {
+        rows(FIXTURE)
+            .map(|r| {
+                let accel = Vector3::new(r[1] as f32, r[2] as f32, r[3] as f32);
+                AccelSample::new(r[0] as i64, accel)
+            })
+            .collect()
+    }
+
+    // (takeoff velocity, height) every jump was synthesised with
+    fn model_reference() -> Vec<(f32, f32)> {
+        rows(MODEL).map(|r| (r[0] as f32, r[1] as f32)).collect()
+    }
+
+    fn heights(model: HeightModel) -> Vec<f32> {
+        let mut engine = BounceEngine::new(BounceConfig {
+            height_model: model,
+            ..Default::default()
+        });
+        samples()
+            .into_iter()
+            .filter_map(|s| engine.push(s))
+            .map(|b| b.height)
+            .collect()
+    }
+
+    fn mean_absolute_error(model: HeightModel) -> f32 {
+        let truth = model_reference();
+        let heights = heights(model);
+        assert_eq!(heights.len(), truth.len());
+
+        let total: f32 = heights
+            .iter()
+            .zip(&truth)
+            .map(|(h, t)| (h - t.1).abs())
+            .sum();
+        total / truth.len() as f32
+    }
+
+    #[test]
+    fn height_from_flight_time() {
+        // 0.5 s in the air is 30.6 cm
+        assert!((flight_time_height(500_000) - 0.3065).abs() < 0.0005);
+    }
+
+    #[test]
+    fn height_from_takeoff_velocity() {
+        assert!((takeoff_velocity_height(2.4517) - 0.3065).abs() < 0.0005);
+        assert_eq!(takeoff_velocity_height(-1.0), 0.0);
+    }
+
+    #[test]
+    fn integrates_constant_acceleration() {
+        // 2 g for 100 ms is a net 1 g, which adds 0.98 m/s
+        let samples: Vec<AccelSample> = (0..=10)
+            .map(|i| AccelSample::new(i * 10_000, Vector3::new(0.0, 0.0, 2.0 * ONE_G)))
+            .collect();
+
+        assert!((takeoff_velocity(&samples, -1.0) + 0.0193).abs() < 0.001);
+    }
+
+    // placeholders until there are force plate captures, see MODEL
+    #[test]
+    fn flight_time_model_recovers_synthesised_heights() {
+        assert!(mean_absolute_error(HeightModel::FlightTime) < 0.01);
+    }
+
+    #[test]
+    fn takeoff_velocity_model_recovers_synthesised_heights() {
+        assert!(mean_absolute_error(HeightModel::TakeoffVelocity) < 0.015);
+    }
+}
@@ -1,20 +1,17 @@
+/*
+    app_algorithims contains the signal processing used by the app crate.
 
+    nothing in here should know about the hardware or the protocol, so every algorithim can be unit
+    tested on the host. algorithims are fed one sample at a time and should not allocate per sample.
+*/
 
-// we can copy stuff across from algo dev to get started 
-
-THIS PROJECT IS NOT SETUP YET COPY lib/src config in app sub crate
-
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub mod bounce;
+pub mod calibration;
+pub mod capture;
+pub mod contact;
+pub mod end;
+pub mod height;
+pub mod orientation;
+pub mod replay;
+pub mod sample;
+pub mod spike;
@@ -0,0 +1,255 @@
+use crate::sample::{AccelSample, Vector3, ONE_G};
+
+/*
+    orientation estimation without a gyro
+
+    standing still the accelerometer only measures gravity, so a low pass filter of the readings
+    points straight up in the sensor's frame. while the athlete moves the readings are anything but
+    gravity, so the filter only listens to readings close to 1 g and trusts them less the further
+    they are from it. that keeps the estimate from the last time the athlete stood still through a
+    set, which is fine as the sensor is strapped on and does not turn much relative to the ground.
+
+    the magnetometer reading with the vertical part removed points to magnetic north, the heading is
+    the angle between it and the sensor's x axis (or y if x points up). the mag should be calibrated
+    first, hard iron offsets swamp the earth's field.
+
+    with up known the vertical acceleration is the reading projected onto it, which does not depend
+    on the mounting angle the way the magnitude does once there is any horizontal acceleration.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrientationConfig {
+    /// time constant of the gravity low pass in micro seconds
+    pub gravity_time_constant: i64,
+    /// readings further than this from 1 g (milli g) are ignored for gravity
+    pub gravity_band: f32,
+    /// time constant of the north low pass in micro seconds
+    pub north_time_constant: i64,
+}
+
+impl Default for OrientationConfig {
+    fn default() -> Self {
+        OrientationConfig {
+            gravity_time_constant: 500_000,
+            gravity_band: 150.0,
+            north_time_constant: 1_000_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrientationFilter {
+    config: OrientationConfig,
+    // unit vector pointing up in the sensor's frame
+    up: Option<Vector3>,
+    // horizontal unit vector pointing to magnetic north in the sensor's frame
+    north: Option<Vector3>,
+    last_accel: Option<i64>,
+    last_mag: Option<i64>,
+}
+
+impl OrientationFilter {
+    pub fn new(config: OrientationConfig) -> Self {
+        OrientationFilter {
+            config,
+            up: None,
+            north: None,
+            last_accel: None,
+            last_mag: None,
+        }
+    }
+
+    /// forget the orientation, e.g. when the sensor was moved to another athlete
+    pub fn reset(&mut self) {
+        *self = OrientationFilter::new(self.config);
+    }
+
+    pub fn push(&mut self, sample: &AccelSample) {
+        let Some(gain) = gain(
+            &mut self.last_accel,
+            sample.timestamp,
+            self.config.gravity_time_constant,
+        ) else {
+            return;
+        };
+        let magnitude = sample.magnitude();
+        let weight = 1.0 - (magnitude - ONE_G).abs() / self.config.gravity_band;
+        if weight <= 0.0 {
+            return;
+        }
+        let reading = sample.accel * (1.0 / magnitude);
+        let up = match self.up {
+            Some(up) => up + (reading - up) * (gain * weight),
+            None => reading,
+        };
+        self.up = normalise(up);
+    }
+
+    /// a magnetometer reading taken at timestamp in micro seconds, ignored until up is known
+    pub fn push_mag(&mut self, timestamp: i64, mag: Vector3) {
+        let Some(up) = self.up else {
+            return;
+        };
+        let Some(gain) = gain(
+            &mut self.last_mag,
+            timestamp,
+            self.config.north_time_constant,
+        ) else {
+            return;
+        };
+        let Some(reading) = normalise(horizontal(mag, up)) else {
+            return;
+        };
+        let north = match self.north {
+            Some(north) => north + (reading - north) * gain,
+            None => reading,
+        };
+        self.north = normalise(horizontal(north, up));
+    }
+
+    /// unit vector pointing up in the sensor's frame, None until the sensor has been still
+    pub fn up(&self) -> Option<Vector3> {
+        self.up
+    }
+
+    /// acceleration along up in milli g with gravity taken off, positive is up
+    pub fn vertical(&self, accel: Vector3) -> Option<f32> {
+        self.up.map(|up| accel.dot(&up) - ONE_G)
+    }
+
+    /// heading of the sensor's x axis in radians clockwise from magnetic north, the y axis when x
+    /// points close to straight up or down
+    pub fn heading(&self) -> Option<f32> {
+        let (up, north) = (self.up?, self.north?);
+        let forward = normalise(horizontal(Vector3::new(1.0, 0.0, 0.0), up))
+            .filter(|_| up.x.abs() < 0.9)
+            .or_else(|| normalise(horizontal(Vector3::new(0.0, 1.0, 0.0), up)))?;
+        let heading = cross(&forward, &north).dot(&up).atan2(forward.dot(&north));
+        Some(heading.rem_euclid(std::f32::consts::TAU))
+    }
+}
+
+impl Default for OrientationFilter {
+    fn default() -> Self {
+        OrientationFilter::new(OrientationConfig::default())
+    }
+}
+
+// gain of a first order low pass for the time since the last update, None for the first update or
+// one that goes back in time
+fn gain(last: &mut Option<i64>, timestamp: i64, time_constant: i64) -> Option<f32> {
+    let previous = last.replace(timestamp);
+    match previous {
+        Some(previous) if timestamp > previous => {
+            let dt = (timestamp - previous) as f32;
+            Some(dt / (time_constant as f32 + dt))
+        }
+        Some(previous) => {
+            *last = Some(previous);
+            None
+        }
+        None => Some(1.0),
+    }
+}
+
+fn horizontal(v: Vector3, up: Vector3) -> Vector3 {
+    v - up * v.dot(&up)
+}
+
+fn normalise(v: Vector3) -> Option<Vector3> {
+    let magnitude = v.magnitude();
+    (magnitude > f32::EPSILON).then(|| v * (1.0 / magnitude))
+}
+
+fn cross(a: &Vector3, b: &Vector3) -> Vector3 {
+    Vector3::new(
+        a.y * b.z - a.z * b.y,
+        a.z * b.x - a.x * b.z,
+        a.x * b.y - a.y * b.x,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PERIOD: i64 = 10_000;
+
+    // rotates v about the x axis by angle radians
+    fn tilt(v: Vector3, angle: f32) -> Vector3 {
+        let (sin, cos) = angle.sin_cos();
+        Vector3::new(v.x, v.y * cos - v.z * sin, v.y * sin + v.z * cos)
+    }
+
+    fn stand(filter: &mut OrientationFilter, up: Vector3, duration: i64) {
+        for t in (0..duration).step_by(PERIOD as usize) {
+            filter.push(&AccelSample::new(t, up * ONE_G));
+        }
+    }
+
+    #[test]
+    fn finds_up_at_any_mounting_angle() {
+        let up = tilt(Vector3::new(0.0, 0.0, 1.0), 0.6);
+        let mut filter = OrientationFilter::default();
+        assert_eq!(filter.vertical(up * ONE_G), None);
+
+        stand(&mut filter, up, 1_000_000);
+
+        assert!((filter.up().unwrap() - up).magnitude() < 1e-3);
+        // two g up with half a g sideways
+        let sideways = tilt(Vector3::new(500.0, 0.0, 0.0), 0.6);
+        let vertical = filter.vertical(up * 3000.0 + sideways).unwrap();
+        assert!((vertical - 2000.0).abs() < 2.0, "{}", vertical);
+    }
+
+    #[test]
+    fn ignores_readings_far_from_one_g() {
+        let up = Vector3::new(0.0, 0.0, 1.0);
+        let mut filter = OrientationFilter::default();
+        stand(&mut filter, up, 500_000);
+
+        // free fall and a hard landing pointing somewhere else
+        for (i, accel) in [Vector3::new(0.0, 50.0, 0.0), Vector3::new(3000.0, 0.0, 0.0)]
+            .iter()
+            .enumerate()
+        {
+            filter.push(&AccelSample::new(500_000 + i as i64 * PERIOD, *accel));
+        }
+
+        assert_eq!(filter.up(), Some(up));
+    }
+
+    #[test]
+    fn tracks_slow_changes() {
+        let mut filter = OrientationFilter::default();
+        stand(&mut filter, Vector3::new(0.0, 0.0, 1.0), 500_000);
+
+        let up = tilt(Vector3::new(0.0, 0.0, 1.0), 0.3);
+        for t in (500_000..3_000_000).step_by(PERIOD as usize) {
+            filter.push(&AccelSample::new(t, up * ONE_G));
+        }
+
+        assert!((filter.up().unwrap() - up).magnitude() < 0.01);
+    }
+
+    #[test]
+    fn heading_from_the_magnetometer() {
+        let up = Vector3::new(0.0, 0.0, 1.0);
+        let mut filter = OrientationFilter::default();
+        stand(&mut filter, up, 200_000);
+        assert_eq!(filter.heading(), None);
+
+        // north is along -y with a steep downward dip, so x points west
+        let mag = Vector3::new(0.0, -20_000.0, -45_000.0);
+        for t in (0..1_000_000).step_by(PERIOD as usize) {
+            filter.push_mag(t, mag);
+        }
+
+        let heading = filter.heading().unwrap();
+        assert!(
+            (heading - 1.5 * std::f32::consts::PI).abs() < 1e-3,
+            "{}",
+            heading
+        );
+    }
+}
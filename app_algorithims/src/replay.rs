@@ -0,0 +1,195 @@
+use crate::bounce::{Bounce, BounceConfig, BounceEngine};
+use crate::calibration::Calibration;
+use crate::capture::Capture;
+use crate::orientation::OrientationFilter;
+use crate::sample::AccelSample;
+
+/*
+    replay of recorded sessions for regression tests
+
+    a capture is run through the same steps the app takes for every sample, calibration then the
+    orientation filter then the bounce engine, and the bounces are compared with a golden file of
+    what the sensor should send the phone for it. the magnetometer only gives the filter a heading,
+    captures without it replay the same. golden files are csv, one bounce per line
+
+        ground_contact_time_ms,height_mm
+
+    when an algorithim change is meant to change the output, regenerate the golden files and review
+    the diff like any other change.
+*/
+
+/// a bounce as the phone sees it, contact time in ms and height in mm. the first jump out of a
+/// standing start has no contact time and is sent as 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Measurement {
+    pub ground_contact_time: u32,
+    pub height: u32,
+}
+
+impl From<&Bounce> for Measurement {
+    fn from(bounce: &Bounce) -> Self {
+        Measurement {
+            ground_contact_time: bounce.ground_contact_ms(),
+            height: bounce.height_mm(),
+        }
+    }
+}
+
+/// how far a measurement may drift from the golden one before it is a regression
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tolerance {
+    /// ms
+    pub ground_contact_time: u32,
+    /// mm
+    pub height: u32,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance {
+            // one sample at 100 Hz
+            ground_contact_time: 10,
+            height: 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mismatch {
+    Count {
+        expected: usize,
+        actual: usize,
+    },
+    Bounce {
+        index: usize,
+        expected: Measurement,
+        actual: Measurement,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GoldenError {
+    /// line of the golden file the error is on, counting from 1
+    pub line: usize,
+}
+
+/// runs a capture through calibration, the orientation filter and the bounce engine like the app
+pub fn replay(
+    capture: &Capture,
+    config: BounceConfig,
+    calibration: &Calibration,
+) -> Vec<Measurement> {
+    let mut engine = BounceEngine::new(config);
+    let mut orientation = OrientationFilter::default();
+    let mut measurements = Vec::new();
+    for sample in &capture.samples {
+        let accel = AccelSample::new(sample.timestamp, calibration.accel.apply(sample.accel));
+        orientation.push(&accel);
+        if let Some(mag) = sample.mag {
+            orientation.push_mag(sample.timestamp, calibration.mag.apply(mag));
+        }
+        if let Some(bounce) = engine.push_oriented(accel, orientation.up()) {
+            measurements.push(Measurement::from(&bounce));
+        }
+    }
+    measurements
+}
+
+pub fn parse_golden(csv: &str) -> Result<Vec<Measurement>, GoldenError> {
+    csv.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim().starts_with('#'))
+        .map(|(index, line)| {
+            let error = GoldenError { line: index + 1 };
+            let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+            match fields[..] {
+                [contact, height] => Ok(Measurement {
+                    ground_contact_time: contact.parse().map_err(|_| error)?,
+                    height: height.parse().map_err(|_| error)?,
+                }),
+                _ => Err(error),
+            }
+        })
+        .collect()
+}
+
+pub fn to_golden(measurements: &[Measurement]) -> String {
+    let mut csv = String::from("# ground_contact_time_ms,height_mm\n");
+    for m in measurements {
+        csv.push_str(&format!("{},{}\n", m.ground_contact_time, m.height));
+    }
+    csv
+}
+
+/// every difference between the replayed measurements and the golden ones
+pub fn compare(
+    actual: &[Measurement],
+    golden: &[Measurement],
+    tolerance: Tolerance,
+) -> Vec<Mismatch> {
+    if actual.len() != golden.len() {
+        return vec![Mismatch::Count {
+            expected: golden.len(),
+            actual: actual.len(),
+        }];
+    }
+
+    actual
+        .iter()
+        .zip(golden)
+        .enumerate()
+        .filter(|(_, (a, g))| {
+            a.ground_contact_time.abs_diff(g.ground_contact_time) > tolerance.ground_contact_time
+                || a.height.abs_diff(g.height) > tolerance.height
+        })
+        .map(|(index, (a, g))| Mismatch::Bounce {
+            index,
+            expected: *g,
+            actual: *a,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(ground_contact_time: u32, height: u32) -> Measurement {
+        Measurement {
+            ground_contact_time,
+            height,
+        }
+    }
+
+    #[test]
+    fn golden_round_trip() {
+        let measurements = vec![measurement(0, 281), measurement(212, 224)];
+
+        assert_eq!(parse_golden(&to_golden(&measurements)), Ok(measurements));
+        assert_eq!(parse_golden("1,2\n3\n"), Err(GoldenError { line: 2 }));
+    }
+
+    #[test]
+    fn compares_within_tolerance() {
+        let golden = vec![measurement(0, 281), measurement(212, 224)];
+        let close = vec![measurement(0, 285), measurement(220, 224)];
+        let off = vec![measurement(0, 281), measurement(212, 240)];
+
+        assert!(compare(&close, &golden, Tolerance::default()).is_empty());
+        assert_eq!(
+            compare(&off, &golden, Tolerance::default()),
+            vec![Mismatch::Bounce {
+                index: 1,
+                expected: golden[1],
+                actual: off[1]
+            }]
+        );
+        assert_eq!(
+            compare(&golden[..1], &golden, Tolerance::default()),
+            vec![Mismatch::Count {
+                expected: 2,
+                actual: 1
+            }]
+        );
+    }
+}
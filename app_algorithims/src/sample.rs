@@ -0,0 +1,77 @@
+use std::ops::{Add, Mul, Sub};
+
+/// standard gravity in m/s^2
+pub const STANDARD_GRAVITY: f32 = 9.806_65;
+
+/// one g expressed in the milli g units the accelerometer reports in
+pub const ONE_G: f32 = 1000.0;
+
+/// a three axis reading, the units depend on the sensor it came from
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Vector3 {
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Vector3 { x, y, z }
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn dot(&self, other: &Vector3) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+}
+
+impl From<(i32, i32, i32)> for Vector3 {
+    fn from((x, y, z): (i32, i32, i32)) -> Self {
+        Vector3::new(x as f32, y as f32, z as f32)
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, rhs: Vector3) -> Vector3 {
+        Vector3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, rhs: Vector3) -> Vector3 {
+        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Mul<f32> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: f32) -> Vector3 {
+        Vector3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+/// an accelerometer reading in milli g and the time it was taken at in micro seconds
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AccelSample {
+    pub timestamp: i64,
+    pub accel: Vector3,
+}
+
+impl AccelSample {
+    pub fn new(timestamp: i64, accel: Vector3) -> Self {
+        AccelSample { timestamp, accel }
+    }
+
+    /// magnitude of the acceleration in milli g, this does not depend on how the sensor is mounted
+    pub fn magnitude(&self) -> f32 {
+        self.accel.magnitude()
+    }
+}
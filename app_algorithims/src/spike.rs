@@ -0,0 +1,155 @@
+use crate::sample::AccelSample;
+
+/*
+    spike start trigger
+
+    once a set is armed the athlete starts it by moving, the first big acceleration spike (the push
+    off of the first jump) starts the recording. spikes inside the refractory period after arming
+    are ignored so picking the sensor up or pressing start on the phone does not start the set, and
+    the signal has to settle under the release threshold before a spike counts.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpikeConfig {
+    /// acceleration magnitude in milli g that triggers the start
+    pub threshold: f32,
+    /// magnitude in milli g the signal has to be under before a spike can trigger
+    pub release_threshold: f32,
+    /// micro seconds after arming during which spikes are ignored
+    pub refractory_period: i64,
+}
+
+impl Default for SpikeConfig {
+    fn default() -> Self {
+        SpikeConfig {
+            threshold: 2000.0,
+            release_threshold: 1500.0,
+            refractory_period: 500_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    Disarmed,
+    // armed at the timestamp of the first sample seen after arming
+    Armed { since: Option<i64>, released: bool },
+}
+
+pub struct SpikeTrigger {
+    config: SpikeConfig,
+    state: State,
+}
+
+impl SpikeTrigger {
+    pub fn new(config: SpikeConfig) -> Self {
+        SpikeTrigger {
+            config,
+            state: State::Disarmed,
+        }
+    }
+
+    pub fn config(&self) -> &SpikeConfig {
+        &self.config
+    }
+
+    /// start looking for a spike, the refractory period starts with the next sample
+    pub fn arm(&mut self) {
+        self.state = State::Armed {
+            since: None,
+            released: false,
+        };
+    }
+
+    pub fn disarm(&mut self) {
+        self.state = State::Disarmed;
+    }
+
+    pub fn is_armed(&self) -> bool {
+        matches!(self.state, State::Armed { .. })
+    }
+
+    /// feed the next sample in, returns the time of the spike when it triggers. the trigger disarms
+    /// itself once it has fired.
+    pub fn push(&mut self, sample: AccelSample) -> Option<i64> {
+        let State::Armed { since, released } = self.state else {
+            return None;
+        };
+
+        let since = since.unwrap_or(sample.timestamp);
+        let magnitude = sample.magnitude();
+        let released = released || magnitude < self.config.release_threshold;
+
+        if sample.timestamp - since < self.config.refractory_period {
+            self.state = State::Armed {
+                since: Some(since),
+                released: false,
+            };
+            return None;
+        }
+
+        if released && magnitude >= self.config.threshold {
+            self.state = State::Disarmed;
+            return Some(sample.timestamp);
+        }
+
+        self.state = State::Armed {
+            since: Some(since),
+            released,
+        };
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sample::Vector3;
+
+    fn sample(millis: i64, magnitude: f32) -> AccelSample {
+        AccelSample::new(millis * 1000, Vector3::new(magnitude, 0.0, 0.0))
+    }
+
+    #[test]
+    fn triggers_on_spike() {
+        let mut trigger = SpikeTrigger::new(SpikeConfig::default());
+        trigger.arm();
+
+        assert_eq!(trigger.push(sample(0, 1000.0)), None);
+        assert_eq!(trigger.push(sample(600, 1000.0)), None);
+        assert_eq!(trigger.push(sample(610, 2500.0)), Some(610_000));
+        assert!(!trigger.is_armed());
+        assert_eq!(trigger.push(sample(620, 2500.0)), None);
+    }
+
+    #[test]
+    fn ignores_spikes_in_refractory_period() {
+        let mut trigger = SpikeTrigger::new(SpikeConfig::default());
+        trigger.arm();
+
+        assert_eq!(trigger.push(sample(0, 1000.0)), None);
+        assert_eq!(trigger.push(sample(100, 3000.0)), None);
+        assert_eq!(trigger.push(sample(499, 3000.0)), None);
+        assert!(trigger.is_armed());
+    }
+
+    #[test]
+    fn waits_for_release() {
+        let mut trigger = SpikeTrigger::new(SpikeConfig::default());
+        trigger.arm();
+
+        // still spiking when the refractory period ends, so it has to settle first
+        assert_eq!(trigger.push(sample(0, 3000.0)), None);
+        assert_eq!(trigger.push(sample(500, 3000.0)), None);
+        assert_eq!(trigger.push(sample(510, 1000.0)), None);
+        assert_eq!(trigger.push(sample(520, 3000.0)), Some(520_000));
+    }
+
+    #[test]
+    fn does_nothing_when_disarmed() {
+        let mut trigger = SpikeTrigger::new(SpikeConfig::default());
+
+        assert_eq!(trigger.push(sample(1000, 1000.0)), None);
+        assert_eq!(trigger.push(sample(2000, 3000.0)), None);
+    }
+}
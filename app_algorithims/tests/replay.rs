@@ -0,0 +1,87 @@
+// replays every capture in tests/fixtures that has a golden file next to it, e.g.
+// rebound_jumps.csv and rebound_jumps.golden.csv, once for every height model. the takeoff velocity
+// heights are compared with rebound_jumps.takeoff_velocity.golden.csv. run with UPDATE_GOLDEN=1 to
+// regenerate the golden files after an intended change to the output.
+
+use std::{env, fs, path::PathBuf};
+
+use app_algorithims::{
+    bounce::BounceConfig,
+    calibration::Calibration,
+    capture::Capture,
+    height::HeightModel,
+    replay::{compare, parse_golden, replay, to_golden, Tolerance},
+};
+
+fn fixtures() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+fn load(path: &PathBuf) -> Capture {
+    let capture = match path.extension().and_then(|e| e.to_str()) {
+        Some("bin") => Capture::from_bytes(&fs::read(path).unwrap()),
+        _ => Capture::from_csv(&fs::read_to_string(path).unwrap()),
+    };
+    capture.unwrap_or_else(|err| panic!("{}: {:?}", path.display(), err))
+}
+
+fn match_golden_files(height_model: HeightModel, suffix: &str) {
+    let update = env::var_os("UPDATE_GOLDEN").is_some();
+    let mut replayed = 0;
+    let mut failures = Vec::new();
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(fixtures())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let name = path.file_name().unwrap().to_str().unwrap();
+        let Some(stem) = name
+            .strip_suffix(".csv")
+            .or_else(|| name.strip_suffix(".bin"))
+        else {
+            continue;
+        };
+        // the flight time golden file marks a capture as replayed, every height model is checked
+        if stem.contains('.') || !fixtures().join(format!("{}.golden.csv", stem)).exists() {
+            continue;
+        }
+        let golden_path = fixtures().join(format!("{}{}.golden.csv", stem, suffix));
+
+        let config = BounceConfig {
+            height_model,
+            ..Default::default()
+        };
+        let measurements = replay(&load(&path), config, &Calibration::default());
+        replayed += 1;
+
+        if update {
+            fs::write(&golden_path, to_golden(&measurements)).unwrap();
+            continue;
+        }
+        let Ok(golden) = fs::read_to_string(&golden_path) else {
+            failures.push(format!("{}: no {}", name, golden_path.display()));
+            continue;
+        };
+        let golden = parse_golden(&golden).unwrap();
+        let mismatches = compare(&measurements, &golden, Tolerance::default());
+        if !mismatches.is_empty() {
+            failures.push(format!("{}: {:?}", name, mismatches));
+        }
+    }
+
+    assert!(replayed >= 2, "no captures found in {:?}", fixtures());
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn flight_time_heights_match_golden_files() {
+    match_golden_files(HeightModel::FlightTime, "");
+}
+
+#[test]
+fn takeoff_velocity_heights_match_golden_files() {
+    match_golden_files(HeightModel::TakeoffVelocity, ".takeoff_velocity");
+}
@@ -0,0 +1,460 @@
+use std::{
+    collections::VecDeque,
+    io,
+    time::{Duration, Instant},
+};
+
+use app::ota::{
+    signature::SIGNATURE_SIZE,
+    update::{self, OtaRequest, PROGRESS_INTERVAL},
+};
+use app::rust_proto::algorithim;
+use app::transport::{
+    chunking::Reassembler,
+    framing,
+    gatt::{self, Characteristic},
+    link::Packet,
+};
+use protobuf::Message;
+
+use crate::transport::Transport;
+
+/*
+    client side of the sensor protocol
+
+    messages go to the sensor framed on byte in and come back chunked and framed on byte out. every
+    message gets a request id and the sensor answers each with a Response carrying it, anything else
+    the sensor sends in the meantime (reps, status, samples) is handed over as it arrives.
+
+    an ota update writes the image to the ota data characteristic between a request carrying its
+    size, digest and signature and a done on ota control, the sensor acks both, then a write to
+    apply boots the new image. the image goes in windows of PROGRESS_INTERVAL chunks, each one
+    waiting for the sensor to notify where it expects the image from next, so a missing chunk is
+    sent again. the request ack says where to start, so an update that was interrupted carries on
+    where it stopped.
+*/
+
+/// bytes of the image per ota data write, with the sequence number fits one write at the 247 byte
+/// mtu phones negotiate
+pub const DEFAULT_OTA_PACKET_SIZE: u16 = 240;
+
+#[derive(Debug)]
+pub enum ClientError {
+    Io(io::Error),
+    /// nothing the client waited for arrived in time
+    Timeout,
+    /// the sensor answered an ota step with something other than its ack
+    Ota {
+        step: &'static str,
+        value: Vec<u8>,
+    },
+}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        ClientError::Io(err)
+    }
+}
+
+pub struct Client<T: Transport> {
+    transport: T,
+    timeout: Duration,
+    reassembler: Reassembler,
+    // messages that arrived while waiting for something else
+    received: VecDeque<algorithim::Message>,
+    // frames that did not decode or parse
+    dropped: usize,
+    next_request_id: u32,
+}
+
+impl<T: Transport> Client<T> {
+    /// timeout is how long to wait for each answer from the sensor
+    pub fn new(transport: T, timeout: Duration) -> Self {
+        Client {
+            transport,
+            timeout,
+            reassembler: Reassembler::new(),
+            received: VecDeque::new(),
+            dropped: 0,
+            next_request_id: 1,
+        }
+    }
+
+    /// frames from the sensor that were dropped, plus the chunks that never arrived
+    pub fn dropped(&self) -> usize {
+        self.dropped + self.reassembler.lost_chunks()
+    }
+
+    /// sends a message, a request id is picked when it has none. returns the request id
+    pub fn send(&mut self, mut message: algorithim::Message) -> Result<u32, ClientError> {
+        if message.requestId == 0 {
+            message.requestId = self.next_request_id;
+            self.next_request_id = self.next_request_id.wrapping_add(1).max(1);
+        }
+        let bytes = message.write_to_bytes().map_err(io::Error::other)?;
+        self.transport
+            .write(Characteristic::ByteIn, &framing::encode(&bytes))?;
+        Ok(message.requestId)
+    }
+
+    /// sends a message and waits for its response, everything else that arrives in the meantime
+    /// goes to on_message
+    pub fn request<F>(
+        &mut self,
+        message: algorithim::Message,
+        mut on_message: F,
+    ) -> Result<algorithim::Response, ClientError>
+    where
+        F: FnMut(&algorithim::Message),
+    {
+        let id = self.send(message)?;
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            let message = self.receive_until(deadline)?.ok_or(ClientError::Timeout)?;
+            match message.content {
+                Some(algorithim::message::Content::Response(response))
+                    if response.requestId == id =>
+                {
+                    return Ok(response)
+                }
+                _ => on_message(&message),
+            }
+        }
+    }
+
+    /// the next message from the sensor, None when nothing arrived within the timeout
+    pub fn receive(&mut self) -> Result<Option<algorithim::Message>, ClientError> {
+        self.receive_until(Instant::now() + self.timeout)
+    }
+
+    fn receive_until(
+        &mut self,
+        deadline: Instant,
+    ) -> Result<Option<algorithim::Message>, ClientError> {
+        loop {
+            if let Some(message) = self.received.pop_front() {
+                return Ok(Some(message));
+            }
+            // anything other than byte out is left over from an ota step and not waited for
+            if self.notification(deadline)?.is_none() {
+                return Ok(None);
+            }
+        }
+    }
+
+    // waits for the next notification up to deadline, byte out notifications are decoded into
+    // received and returned as well
+    fn notification(&mut self, deadline: Instant) -> Result<Option<Packet>, ClientError> {
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        let Some(packet) = self.transport.notification(timeout)? else {
+            return Ok(None);
+        };
+        if packet.characteristic == Characteristic::ByteOut {
+            for frame in self.reassembler.push(&packet.value) {
+                match frame
+                    .ok()
+                    .and_then(|frame| algorithim::Message::parse_from_bytes(&frame).ok())
+                {
+                    Some(message) => self.received.push_back(message),
+                    None => self.dropped += 1,
+                }
+            }
+        }
+        Ok(Some(packet))
+    }
+
+    // waits for a notification on characteristic
+    fn wait_for(&mut self, characteristic: Characteristic) -> Result<Vec<u8>, ClientError> {
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            match self.notification(deadline)? {
+                Some(packet) if packet.characteristic == characteristic => return Ok(packet.value),
+                Some(_) => continue,
+                None => return Err(ClientError::Timeout),
+            }
+        }
+    }
+
+    // sends value to ota control and checks the sensor acks it
+    fn ota_control(
+        &mut self,
+        step: &'static str,
+        value: &[u8],
+        ack: u8,
+    ) -> Result<(), ClientError> {
+        self.transport.write(Characteristic::OtaControl, value)?;
+        match self.wait_for(Characteristic::OtaControl)? {
+            value if value.first() == Some(&ack) => Ok(()),
+            value => Err(ClientError::Ota { step, value }),
+        }
+    }
+
+    // sends the ota request, returns the offset the sensor carries on from
+    fn ota_request(&mut self, request: &OtaRequest) -> Result<usize, ClientError> {
+        self.transport
+            .write(Characteristic::OtaControl, &request.to_bytes())?;
+        loop {
+            let value = self.wait_for(Characteristic::OtaControl)?;
+            match (value.first(), update::next_offset(&value)) {
+                // left over from before the request
+                (Some(&gatt::OTA_CONTROL_PROGRESS), _) => continue,
+                (Some(&gatt::OTA_CONTROL_REQUEST_ACK), Some(offset)) => return Ok(offset as usize),
+                _ => {
+                    return Err(ClientError::Ota {
+                        step: "request",
+                        value,
+                    })
+                }
+            }
+        }
+    }
+
+    // waits for the sensor to say where it expects the image from next
+    fn ota_progress(&mut self) -> Result<usize, ClientError> {
+        let value = self.wait_for(Characteristic::OtaControl)?;
+        match (value.first(), update::next_offset(&value)) {
+            (Some(&gatt::OTA_CONTROL_PROGRESS), Some(offset)) => Ok(offset as usize),
+            _ => Err(ClientError::Ota {
+                step: "data",
+                value,
+            }),
+        }
+    }
+
+    /// writes a firmware image to the sensor and boots it, progress gets the bytes written so far.
+    /// see app::ota::signature for signing images
+    pub fn ota<F>(
+        &mut self,
+        image: &[u8],
+        signature: [u8; SIGNATURE_SIZE],
+        packet_size: u16,
+        mut progress: F,
+    ) -> Result<(), ClientError>
+    where
+        F: FnMut(usize),
+    {
+        let packet_size = packet_size.max(1);
+        let request = OtaRequest::for_image(image, signature, packet_size);
+        let packet_size = packet_size as usize;
+        // chunks are numbered from start, where the image was at the request
+        let mut start = self.ota_request(&request)?;
+        let mut offset = start;
+        while offset < image.len() {
+            let first = ((offset - start) / packet_size) as u32;
+            // up to the next progress notification
+            let last = (first / PROGRESS_INTERVAL + 1) * PROGRESS_INTERVAL;
+            for sequence in first..last {
+                let at = start + sequence as usize * packet_size;
+                if at >= image.len() {
+                    break;
+                }
+                let packet = &image[at..image.len().min(at + packet_size)];
+                self.transport
+                    .write(Characteristic::OtaData, &update::chunk(sequence, packet))?;
+                progress(at + packet.len());
+            }
+            offset = match self.ota_progress() {
+                Ok(offset) => offset,
+                // the end of the window went missing, asking again says where the sensor is at
+                Err(ClientError::Timeout) => {
+                    start = self.ota_request(&request)?;
+                    start
+                }
+                Err(err) => return Err(err),
+            };
+        }
+
+        self.ota_control(
+            "done",
+            &[gatt::OTA_CONTROL_DONE],
+            gatt::OTA_CONTROL_DONE_ACK,
+        )?;
+
+        self.transport.write(Characteristic::OtaApply, &[1])?;
+        match self.wait_for(Characteristic::OtaApply)? {
+            value if value.first() == Some(&gatt::UPDATE_SUCCESS) => Ok(()),
+            value => Err(ClientError::Ota {
+                step: "apply",
+                value,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::StreamTransport;
+    use app::app::app::App;
+    use app::ota::signature::{self, SEED_SIZE};
+    use app::ota::update::{MemoryTarget, NakReason, OtaUpdate};
+    use app::transport::{
+        chunking::Chunker,
+        duplex::{self, ByteStream},
+        link::LinkDecoder,
+    };
+    use std::{
+        io::{Read, Write},
+        os::unix::net::UnixStream,
+        thread,
+    };
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+    // the sensor is built with its public key
+    const SEED: [u8; SEED_SIZE] = [9; SEED_SIZE];
+
+    fn ota(
+        client: &mut Client<StreamTransport>,
+        image: &[u8],
+        progress: impl FnMut(usize),
+    ) -> Result<(), ClientError> {
+        let signed = signature::sign(image, &SEED);
+        let (image, signature) = signature::split_signed(&signed).unwrap();
+        client.ota(image, signature, 300, progress)
+    }
+
+    // a sensor on the other end of a socket, messages go through a real App and the ota steps
+    // through a real OtaUpdate
+    fn sensor() -> Client<StreamTransport> {
+        let (ours, mut theirs) = UnixStream::pair().unwrap();
+        thread::spawn(move || {
+            let (link, mut phone) = duplex::pipe(duplex::DEFAULT_CAPACITY);
+            let mut app = App::new(Box::new(link));
+            let mut decoder = LinkDecoder::new();
+            let mut chunker = Chunker::new();
+            let mut ota = OtaUpdate::new(MemoryTarget::new(4096), signature::public_key(&SEED));
+            let mut buffer = [0u8; 1024];
+            let mut output = [0u8; 4096];
+            while let Ok(len @ 1..) = theirs.read(&mut buffer) {
+                for packet in decoder.push(&buffer[..len]) {
+                    let reply = match packet.characteristic {
+                        Characteristic::ByteIn => {
+                            app.handle_bytes(&packet.value);
+                            let len = phone.read_timeout(&mut output, Duration::ZERO).unwrap();
+                            chunker
+                                .chunk(&output[..len.unwrap_or(0)], 185)
+                                .iter()
+                                .map(|chunk| Packet::new(Characteristic::ByteOut, chunk))
+                                .collect()
+                        }
+                        Characteristic::OtaData => ota
+                            .data(&packet.value)
+                            .map(|value| Packet::new(Characteristic::OtaControl, &value))
+                            .into_iter()
+                            .collect(),
+                        Characteristic::OtaControl => ota
+                            .control(&packet.value)
+                            .map(|value| Packet::new(Characteristic::OtaControl, &value))
+                            .into_iter()
+                            .collect(),
+                        _ => vec![Packet::new(Characteristic::OtaApply, &[ota.apply()])],
+                    };
+                    for packet in reply {
+                        theirs.write_all(&packet.encode()).unwrap();
+                    }
+                }
+            }
+        });
+        Client::new(
+            StreamTransport::new(ours.try_clone().unwrap(), ours),
+            TIMEOUT,
+        )
+    }
+
+    #[test]
+    fn requests_get_their_response() {
+        let mut client = sensor();
+        let mut conf = algorithim::AlgorithimConfiguration::new();
+        conf.set_spikeStart(algorithim::SpikeStart::new());
+        let mut message = algorithim::Message::new();
+        message.set_Algorithim(conf);
+
+        let first = client.request(message.clone(), |_| ()).unwrap();
+        let second = client.request(message, |_| ()).unwrap();
+
+        assert!(first.success);
+        assert_eq!((first.requestId, second.requestId), (1, 2));
+    }
+
+    #[test]
+    fn other_messages_are_passed_on() {
+        let mut client = sensor();
+        let mut calibrate = algorithim::Calibrate::new();
+        calibrate.step = algorithim::CalibrationStep::CALIBRATION_STATUS.into();
+        let mut message = algorithim::Message::new();
+        message.set_calibrate(calibrate);
+
+        let mut others = Vec::new();
+        let response = client
+            .request(message, |message| others.push(message.clone()))
+            .unwrap();
+
+        assert!(response.success);
+        assert_eq!(others.len(), 1);
+        assert!(others[0].has_calibrationStatus());
+        assert_eq!(client.dropped(), 0);
+    }
+
+    #[test]
+    fn updates_firmware() {
+        let mut client = sensor();
+        let image: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+
+        let mut progress = Vec::new();
+        ota(&mut client, &image, |written| progress.push(written)).unwrap();
+
+        assert_eq!(progress, vec![300, 600, 900, 1000]);
+    }
+
+    #[test]
+    fn resumes_an_interrupted_update() {
+        let mut client = sensor();
+        let image: Vec<u8> = (0..4000).map(|i| (i % 251) as u8).collect();
+        let signed = signature::sign(&image, &SEED);
+        let (image, signature) = signature::split_signed(&signed).unwrap();
+        // an earlier attempt got the first chunk across
+        let request = OtaRequest::for_image(image, signature, 100);
+        assert_eq!(client.ota_request(&request).unwrap(), 0);
+        client
+            .transport
+            .write(Characteristic::OtaData, &update::chunk(0, &image[..100]))
+            .unwrap();
+
+        let mut progress = Vec::new();
+        client
+            .ota(image, signature, 100, |written| progress.push(written))
+            .unwrap();
+
+        assert_eq!(progress.first(), Some(&200));
+        assert_eq!(progress.last(), Some(&4000));
+        assert_eq!(progress.len(), 39);
+    }
+
+    #[test]
+    fn images_signed_with_another_key_are_not_applied() {
+        let mut client = sensor();
+        let signed = signature::sign(&[1; 100], &[1; SEED_SIZE]);
+        let (image, signature) = signature::split_signed(&signed).unwrap();
+
+        let result = client.ota(image, signature, 300, |_| ());
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Ota { step: "done", value })
+                if value == [gatt::OTA_CONTROL_DONE_NAK, NakReason::BadSignature as u8]
+        ));
+    }
+
+    #[test]
+    fn reports_the_failed_ota_step() {
+        let mut client = sensor();
+
+        let result = ota(&mut client, &[0; 5000], |_| ());
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Ota { step: "request", value })
+                if value == [gatt::OTA_CONTROL_REQUEST_NAK, NakReason::TooLarge as u8]
+        ));
+    }
+}
@@ -0,0 +1,315 @@
+use std::{io::Read, process::ExitCode, time::Duration};
+
+use app::ota::signature::{self, SEED_SIZE};
+use app::ota::update::NakReason;
+use app::rust_proto::algorithim;
+use app::transport::framing::{self, FrameDecoder};
+use protobuf::{text_format, Message};
+
+use crate::client::{Client, ClientError, DEFAULT_OTA_PACKET_SIZE};
+use crate::transport::{Address, StreamTransport};
+
+mod client;
+mod transport;
+
+/*
+    sensor-cli talks to a sensor from a linux host
+
+    messages are written and printed in protobuf text format, e.g. for a configuration
+
+        sensor-cli configure 'spikeStart { threshold: 1800 } counted { counts: 5 }'
+
+    see the usage below for the rest. the sensor is reached over the link format on a tcp or unix
+    socket or on stdin and stdout, which is what the simulator serves.
+
+    firmware images have to be signed before the sensor takes them, keygen makes the key pair the
+    firmware is built with and sign signs an esp.bin from espflash save-image, see
+    app::ota::signature.
+*/
+
+const USAGE: &str = "usage: sensor-cli [--connect <address>] [--timeout <seconds>] <command>
+
+commands
+    encode <message>            print the frame of a Message as hex
+    decode <hex>                print the Messages in hex encoded frames
+    send <message>              send a Message, print what comes back until its Response
+    configure <configuration>   send an AlgorithimConfiguration
+    listen [reps]               print what the sensor sends, stop after reps reps
+    ota <signed image> [packet size]
+                                write a signed firmware image to the sensor and boot it
+    keygen <key>                write a new signing key to key and its public key to key.pub
+    sign <key> <image> [signed image]
+                                sign a firmware image, the default output is image.signed
+
+messages are in protobuf text format
+addresses are tcp:<host>:<port>, unix:<path> or stdio, the default is tcp:127.0.0.1:7878";
+
+struct Options {
+    address: Address,
+    timeout: Duration,
+    command: Vec<String>,
+}
+
+fn parse_options(args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        address: Address::default(),
+        timeout: Duration::from_secs(5),
+        command: Vec::new(),
+    };
+    let mut args = args.peekable();
+    while let Some(arg) = args.next_if(|arg| arg.starts_with("--")) {
+        let value = args.next().ok_or(format!("{} needs a value", arg))?;
+        match arg.as_str() {
+            "--connect" => options.address = value.parse()?,
+            "--timeout" => {
+                options.timeout = value
+                    .parse()
+                    .ok()
+                    .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                    .ok_or(format!("bad timeout {}", value))?;
+            }
+            _ => return Err(format!("unknown option {}", arg)),
+        }
+    }
+    options.command = args.collect();
+    Ok(options)
+}
+
+fn parse_message<M: protobuf::MessageFull>(text: &str) -> Result<M, String> {
+    text_format::parse_from_str(text).map_err(|err| format!("{}: {}", M::NAME, err))
+}
+
+fn print_message(message: &algorithim::Message) {
+    println!("{}", text_format::print_to_string(message));
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let hex: Vec<char> = hex.chars().filter(|c| !c.is_whitespace()).collect();
+    if !hex.len().is_multiple_of(2) {
+        return Err(format!("odd number of hex digits {}", hex.len()));
+    }
+    hex.chunks(2)
+        .map(|pair| {
+            let pair: String = pair.iter().collect();
+            u8::from_str_radix(&pair, 16).map_err(|_| format!("bad hex {}", pair))
+        })
+        .collect()
+}
+
+fn connect(options: &Options) -> Result<Client<StreamTransport>, String> {
+    StreamTransport::connect(&options.address)
+        .map(|transport| Client::new(transport, options.timeout))
+        .map_err(|err| format!("error connecting to {:?}: {}", options.address, err))
+}
+
+fn client_error(err: ClientError) -> String {
+    match err {
+        ClientError::Io(err) => format!("link error: {}", err),
+        ClientError::Timeout => "timed out waiting for the sensor".to_string(),
+        ClientError::Ota { step, value } => match value.get(1).copied().map(NakReason::from_code) {
+            Some(Some(reason)) => format!("ota {} rejected, {:?}", step, reason),
+            _ => format!("ota {} rejected with {:?}", step, value),
+        },
+    }
+}
+
+// sends message and prints everything up to and including its response, fails if it was rejected
+fn request(options: &Options, message: algorithim::Message) -> Result<(), String> {
+    let mut client = connect(options)?;
+    let response = client
+        .request(message, print_message)
+        .map_err(client_error)?;
+    let mut message = algorithim::Message::new();
+    message.set_response(response.clone());
+    print_message(&message);
+    match response.success {
+        true => Ok(()),
+        false => Err(format!("rejected with {:?}", response.error)),
+    }
+}
+
+fn listen(options: &Options, reps: Option<usize>) -> Result<(), String> {
+    let mut client = connect(options)?;
+    let mut received = 0;
+    while reps.is_none_or(|reps| received < reps) {
+        if let Some(message) = client.receive().map_err(client_error)? {
+            received += message.has_rep() as usize;
+            print_message(&message);
+        }
+    }
+    if client.dropped() > 0 {
+        eprintln!("{} frames were lost or corrupt", client.dropped());
+    }
+    Ok(())
+}
+
+fn read_file(path: &str) -> Result<Vec<u8>, String> {
+    std::fs::read(path).map_err(|err| format!("error reading {}: {}", path, err))
+}
+
+fn write_file(path: &str, bytes: &[u8]) -> Result<(), String> {
+    std::fs::write(path, bytes).map_err(|err| format!("error writing {}: {}", path, err))
+}
+
+fn read_key(path: &str) -> Result<[u8; SEED_SIZE], String> {
+    read_file(path)?
+        .try_into()
+        .map_err(|_| format!("{} is not a {} byte signing key", path, SEED_SIZE))
+}
+
+fn keygen(path: &str) -> Result<(), String> {
+    let mut seed = [0u8; SEED_SIZE];
+    std::fs::File::open("/dev/urandom")
+        .and_then(|mut random| random.read_exact(&mut seed))
+        .map_err(|err| format!("error reading /dev/urandom: {}", err))?;
+    write_file(path, &seed)?;
+    write_file(&format!("{}.pub", path), &signature::public_key(&seed))?;
+    eprintln!(
+        "build the firmware with {}.pub and keep {} private",
+        path, path
+    );
+    Ok(())
+}
+
+fn sign(key: &str, image: &str, output: &str) -> Result<(), String> {
+    let seed = read_key(key)?;
+    let image = read_file(image)?;
+    if signature::split_signed(&image).is_some() {
+        return Err("the image is already signed".to_string());
+    }
+    write_file(output, &signature::sign(&image, &seed))
+}
+
+fn ota(options: &Options, image: &str, packet_size: u16) -> Result<(), String> {
+    let signed = read_file(image)?;
+    let (image, signature) = signature::split_signed(&signed)
+        .ok_or(format!("{} is not signed, see sensor-cli sign", image))?;
+    let mut client = connect(options)?;
+    let mut last_percent = None;
+    client
+        .ota(image, signature, packet_size, |written| {
+            let percent = written * 100 / image.len();
+            if last_percent != Some(percent) {
+                eprint!("\r{}%", percent);
+                last_percent = Some(percent);
+            }
+        })
+        .map_err(client_error)?;
+    eprintln!("\nupdate applied, the sensor restarts with the new image");
+    Ok(())
+}
+
+fn run(options: Options) -> Result<(), String> {
+    let command: Vec<&str> = options.command.iter().map(|arg| arg.as_str()).collect();
+    match command[..] {
+        ["encode", message] => {
+            let message: algorithim::Message = parse_message(message)?;
+            let bytes = message.write_to_bytes().map_err(|err| err.to_string())?;
+            println!("{}", to_hex(&framing::encode(&bytes)));
+            Ok(())
+        }
+        ["decode", hex] => {
+            for frame in FrameDecoder::new().push(&from_hex(hex)?) {
+                let frame = frame.map_err(|err| format!("bad frame {:?}", err))?;
+                let message = algorithim::Message::parse_from_bytes(&frame)
+                    .map_err(|err| format!("bad message {}", err))?;
+                print_message(&message);
+            }
+            Ok(())
+        }
+        ["send", message] => request(&options, parse_message(message)?),
+        ["configure", configuration] => {
+            let mut message = algorithim::Message::new();
+            message.set_Algorithim(parse_message(configuration)?);
+            request(&options, message)
+        }
+        ["listen"] => listen(&options, None),
+        ["listen", reps] => {
+            let reps = reps
+                .parse()
+                .map_err(|_| format!("bad rep count {}", reps))?;
+            listen(&options, Some(reps))
+        }
+        ["ota", image] => ota(&options, image, DEFAULT_OTA_PACKET_SIZE),
+        ["ota", image, packet_size] => {
+            let packet_size = packet_size
+                .parse()
+                .map_err(|_| format!("bad packet size {}", packet_size))?;
+            ota(&options, image, packet_size)
+        }
+        ["keygen", key] => keygen(key),
+        ["sign", key, image] => sign(key, image, &format!("{}.signed", image)),
+        ["sign", key, image, output] => sign(key, image, output),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn main() -> ExitCode {
+    match parse_options(std::env::args().skip(1)).and_then(run) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn parses_options() {
+        let options = parse_options(args(&[
+            "--connect",
+            "unix:/tmp/s",
+            "--timeout",
+            "0.5",
+            "listen",
+            "3",
+        ]))
+        .unwrap();
+
+        assert_eq!(options.address, Address::Unix("/tmp/s".to_string()));
+        assert_eq!(options.timeout, Duration::from_millis(500));
+        assert_eq!(options.command, vec!["listen", "3"]);
+        assert!(parse_options(args(&["--connect"])).is_err());
+        for timeout in ["-1", "nan", "inf", "soon"] {
+            assert!(parse_options(args(&["--timeout", timeout])).is_err());
+        }
+    }
+
+    #[test]
+    fn messages_round_trip_through_text_and_frames() {
+        let message: algorithim::Message = parse_message(
+            "requestId: 7 Algorithim { spikeStart { threshold: 1800 } counted { counts: 5 } }",
+        )
+        .unwrap();
+        let hex = to_hex(&framing::encode(&message.write_to_bytes().unwrap()));
+
+        let frames = FrameDecoder::new().push(&from_hex(&hex).unwrap());
+        let decoded = algorithim::Message::parse_from_bytes(frames[0].as_ref().unwrap()).unwrap();
+
+        assert_eq!(decoded, message);
+        assert_eq!(decoded.Algorithim().counted().counts, 5);
+        assert!(parse_message::<algorithim::Message>("nonsense { }").is_err());
+    }
+
+    #[test]
+    fn hex_needs_whole_bytes() {
+        assert_eq!(from_hex("0a ff"), Ok(vec![0x0a, 0xff]));
+        assert!(from_hex("0af").is_err());
+        assert!(from_hex("0g").is_err());
+    }
+}
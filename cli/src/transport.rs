@@ -0,0 +1,178 @@
+use std::{
+    io::{self, Read, Write},
+    net::TcpStream,
+    os::unix::net::UnixStream,
+    sync::mpsc::{channel, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use app::transport::{
+    gatt::Characteristic,
+    link::{LinkDecoder, Packet, DEFAULT_PORT},
+};
+
+/*
+    transports to a sensor
+
+    a transport writes to the sensor's characteristics and hands back its notifications, which is
+    all the client needs whether the other end is a real sensor over ble or the simulator over a
+    socket. StreamTransport speaks the link format (see app::transport::link) over anything that
+    reads and writes bytes, a tcp or unix socket or the process's stdin and stdout.
+*/
+
+pub trait Transport {
+    fn write(&mut self, characteristic: Characteristic, value: &[u8]) -> io::Result<()>;
+    /// waits up to timeout for the next notification, None when nothing arrived in time
+    fn notification(&mut self, timeout: Duration) -> io::Result<Option<Packet>>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Address {
+    Tcp(String),
+    Unix(String),
+    /// the link runs over stdin and stdout, e.g. piped to the simulator
+    Stdio,
+}
+
+impl Default for Address {
+    fn default() -> Self {
+        Address::Tcp(format!("127.0.0.1:{}", DEFAULT_PORT))
+    }
+}
+
+impl std::str::FromStr for Address {
+    type Err = String;
+
+    /// tcp:<host>:<port>, unix:<path> or stdio
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        match address.split_once(':') {
+            Some(("tcp", host)) => Ok(Address::Tcp(host.to_string())),
+            Some(("unix", path)) => Ok(Address::Unix(path.to_string())),
+            None if address == "stdio" => Ok(Address::Stdio),
+            _ => Err(format!("unknown address {}", address)),
+        }
+    }
+}
+
+pub struct StreamTransport {
+    writer: Box<dyn Write + Send>,
+    // filled by a thread reading the stream, so waiting for a notification can time out on any
+    // stream
+    notifications: Receiver<io::Result<Packet>>,
+}
+
+impl StreamTransport {
+    pub fn new<R, W>(mut reader: R, writer: W) -> Self
+    where
+        R: Read + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        let (sender, notifications) = channel();
+        thread::spawn(move || {
+            let mut decoder = LinkDecoder::new();
+            let mut buffer = [0u8; 1024];
+            loop {
+                let result = match reader.read(&mut buffer) {
+                    Ok(0) => Err(io::Error::from(io::ErrorKind::UnexpectedEof)),
+                    Ok(len) => Ok(decoder.push(&buffer[..len])),
+                    Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(err) => Err(err),
+                };
+                let sent = match result {
+                    Ok(packets) => packets.into_iter().all(|p| sender.send(Ok(p)).is_ok()),
+                    Err(err) => {
+                        let _ = sender.send(Err(err));
+                        false
+                    }
+                };
+                if !sent {
+                    return;
+                }
+            }
+        });
+
+        StreamTransport {
+            writer: Box::new(writer),
+            notifications,
+        }
+    }
+
+    pub fn connect(address: &Address) -> io::Result<Self> {
+        match address {
+            Address::Tcp(host) => {
+                let stream = TcpStream::connect(host)?;
+                stream.set_nodelay(true)?;
+                Ok(StreamTransport::new(stream.try_clone()?, stream))
+            }
+            Address::Unix(path) => {
+                let stream = UnixStream::connect(path)?;
+                Ok(StreamTransport::new(stream.try_clone()?, stream))
+            }
+            Address::Stdio => Ok(StreamTransport::new(io::stdin(), io::stdout())),
+        }
+    }
+}
+
+impl Transport for StreamTransport {
+    fn write(&mut self, characteristic: Characteristic, value: &[u8]) -> io::Result<()> {
+        self.writer
+            .write_all(&Packet::new(characteristic, value).encode())?;
+        self.writer.flush()
+    }
+
+    fn notification(&mut self, timeout: Duration) -> io::Result<Option<Packet>> {
+        match self.notifications.recv_timeout(timeout) {
+            Ok(result) => result.map(Some),
+            Err(RecvTimeoutError::Timeout) => Ok(None),
+            Err(RecvTimeoutError::Disconnected) => Err(io::ErrorKind::UnexpectedEof.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_addresses() {
+        assert_eq!(
+            "tcp:localhost:80".parse(),
+            Ok(Address::Tcp("localhost:80".to_string()))
+        );
+        assert_eq!(
+            "unix:/tmp/sensor".parse(),
+            Ok(Address::Unix("/tmp/sensor".to_string()))
+        );
+        assert_eq!("stdio".parse(), Ok(Address::Stdio));
+        assert!("ble:esp32".parse::<Address>().is_err());
+    }
+
+    #[test]
+    fn exchanges_packets() {
+        let (ours, mut theirs) = UnixStream::pair().unwrap();
+        let mut transport = StreamTransport::new(ours.try_clone().unwrap(), ours);
+
+        transport.write(Characteristic::ByteIn, b"ping").unwrap();
+        let mut written = [0u8; 7];
+        theirs.read_exact(&mut written).unwrap();
+        theirs
+            .write_all(&Packet::new(Characteristic::ByteOut, b"pong").encode())
+            .unwrap();
+
+        assert_eq!(
+            LinkDecoder::new().push(&written),
+            vec![Packet::new(Characteristic::ByteIn, b"ping")]
+        );
+        assert_eq!(
+            transport.notification(Duration::from_secs(1)).unwrap(),
+            Some(Packet::new(Characteristic::ByteOut, b"pong"))
+        );
+        assert_eq!(
+            transport.notification(Duration::from_millis(10)).unwrap(),
+            None
+        );
+        drop(theirs);
+        assert!(transport.notification(Duration::from_secs(1)).is_err());
+    }
+}
@@ -2,5 +2,30 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     embuild::build::CfgArgs::output_propagated("ESP_IDF")?;
     embuild::build::LinkArgs::output_propagated("ESP_IDF")?;
+    check_ota_public_key()?;
     Ok(())
 }
+
+// the firmware includes the public key updates are signed for, fail here with how to make one
+// rather than on the include_bytes! in ble.rs
+fn check_ota_public_key() -> Result<(), String> {
+    println!("cargo:rerun-if-env-changed=OTA_PUBLIC_KEY");
+    let path = std::env::var("OTA_PUBLIC_KEY")
+        .map_err(|_| "OTA_PUBLIC_KEY is not set, see .cargo/config.toml".to_string())?;
+    println!("cargo:rerun-if-changed={}", path);
+    match std::fs::metadata(&path) {
+        Ok(key) if key.len() == 32 => Ok(()),
+        Ok(key) => Err(format!(
+            "{} is {} bytes, an ed25519 public key is 32. make a new key pair with \
+             `cargo run --manifest-path ../cli/Cargo.toml -- keygen ota.key`",
+            path,
+            key.len()
+        )),
+        Err(_) => Err(format!(
+            "no ota public key at {}, make a key pair with \
+             `cargo run --manifest-path ../cli/Cargo.toml -- keygen ota.key` and keep ota.key \
+             private, see Readme.md",
+            path
+        )),
+    }
+}
@@ -1,48 +1,38 @@
 use std::{
-    io::Write,
-    ops::Index,
     sync::{
         self,
         mpsc::{sync_channel, Receiver, SyncSender},
         Arc,
     },
+    time::Duration,
     u8,
 };
 
+use app::ota::signature::PUBLIC_KEY_SIZE;
+use app::ota::update::{NakReason, OtaUpdate};
+use app::transport::chunking::{Chunker, DEFAULT_ATT_MTU};
+use app::transport::duplex::{ByteStream, PipeEnd};
+use app::transport::gatt::{
+    NONE_ATTEMPTED, OTA_CONTROL_DONE_ACK, OTA_CONTROL_DONE_NAK, OTA_CONTROL_REQUEST_ACK,
+    OTA_CONTROL_REQUEST_NAK, UPDATE_SUCCESS,
+};
 use esp32_nimble::{
     utilities::mutex::RawMutex, uuid128, BLECharacteristic, BLEDevice, NimbleProperties,
 };
 
-use esp_idf_sys::{
-    self as _, esp_ota_end, esp_ota_handle_t, esp_ota_set_boot_partition, esp_ota_write,
-};
+use esp_idf_sys as _;
 
-// let a = 0;
-// global update handle
-// let mut updateHandle = esp_ota_handle_t::from;
-static mut UPDATE_HANDLE: esp_ota_handle_t = 0;
-
-const NONE_ATTEMPTED: u8 = 2;
-const UPDATE_FAIL: u8 = 1;
-const UPDATE_SUCCESS: u8 = 0;
-
-const OTA_CONTROL_NOP: u8 = 0x00;
-const OTA_CONTROL_REQUEST: u8 = 0x01;
-const OTA_CONTROL_REQUEST_ACK: u8 = 0x02;
-const OTA_CONTROL_REQUEST_NAK: u8 = 0x03;
-const OTA_CONTROL_DONE: u8 = 0x04;
-const OTA_CONTROL_DONE_ACK: u8 = 0x05;
-const OTA_CONTROL_DONE_NAK: u8 = 0x06;
-
-// ota logic contains the data required for the ota update app logic
-struct OtaLogic {
-    updating: bool,
-    update_available: bool,
-    packet_size: u16,
-    packets_received: u16,
-}
+use crate::storage::ota::EspOtaTarget;
+
+// longest the ble loop waits for output from the app before checking the queued operations
+const OPERATION_INTERVAL: Duration = Duration::from_millis(20);
+
+// updates have to be signed with the private key of this public key, see app::ota::signature. the
+// path is set by OTA_PUBLIC_KEY in .cargo/config.toml
+const OTA_PUBLIC_KEY: &[u8; PUBLIC_KEY_SIZE] = include_bytes!(env!("OTA_PUBLIC_KEY"));
 
 // Application operations that can be queued
+#[derive(Debug)]
 enum BluetoothOperation {
     ApplyUpdateNoneAvailable,
     ApplyUpdateUpdateFail,
@@ -51,6 +41,7 @@ enum BluetoothOperation {
     OTAControlRequestAck,
     OTAControldoneAck,
     OTAControldoneNak,
+    OTAControlProgress,
 }
 
 struct BLEOperationMessage {
@@ -71,9 +62,12 @@ pub struct BluetoothProcessing {
     ble_op_sender: SyncSender<BLEOperationMessage>,
     ble_op_receiver: Receiver<BLEOperationMessage>,
     ble_device: Option<Arc<sync::Mutex<&'static mut BLEDevice>>>,
-    ota_logic: Arc<sync::Mutex<OtaLogic>>,
-    byte_input_stream: Arc<sync::Mutex<Vec<u8>>>,
-    byte_output_stream: Arc<sync::Mutex<Vec<u8>>>,
+    // the update the phone is writing to the ota characteristics
+    ota: Arc<sync::Mutex<OtaUpdate<EspOtaTarget>>>,
+    // the ble end of the app's link
+    link: PipeEnd,
+    // handle of the connected client, used to look up the negotiated mtu
+    conn_handle: Arc<sync::Mutex<Option<u16>>>,
 }
 
 impl BluetoothProcessing {
@@ -93,6 +87,8 @@ impl BluetoothProcessing {
         let device = Arc::clone(self.ble_device.as_ref().unwrap());
         let device_arc_a = Arc::clone(self.ble_device.as_ref().unwrap()); // reference for arc
         let device_arc_b = Arc::clone(self.ble_device.as_ref().unwrap()); // reference for arc
+        let conn_handle_connect = Arc::clone(&self.conn_handle);
+        let conn_handle_disconnect = Arc::clone(&self.conn_handle);
 
         // self.device()
         device
@@ -104,8 +100,9 @@ impl BluetoothProcessing {
             )
             .unwrap();
 
-        device.lock().unwrap().get_server().on_connect(move |_| {
+        device.lock().unwrap().get_server().on_connect(move |desc| {
             ::log::info!("Client connected");
+            *conn_handle_connect.lock().unwrap() = Some(desc.conn_handle);
             device_arc_a
                 .lock()
                 .unwrap()
@@ -116,6 +113,8 @@ impl BluetoothProcessing {
 
         device.lock().unwrap().get_server().on_disconnect(move |_| {
             ::log::info!("start advertising again, client disconnected");
+            *conn_handle_disconnect.lock().unwrap() = None;
+            // an update in progress is kept, the phone resumes it by sending the same request
 
             device_arc_b
                 .lock()
@@ -128,31 +127,33 @@ impl BluetoothProcessing {
         });
         self
     }
-    pub fn new(
-        byte_input_stream: Arc<sync::Mutex<Vec<u8>>>,
-        byte_output_stream: Arc<sync::Mutex<Vec<u8>>>,
-    ) -> Self {
-        // create ota logic
-        let ota_logic = Arc::new(sync::Mutex::new(OtaLogic {
-            update_available: false,
-            updating: false,
-            packet_size: 0,
-            packets_received: 0,
-        }));
+    pub fn new(link: PipeEnd) -> Self {
+        let ota_target = EspOtaTarget::new().expect("the partition table has no ota partition");
+        let ota = Arc::new(sync::Mutex::new(OtaUpdate::new(
+            ota_target,
+            *OTA_PUBLIC_KEY,
+        )));
 
         // create channels for Bluetooth operations
         let (sender, receiver) = sync_channel::<BLEOperationMessage>(5);
 
         BluetoothProcessing {
-            byte_input_stream,
+            link,
             ble_op_sender: sender,
             ble_op_receiver: receiver,
-            ota_logic,
+            ota,
             ble_device: None,
-            byte_output_stream,
+            conn_handle: Arc::new(sync::Mutex::new(None)),
+        }
+    }
+    // att_mtu is the mtu negotiated with the connected client
+    fn att_mtu(&self) -> usize {
+        match *self.conn_handle.lock().unwrap() {
+            Some(handle) => unsafe { esp_idf_sys::ble_att_mtu(handle) as usize },
+            None => DEFAULT_ATT_MTU,
         }
     }
-    pub fn run_ble(self) {
+    pub fn run_ble(mut self) {
         // create characteristics
         let device = Arc::clone(self.ble_device.as_ref().unwrap());
 
@@ -168,103 +169,27 @@ impl BluetoothProcessing {
             NimbleProperties::READ | NimbleProperties::WRITE | NimbleProperties::NOTIFY,
         );
 
-        let ota_logic_control_arc = Arc::clone(&self.ota_logic);
+        let ota_control = Arc::clone(&self.ota);
         let ble_op_control_sender = self.ble_op_sender.clone();
         ota_control_characteristic.lock().on_write(move |value, _| {
-            if value.is_empty() {
-                return; // return early if char value is null
-            }
-
-            match *value.index(0) {
-                OTA_CONTROL_REQUEST => {
-                    ::log::info!("we have been told to write the file");
-                    unsafe {
-                        let partition =
-                            esp_idf_sys::esp_ota_get_next_update_partition(std::ptr::null());
-                        if partition.is_null() {
-                            ::log::error!("no ota partition");
-                        }
-                        let error = esp_idf_sys::esp_ota_begin(
-                            partition,
-                            esp_idf_sys::OTA_WITH_SEQUENTIAL_WRITES as usize,
-                            &mut UPDATE_HANDLE,
-                        );
-                        match error != 0 {
-                            true => {
-                                ::log::info!("ota begin error begining, err == {:?}", error);
-                                esp_idf_sys::esp_ota_abort(UPDATE_HANDLE);
-                                ble_op_control_sender
-                                    .send(BLEOperationMessage {
-                                        operation: BluetoothOperation::OTAControlRequestNack,
-                                        data: OTA_CONTROL_REQUEST_NAK.to_be_bytes().to_vec(),
-                                    })
-                                    .unwrap();
-                            }
-
-                            false => {
-                                // set variables with update information
-                                ::log::info!("reading in packet size");
-
-                                ota_logic_control_arc.lock().unwrap().updating = true;
-                                match value.len() {
-                                    1 => {
-                                        ota_logic_control_arc.lock().unwrap().packet_size =
-                                            *value.index(0) as u16;
-                                    }
-                                    2.. => {
-                                        ota_logic_control_arc.lock().unwrap().packet_size =
-                                            ((*value.index(1) as u16) << 8)
-                                                + *value.index(0) as u16;
-                                    }
-                                    _ => {}
-                                }
-
-                                ota_logic_control_arc.lock().unwrap().packets_received = 0;
-
-                                ::log::info!("control request ack");
-
-                                // no error so ack
-                                ble_op_control_sender
-                                    .send(BLEOperationMessage {
-                                        operation: BluetoothOperation::OTAControlRequestAck,
-                                        data: OTA_CONTROL_REQUEST_ACK.to_be_bytes().to_vec(),
-                                    })
-                                    .unwrap();
-                            }
-                        }
-                    }
-                }
-                OTA_CONTROL_DONE => {
-                    ota_logic_control_arc.lock().unwrap().updating = false; // we are no longer updating
-                                                                            // end the ota
-                    let err = unsafe { esp_ota_end(UPDATE_HANDLE) };
-                    match err == 0 {
-                        true => {
-                            ota_logic_control_arc.lock().unwrap().update_available = true;
-                            ble_op_control_sender
-                                .send(BLEOperationMessage {
-                                    operation: BluetoothOperation::OTAControldoneAck,
-                                    data: OTA_CONTROL_DONE_ACK.to_be_bytes().to_vec(),
-                                })
-                                .unwrap();
-                        }
-                        false => {
-                            if err == esp_idf_sys::ESP_ERR_OTA_VALIDATE_FAILED {
-                                ::log::info!("Image validation failed, image is corrupted!")
-                            } else {
-                                ::log::info!("esp ota end failed, err = {:?}", err)
-                            }
-                            ble_op_control_sender
-                                .send(BLEOperationMessage {
-                                    operation: BluetoothOperation::OTAControldoneNak,
-                                    data: OTA_CONTROL_DONE_NAK.to_be_bytes().to_vec(),
-                                })
-                                .unwrap();
-                        }
-                    }
-                }
-                _ => {}
+            let Some(reply) = ota_control.lock().unwrap().control(value) else {
+                return; // not a control opcode
             };
+            let operation = match reply[0] {
+                OTA_CONTROL_REQUEST_ACK => BluetoothOperation::OTAControlRequestAck,
+                OTA_CONTROL_REQUEST_NAK => BluetoothOperation::OTAControlRequestNack,
+                OTA_CONTROL_DONE_ACK => BluetoothOperation::OTAControldoneAck,
+                _ => BluetoothOperation::OTAControldoneNak,
+            };
+            if let [OTA_CONTROL_REQUEST_NAK | OTA_CONTROL_DONE_NAK, reason] = reply[..] {
+                ::log::info!("ota control nak, reason {:?}", NakReason::from_code(reason));
+            }
+            ble_op_control_sender
+                .send(BLEOperationMessage {
+                    operation,
+                    data: reply,
+                })
+                .unwrap();
         });
 
         // ota data characteristic
@@ -273,20 +198,19 @@ impl BluetoothProcessing {
             NimbleProperties::READ | NimbleProperties::WRITE,
         );
 
-        // arcs and clones
-        let ota_logic_data_arc = Arc::clone(&self.ota_logic);
+        let ota_data = Arc::clone(&self.ota);
+        let ble_op_data_sender = self.ble_op_sender.clone();
         ota_data_characteristic
             .lock()
             .on_write(move |data, _connection| {
-                if ota_logic_data_arc.lock().unwrap().updating {
-                    let err = unsafe {
-                        esp_ota_write(UPDATE_HANDLE, data.as_ptr() as *const _, data.len())
-                    };
-                    if err != 0 {
-                        ::log::info!("data: {:?}, len: {:?}", data, data.len());
-                        ::log::info!("esp ota write failed: err == {:?}", err);
-                    }
-                    ota_logic_data_arc.lock().unwrap().packets_received += 1;
+                // progress is notified on ota control
+                if let Some(progress) = ota_data.lock().unwrap().data(data) {
+                    ble_op_data_sender
+                        .send(BLEOperationMessage {
+                            operation: BluetoothOperation::OTAControlProgress,
+                            data: progress,
+                        })
+                        .unwrap();
                 }
             });
 
@@ -296,47 +220,26 @@ impl BluetoothProcessing {
         );
 
         /*
-            this applies the update if any connected device writes to the char. should change to have a key to apply the update
+            this applies the update if any connected device writes to the char, but only an image
+            signed with the OTA_PUBLIC_KEY's private key gets that far
         */
-        let ota_logic_apply_update_arc = Arc::clone(&self.ota_logic);
+        let ota_apply = Arc::clone(&self.ota);
         let ble_op_apply_update_sender = self.ble_op_sender.clone();
         ota_apply_update_characteristic
             .lock()
             .on_write(move |_, _connection| {
-                match ota_logic_apply_update_arc.lock().unwrap().update_available {
-                    true => {
-                        let partition = unsafe {
-                            esp_idf_sys::esp_ota_get_next_update_partition(std::ptr::null())
-                        };
-                        let error = unsafe { esp_ota_set_boot_partition(partition) };
-                        match error == 0 {
-                            true => {
-                                ble_op_apply_update_sender
-                                    .send(BLEOperationMessage {
-                                        operation: BluetoothOperation::ApplyUpdateUpdateSuccess,
-                                        data: UPDATE_SUCCESS.to_be_bytes().to_vec(),
-                                    })
-                                    .unwrap();
-                            }
-                            false => {
-                                ble_op_apply_update_sender
-                                    .send(BLEOperationMessage {
-                                        operation: BluetoothOperation::ApplyUpdateUpdateFail,
-                                        data: UPDATE_FAIL.to_be_bytes().to_vec(),
-                                    })
-                                    .unwrap();
-                            }
-                        }
-                    }
-                    false => {
-                        ble_op_apply_update_sender
-                            .send(BLEOperationMessage {
-                                operation: BluetoothOperation::ApplyUpdateNoneAvailable,
-                                data: NONE_ATTEMPTED.to_ne_bytes().to_vec(),
-                            })
-                            .unwrap();
-                    }
-                }
+                let result = ota_apply.lock().unwrap().apply();
+                let operation = match result {
+                    UPDATE_SUCCESS => BluetoothOperation::ApplyUpdateUpdateSuccess,
+                    NONE_ATTEMPTED => BluetoothOperation::ApplyUpdateNoneAvailable,
+                    _ => BluetoothOperation::ApplyUpdateUpdateFail,
+                };
+                ble_op_apply_update_sender
+                    .send(BLEOperationMessage {
+                        operation,
+                        data: vec![result],
+                    })
+                    .unwrap();
             });
 
         /* messaging service */
@@ -357,18 +260,16 @@ impl BluetoothProcessing {
             NimbleProperties::NOTIFY | NimbleProperties::READ,
         );
 
-        // let byteWriter = Arc::clone(&self.byte_input_stream);
+        // the nimble task must not wait for the app, bytes that do not fit in the link are dropped
+        let byte_input = self.link.clone();
         byte_in_stream_characteristic
             .lock()
-            .on_write(move |data, _conn| {
-                println!("got data in ble processnig {:?}", data);
-                let err = self.byte_input_stream.lock().unwrap().write(data);
-                match err {
-                    Ok(size) => {
-                        println!("wrote {:?} bytes", size);
-                    }
-                    Err(_) => println!("error writing to byte inp stream: {:?}", err),
+            .on_write(move |data, _conn| match byte_input.try_write(data) {
+                Ok(size) if size < data.len() => {
+                    ::log::error!("link full, dropped {:?} bytes", data.len() - size)
                 }
+                Ok(_) => {}
+                Err(err) => ::log::error!("error writing to the link {:?}", err),
             });
 
         // start advertising
@@ -376,26 +277,25 @@ impl BluetoothProcessing {
         ble_advertising.name("esp32");
         ble_advertising.start().unwrap();
 
+        // splits the output into notifications that fit the mtu
+        let mut chunker = Chunker::new();
+
+        let mut buffer = [0u8; 512];
         loop {
-            // send bytes if there are bytes to send
-            let buffer_len = self.byte_output_stream.lock().unwrap().len();
-            println!("capacity: {:?}", buffer_len);
-            if buffer_len > 0 {
-                println!("HERE");
-                let mut temp_vec = Vec::<u8>::with_capacity(buffer_len);
-                for val in
-                    self.byte_output_stream
-                        .lock()
-                        .unwrap()
-                        .drain(std::ops::RangeToInclusive {
-                            end: buffer_len - 1,
-                        })
-                {
-                    temp_vec.push(val);
+            // the app's output is notified as soon as it is written, the operations are checked
+            // in between
+            match self.link.read_timeout(&mut buffer, OPERATION_INTERVAL) {
+                Ok(Some(0)) => {
+                    ::log::error!("the app closed its link");
+                    esp_idf_hal::delay::FreeRtos::delay_ms(OPERATION_INTERVAL.as_millis() as u32);
                 }
-
-                println!("writing bytes: {:?}", temp_vec);
-                byte_out_char.lock().set_value(&temp_vec).notify();
+                Ok(Some(len)) => {
+                    for chunk in chunker.chunk(&buffer[..len], self.att_mtu()) {
+                        byte_out_char.lock().set_value(&chunk).notify();
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => ::log::error!("error reading from the link {:?}", err),
             }
 
             let recv_res = self
@@ -417,13 +317,13 @@ impl BluetoothProcessing {
                     BluetoothOperation::OTAControlRequestNack
                     | BluetoothOperation::OTAControlRequestAck
                     | BluetoothOperation::OTAControldoneAck
-                    | BluetoothOperation::OTAControldoneNak => {
-                        ::log::info!("OTAControlRequestNack");
+                    | BluetoothOperation::OTAControldoneNak
+                    | BluetoothOperation::OTAControlProgress => {
+                        ::log::info!("{:?}", op.operation);
                         op.set_and_notify(Arc::clone(&ota_control_characteristic));
                     }
                 }
             }
-            esp_idf_hal::delay::FreeRtos::delay_ms(1000);
         }
     }
 }
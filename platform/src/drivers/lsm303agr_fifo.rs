@@ -0,0 +1,324 @@
+use std::{collections::VecDeque, ptr};
+
+use app::domain::{
+    fifo::{self, AccelMode, AccelScale, OdrClock, FIFO_SAMPLE_SIZE, FIFO_SIZE},
+    imu::ImuSource,
+    sample::{ImuSample, Vector3},
+    sensor::SensorConfig,
+};
+
+use esp_idf_hal::{
+    delay::BLOCK,
+    i2c::{config, I2cDriver},
+    peripherals::Peripherals,
+    prelude::*,
+};
+
+use esp_idf_sys::{
+    esp, esp_timer_get_time, gpio_config, gpio_config_t, gpio_install_isr_service,
+    gpio_int_type_t_GPIO_INTR_POSEDGE, gpio_isr_handler_add, gpio_mode_t_GPIO_MODE_INPUT,
+    xPortGetTickRateHz, xQueueGenericCreate, xQueueGiveFromISR, xQueueReceive, QueueHandle_t,
+    ESP_ERR_INVALID_STATE, ESP_INTR_FLAG_IRAM,
+};
+
+/*
+    fifo driven acquisition for the lsm303agr
+
+    the accelerometer fills its fifo at the output data rate and pulls INT1 high once the
+    watermark is reached. the interrupt wakes the reading thread which empties the fifo in one burst
+    read, so nothing polls the sensor and the samples are timestamped from the data rate instead of
+    from whenever the loop got round to them. the magnetometer runs at 100 Hz, its latest reading is
+    sent along with every accel sample.
+
+    the lsm303agr crate does not expose the fifo so the registers are written directly. the app
+    can change the data rates, range and mode at runtime, the fifo is restarted when it does.
+*/
+
+// gpio INT1 of the lsm303agr is wired to
+const LSM303AGR_INT1_PIN: i32 = 3;
+
+// samples in the fifo before the interrupt fires, leaves half the fifo spare for a late read
+const WATERMARK: u8 = 16;
+
+const ACCEL_ADDRESS: u8 = 0x19;
+const MAG_ADDRESS: u8 = 0x1E;
+
+const CTRL_REG1_A: u8 = 0x20;
+const CTRL_REG3_A: u8 = 0x22;
+const CTRL_REG4_A: u8 = 0x23;
+const CTRL_REG5_A: u8 = 0x24;
+const OUT_X_L_A: u8 = 0x28;
+const FIFO_CTRL_REG_A: u8 = 0x2E;
+const FIFO_SRC_REG_A: u8 = 0x2F;
+const CFG_REG_A_M: u8 = 0x60;
+const CFG_REG_C_M: u8 = 0x62;
+const STATUS_REG_M: u8 = 0x67;
+const OUTX_L_REG_M: u8 = 0x68;
+
+// setting the top bit of a register address reads the following registers in the same transfer
+const AUTO_INCREMENT: u8 = 0x80;
+
+const XYZ_EN: u8 = 0x07;
+const LP_EN: u8 = 0x08;
+const I1_WTM: u8 = 0x04;
+const BDU: u8 = 0x80;
+const HR: u8 = 0x08;
+const FIFO_EN: u8 = 0x40;
+const FIFO_MODE_BYPASS: u8 = 0x00;
+const FIFO_MODE_STREAM: u8 = 0x80;
+const FIFO_SRC_OVRN: u8 = 0x40;
+const FIFO_SRC_EMPTY: u8 = 0x20;
+const FIFO_SRC_FSS: u8 = 0x1F;
+const MAG_COMP_TEMP_EN: u8 = 0x80; // continuous mode is 0 so needs no bits
+const MAG_BDU: u8 = 0x10;
+const MAG_XYZ_READY: u8 = 0x08;
+// nano tesla per digit
+const MAG_SENSITIVITY: f32 = 150.0;
+
+// CTRL_REG1_A for a configuration, the odr goes in the top four bits
+fn ctrl_reg1_a(config: &SensorConfig) -> u8 {
+    let odr: u8 = match config.accel_odr {
+        1 => 1,
+        10 => 2,
+        25 => 3,
+        50 => 4,
+        100 => 5,
+        200 => 6,
+        400 => 7,
+        _ => 9, // 1344 Hz, validated by the app
+    };
+    let low_power = match config.mode {
+        AccelMode::LowPower => LP_EN,
+        _ => 0,
+    };
+    odr << 4 | low_power | XYZ_EN
+}
+
+// CTRL_REG4_A for a configuration, the full scale goes in bits 4 and 5
+fn ctrl_reg4_a(config: &SensorConfig) -> u8 {
+    let scale: u8 = match config.scale {
+        AccelScale::G2 => 0,
+        AccelScale::G4 => 1,
+        AccelScale::G8 => 2,
+        AccelScale::G16 => 3,
+    };
+    let high_resolution = match config.mode {
+        AccelMode::HighResolution => HR,
+        _ => 0,
+    };
+    BDU | scale << 4 | high_resolution
+}
+
+// CFG_REG_A_M for a configuration, the odr goes in bits 2 and 3
+fn cfg_reg_a_m(config: &SensorConfig) -> u8 {
+    let odr: u8 = match config.mag_odr {
+        10 => 0,
+        20 => 1,
+        50 => 2,
+        _ => 3, // 100 Hz
+    };
+    MAG_COMP_TEMP_EN | odr << 2
+}
+
+static mut WATERMARK_QUEUE: Option<QueueHandle_t> = None;
+
+#[link_section = ".iram0.text"]
+unsafe extern "C" fn watermark_interrupt(_: *mut std::ffi::c_void) {
+    xQueueGiveFromISR(WATERMARK_QUEUE.unwrap(), std::ptr::null_mut());
+}
+
+// Queue configurations
+const QUEUE_TYPE_BASE: u8 = 0;
+const ITEM_SIZE: u32 = 0; // we're not posting any actual data, just notifying
+const QUEUE_SIZE: u32 = 1;
+
+fn init_interrupt() -> Result<(), Error> {
+    let io_conf = gpio_config_t {
+        pin_bit_mask: 1 << LSM303AGR_INT1_PIN,
+        mode: gpio_mode_t_GPIO_MODE_INPUT,
+        pull_up_en: false.into(),
+        pull_down_en: true.into(),
+        intr_type: gpio_int_type_t_GPIO_INTR_POSEDGE, // INT1 is active high
+    };
+
+    unsafe {
+        esp!(gpio_config(&io_conf)).map_err(|_| Error::HardwareError)?;
+
+        // the service may already be installed for the button
+        let res = gpio_install_isr_service(ESP_INTR_FLAG_IRAM as i32);
+        if res != ESP_ERR_INVALID_STATE {
+            esp!(res).map_err(|_| Error::HardwareError)?;
+        }
+
+        let queue = xQueueGenericCreate(QUEUE_SIZE, ITEM_SIZE, QUEUE_TYPE_BASE);
+        if queue.is_null() {
+            return Err(Error::HardwareError);
+        }
+        WATERMARK_QUEUE = Some(queue);
+
+        esp!(gpio_isr_handler_add(
+            LSM303AGR_INT1_PIN,
+            Some(watermark_interrupt),
+            std::ptr::null_mut()
+        ))
+        .map_err(|_| Error::HardwareError)
+    }
+}
+
+// wait_for_watermark blocks until the interrupt fires, or about as long as the fifo takes to fill
+// in case an edge was missed
+fn wait_for_watermark(odr: u32) {
+    unsafe {
+        let wait_ticks = xPortGetTickRateHz() * FIFO_SIZE as u32 / odr;
+        xQueueReceive(WATERMARK_QUEUE.unwrap(), ptr::null_mut(), wait_ticks.max(1));
+    }
+}
+
+fn micros() -> i64 {
+    unsafe { esp_timer_get_time() }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    HardwareError,
+}
+
+/*
+    Init the i2c bus the sensor is on
+*/
+fn init_i2c() -> Result<I2cDriver<'static>, Error> {
+    let peripherals = Peripherals::take().unwrap();
+    let sda = peripherals.pins.gpio0;
+    let scl = peripherals.pins.gpio1;
+
+    // master configuration (default)
+    let i2c_config = config::Config {
+        baudrate: Hertz(500000),
+        sda_pullup_enabled: false,
+        scl_pullup_enabled: false,
+    };
+
+    I2cDriver::new(peripherals.i2c0, sda, scl, &i2c_config).map_err(|_| Error::HardwareError)
+}
+
+pub struct FifoSensor {
+    i2c: I2cDriver<'static>,
+    config: SensorConfig,
+    clock: OdrClock,
+    pending: VecDeque<ImuSample>,
+    last_mag: Vector3,
+}
+
+/*
+    Init the sensor for fifo reads
+*/
+pub fn init_fifo_sensor() -> Result<FifoSensor, Error> {
+    let config = SensorConfig::default();
+    let mut sensor = FifoSensor {
+        i2c: init_i2c()?,
+        config,
+        clock: OdrClock::new(config.accel_odr),
+        pending: VecDeque::with_capacity(FIFO_SIZE),
+        last_mag: Vector3::default(),
+    };
+    sensor.write_config()?;
+    init_interrupt()?;
+    Ok(sensor)
+}
+
+impl FifoSensor {
+    fn write_register(&mut self, address: u8, register: u8, value: u8) -> Result<(), Error> {
+        self.i2c
+            .write(address, &[register, value], BLOCK)
+            .map_err(|_| Error::HardwareError)
+    }
+
+    fn read_registers(
+        &mut self,
+        address: u8,
+        register: u8,
+        buffer: &mut [u8],
+    ) -> Result<(), Error> {
+        self.i2c
+            .write_read(address, &[register | AUTO_INCREMENT], buffer, BLOCK)
+            .map_err(|_| Error::HardwareError)
+    }
+
+    // write_config writes the configuration to the sensor and restarts the fifo
+    fn write_config(&mut self) -> Result<(), Error> {
+        let config = self.config;
+        self.write_register(ACCEL_ADDRESS, CTRL_REG1_A, ctrl_reg1_a(&config))?;
+        self.write_register(ACCEL_ADDRESS, CTRL_REG4_A, ctrl_reg4_a(&config))?;
+        self.write_register(ACCEL_ADDRESS, CTRL_REG5_A, FIFO_EN)?;
+        // going through bypass mode empties the fifo
+        self.write_register(ACCEL_ADDRESS, FIFO_CTRL_REG_A, FIFO_MODE_BYPASS)?;
+        self.write_register(ACCEL_ADDRESS, FIFO_CTRL_REG_A, FIFO_MODE_STREAM | WATERMARK)?;
+        self.write_register(ACCEL_ADDRESS, CTRL_REG3_A, I1_WTM)?;
+
+        self.write_register(MAG_ADDRESS, CFG_REG_A_M, cfg_reg_a_m(&config))?;
+        self.write_register(MAG_ADDRESS, CFG_REG_C_M, MAG_BDU)?;
+        Ok(())
+    }
+
+    // read_fifo empties the fifo into pending
+    fn read_fifo(&mut self) -> Result<(), Error> {
+        let read_time = micros();
+
+        let mut src = [0u8; 1];
+        self.read_registers(ACCEL_ADDRESS, FIFO_SRC_REG_A, &mut src)?;
+        let count = match src[0] {
+            s if s & FIFO_SRC_EMPTY != 0 => 0,
+            s if s & FIFO_SRC_OVRN != 0 => FIFO_SIZE,
+            s => (s & FIFO_SRC_FSS) as usize,
+        };
+        if src[0] & FIFO_SRC_OVRN != 0 {
+            // samples were overwritten so the timestamps can not carry on from the last read
+            ::log::info!("accel fifo overrun");
+            self.clock.reset();
+        }
+        if count == 0 {
+            return Ok(());
+        }
+
+        let mut bytes = [0u8; FIFO_SIZE * FIFO_SAMPLE_SIZE];
+        let bytes = &mut bytes[..count * FIFO_SAMPLE_SIZE];
+        self.read_registers(ACCEL_ADDRESS, OUT_X_L_A, bytes)?;
+
+        let mut status = [0u8; 1];
+        self.read_registers(MAG_ADDRESS, STATUS_REG_M, &mut status)?;
+        if status[0] & MAG_XYZ_READY != 0 {
+            let mut mag = [0u8; 6];
+            self.read_registers(MAG_ADDRESS, OUTX_L_REG_M, &mut mag)?;
+            let axis = |i: usize| i16::from_le_bytes([mag[i], mag[i + 1]]) as f32 * MAG_SENSITIVITY;
+            self.last_mag = Vector3::new(axis(0), axis(2), axis(4));
+        }
+
+        let accel = fifo::decode_accel(bytes, self.config.scale, self.config.mode);
+        let timestamps = self.clock.timestamps(accel.len(), read_time);
+        for (timestamp, accel) in timestamps.into_iter().zip(accel) {
+            self.pending
+                .push_back(ImuSample::new(timestamp, accel, self.last_mag));
+        }
+        Ok(())
+    }
+}
+
+impl ImuSource for FifoSensor {
+    type Error = Error;
+
+    // blocks until the next fifo read, the sensor never runs out
+    fn next_sample(&mut self) -> Result<Option<ImuSample>, Error> {
+        while self.pending.is_empty() {
+            wait_for_watermark(self.config.accel_odr);
+            self.read_fifo()?;
+        }
+        Ok(self.pending.pop_front())
+    }
+
+    fn configure(&mut self, config: SensorConfig) -> Result<(), Error> {
+        self.config = config;
+        self.clock = OdrClock::new(config.accel_odr);
+        // samples still pending were taken with the old configuration, they are kept
+        self.write_config()
+    }
+}
@@ -4,9 +4,9 @@
     - button
     - led
     - bmi160
-    - lsm303agr
+    - lsm303agr_fifo
 
 */
 
 pub mod button;
-pub mod lsm303agr;
+pub mod lsm303agr_fifo;
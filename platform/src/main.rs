@@ -8,15 +8,23 @@ use core::time;
 use std::{
     fs::File,
     io::{BufReader, BufWriter, Read, Write},
-    sync::Arc,
     thread,
 };
 
 use app::domain::domain::{self, ReadWrite};
+use app::domain::imu;
+use app::transport::duplex;
+use esp_idf_svc::nvs::EspDefaultNvsPartition;
 use protobuf::Message;
 
 use crate::bluetooth::ble;
+use crate::drivers::lsm303agr_fifo;
+use crate::storage::{nvs::NvsSettingsStore, partition::PartitionFlash};
 mod bluetooth;
+mod drivers;
+mod storage;
+
+const APP_WRITE_TIMEOUT: time::Duration = time::Duration::from_secs(1);
 
 /*
     main is the entry point for the application
@@ -27,15 +35,29 @@ fn main() {
     esp_idf_sys::link_patches();
     esp_idf_svc::log::EspLogger::initialize_default();
 
-    // create buffers
-    let output_buffer = Arc::new(std::sync::Mutex::new(Vec::<u8>::with_capacity(500)));
-    let input_buffer = Arc::new(std::sync::Mutex::new(Vec::<u8>::with_capacity(500)));
+    // the app and the ble task talk over a pipe, the app gives up on a message if the ble task
+    // has not made room for it within APP_WRITE_TIMEOUT
+    let (mut app_link, ble_link) = duplex::pipe(duplex::DEFAULT_CAPACITY);
+    app_link.set_write_timeout(Some(APP_WRITE_TIMEOUT));
+
+    let mut my_app = app::app::app::App::new(Box::new(app_link));
+    let sample_sender = my_app.sample_sender();
+    let sensor_configs = my_app.sensor_config_receiver().unwrap();
 
-    let my_app = app::app::app::App::new(Arc::clone(&input_buffer), Arc::clone(&output_buffer));
+    // carry on with the settings and calibration saved on the device, without them the app starts
+    // unconfigured and the samples are used uncorrected
+    match EspDefaultNvsPartition::take().and_then(NvsSettingsStore::new) {
+        Ok(store) => my_app.set_settings_store(Box::new(store)),
+        Err(err) => ::log::error!("error opening nvs {:?}", err),
+    }
+    // finished sets are logged so they can be downloaded after training without the phone
+    match PartitionFlash::new() {
+        Some(flash) => my_app.set_session_flash(Box::new(flash)),
+        None => ::log::error!("no sessions partition"),
+    }
 
     // start and init the bluetooth processing
-    let mut bluetooth_processor =
-        ble::BluetoothProcessing::new(Arc::clone(&input_buffer), Arc::clone(&output_buffer));
+    let mut bluetooth_processor = ble::BluetoothProcessing::new(ble_link);
     bluetooth_processor = bluetooth_processor.init_device();
     bluetooth_processor = bluetooth_processor.init_server();
 
@@ -44,5 +66,14 @@ fn main() {
         bluetooth_processor.run_ble();
     });
 
+    // read the imu and forward the samples to the app
+    thread::spawn(move || {
+        let result = lsm303agr_fifo::init_fifo_sensor()
+            .and_then(|mut sensor| imu::forward(&mut sensor, &sample_sender, &sensor_configs));
+        if let Err(err) = result {
+            ::log::error!("error reading lsm303agr {:?}", err);
+        }
+    });
+
     my_app.run();
 }
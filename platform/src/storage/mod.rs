@@ -0,0 +1,12 @@
+/*
+    this storage module keeps data on the esp32 across restarts
+
+    - nvs
+    - partition
+    - ota
+
+*/
+
+pub mod nvs;
+pub mod ota;
+pub mod partition;
@@ -0,0 +1,52 @@
+use app::domain::settings::{SettingsStore, StoreError};
+use esp_idf_svc::nvs::{EspDefaultNvsPartition, EspNvs, NvsDefault};
+use esp_idf_sys::EspError;
+
+/*
+    settings kept in the default nvs partition
+
+    every key of the settings store is a blob in one nvs namespace, what goes in the blobs and how
+    they are versioned is up to the app.
+*/
+
+const NAMESPACE: &str = "settings";
+// largest blob that can be loaded, the settings and calibration are well under it
+const MAX_VALUE_SIZE: usize = 512;
+
+pub struct NvsSettingsStore {
+    nvs: EspNvs<NvsDefault>,
+}
+
+impl NvsSettingsStore {
+    pub fn new(partition: EspDefaultNvsPartition) -> Result<Self, EspError> {
+        Ok(NvsSettingsStore {
+            nvs: EspNvs::new(partition, NAMESPACE, true)?,
+        })
+    }
+}
+
+fn store_error(err: EspError) -> StoreError {
+    StoreError(format!("{:?}", err))
+}
+
+impl SettingsStore for NvsSettingsStore {
+    fn load(&mut self, key: &str) -> Result<Option<Vec<u8>>, StoreError> {
+        let mut buffer = [0u8; MAX_VALUE_SIZE];
+        let value = self.nvs.get_raw(key, &mut buffer).map_err(store_error)?;
+        Ok(value.map(|bytes| bytes.to_vec()))
+    }
+
+    fn save(&mut self, key: &str, value: &[u8]) -> Result<(), StoreError> {
+        if value.len() > MAX_VALUE_SIZE {
+            return Err(StoreError(format!(
+                "{} bytes is too big for {}",
+                value.len(),
+                key
+            )));
+        }
+        self.nvs
+            .set_raw(key, value)
+            .map(|_| ())
+            .map_err(store_error)
+    }
+}
@@ -0,0 +1,89 @@
+use app::ota::update::{NakReason, OtaTarget};
+use esp_idf_sys::{esp, esp_ota_handle_t, esp_partition_t, EspError};
+
+/*
+    ota partition
+
+    images are written to the ota partition that is not running, esp_ota_end checks the image is
+    one the bootloader can start and esp_ota_set_boot_partition makes it the one booted next.
+    checking the image is the one the phone meant to send is done by app::ota::update.
+*/
+
+pub struct EspOtaTarget {
+    partition: *const esp_partition_t,
+    // handle of the update being written
+    handle: Option<esp_ota_handle_t>,
+}
+
+// the partition table is read only and lives as long as the program, see PartitionFlash
+unsafe impl Send for EspOtaTarget {}
+
+impl EspOtaTarget {
+    /// None when the partition table has no partition to update into
+    pub fn new() -> Option<Self> {
+        let partition = unsafe { esp_idf_sys::esp_ota_get_next_update_partition(std::ptr::null()) };
+        (!partition.is_null()).then_some(EspOtaTarget {
+            partition,
+            handle: None,
+        })
+    }
+}
+
+fn flash_failed(err: EspError) -> NakReason {
+    ::log::error!("ota flash error {:?}", err);
+    NakReason::FlashFailed
+}
+
+impl OtaTarget for EspOtaTarget {
+    fn capacity(&self) -> usize {
+        unsafe { (*self.partition).size as usize }
+    }
+
+    // the size is already checked against the partition by the update, passing it on would have
+    // esp_ota_begin erase the whole image up front and stall the bluetooth stack. sequential
+    // writes erase each sector as the image reaches it
+    fn begin(&mut self, _image_size: usize) -> Result<(), NakReason> {
+        self.abort();
+        let mut handle: esp_ota_handle_t = 0;
+        esp!(unsafe {
+            esp_idf_sys::esp_ota_begin(
+                self.partition,
+                esp_idf_sys::OTA_WITH_SEQUENTIAL_WRITES as usize,
+                &mut handle,
+            )
+        })
+        .map_err(flash_failed)?;
+        self.handle = Some(handle);
+        Ok(())
+    }
+
+    fn write(&mut self, data: &[u8]) -> Result<(), NakReason> {
+        let handle = self.handle.ok_or(NakReason::NotUpdating)?;
+        esp!(unsafe { esp_idf_sys::esp_ota_write(handle, data.as_ptr() as *const _, data.len()) })
+            .map_err(flash_failed)
+    }
+
+    fn end(&mut self) -> Result<(), NakReason> {
+        let handle = self.handle.take().ok_or(NakReason::NotUpdating)?;
+        // esp_ota_end frees the handle even when it fails
+        match unsafe { esp_idf_sys::esp_ota_end(handle) } {
+            0 => Ok(()),
+            esp_idf_sys::ESP_ERR_OTA_VALIDATE_FAILED => {
+                ::log::info!("image validation failed, image is corrupted!");
+                Err(NakReason::InvalidImage)
+            }
+            err => Err(flash_failed(EspError::from(err).unwrap())),
+        }
+    }
+
+    fn abort(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            unsafe { esp_idf_sys::esp_ota_abort(handle) };
+        }
+    }
+
+    fn apply(&mut self) -> Result<(), NakReason> {
+        esp!(unsafe { esp_idf_sys::esp_ota_set_boot_partition(self.partition) })
+            .map_err(flash_failed)
+    }
+}
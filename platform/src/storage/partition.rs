@@ -0,0 +1,90 @@
+use app::storage::flash::{Flash, FlashError, SECTOR_SIZE};
+use esp_idf_sys::{esp, esp_partition_t, EspError};
+
+/*
+    flash of the sessions data partition
+
+    the partition table has a data partition of the custom subtype 0x40 labelled sessions, the
+    session log is kept in it. reads, writes and erases go through the esp partition api which
+    checks they stay inside the partition.
+*/
+
+const LABEL: &[u8] = b"sessions\0";
+const SUBTYPE: esp_idf_sys::esp_partition_subtype_t = 0x40;
+
+pub struct PartitionFlash {
+    partition: *const esp_partition_t,
+}
+
+// the partition table is read only and lives as long as the program, the pointer to an entry in it
+// can be used from any thread
+unsafe impl Send for PartitionFlash {}
+
+impl PartitionFlash {
+    /// None when the partition table has no sessions partition
+    pub fn new() -> Option<Self> {
+        let partition = unsafe {
+            esp_idf_sys::esp_partition_find_first(
+                esp_idf_sys::esp_partition_type_t_ESP_PARTITION_TYPE_DATA,
+                SUBTYPE,
+                LABEL.as_ptr() as *const _,
+            )
+        };
+        (!partition.is_null()).then_some(PartitionFlash { partition })
+    }
+}
+
+fn flash_error(err: EspError) -> FlashError {
+    FlashError::Hardware(format!("{:?}", err))
+}
+
+impl Flash for PartitionFlash {
+    fn size(&self) -> usize {
+        let size = unsafe { (*self.partition).size } as usize;
+        size - size % SECTOR_SIZE
+    }
+
+    fn read(&mut self, offset: usize, buffer: &mut [u8]) -> Result<(), FlashError> {
+        if offset + buffer.len() > self.size() {
+            return Err(FlashError::OutOfBounds);
+        }
+        esp!(unsafe {
+            esp_idf_sys::esp_partition_read(
+                self.partition,
+                offset,
+                buffer.as_mut_ptr() as *mut _,
+                buffer.len(),
+            )
+        })
+        .map_err(flash_error)
+    }
+
+    fn write(&mut self, offset: usize, bytes: &[u8]) -> Result<(), FlashError> {
+        if offset + bytes.len() > self.size() {
+            return Err(FlashError::OutOfBounds);
+        }
+        esp!(unsafe {
+            esp_idf_sys::esp_partition_write(
+                self.partition,
+                offset,
+                bytes.as_ptr() as *const _,
+                bytes.len(),
+            )
+        })
+        .map_err(flash_error)
+    }
+
+    fn erase_sector(&mut self, sector: usize) -> Result<(), FlashError> {
+        if sector >= self.sectors() {
+            return Err(FlashError::OutOfBounds);
+        }
+        esp!(unsafe {
+            esp_idf_sys::esp_partition_erase_range(
+                self.partition,
+                sector * SECTOR_SIZE,
+                SECTOR_SIZE,
+            )
+        })
+        .map_err(flash_error)
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    net::TcpListener,
+    os::unix::net::UnixListener,
+    path::PathBuf,
+    process::ExitCode,
+    sync::mpsc::{Receiver, SyncSender},
+    thread,
+};
+
+use app::app::app::App;
+use app::domain::{
+    imu::{self, ImuSource, ReplaySource},
+    sample::ImuSample,
+    sensor::SensorConfig,
+};
+use app::ota::signature::PUBLIC_KEY_SIZE;
+use app::ota::update::OtaUpdate;
+use app::storage::flash::MemoryFlash;
+use app::transport::{duplex, link::DEFAULT_PORT};
+
+use crate::ota::SimulatedPartition;
+use crate::peripheral::Peripheral;
+use crate::source::{synthetic_set, Looped, Paced, SYNTHETIC_PERIOD};
+
+mod ota;
+mod peripheral;
+mod source;
+
+/*
+    sensor-simulator runs the sensor's app on a linux host
+
+    it is wired up the way the firmware's main is, an imu thread feeding samples to the app and
+    the peripheral standing in for the ble server, so the phone app, the cli and ci can talk to the
+    real app logic without an esp32. the samples come from synthetic sets of rebound jumps or a
+    recorded capture, played back in real time.
+*/
+
+const USAGE: &str = "usage: sensor-simulator [options]
+
+options
+    --listen <address>   tcp:<host>:<port> or unix:<path>, the default is tcp:127.0.0.1:7878
+    --replay <capture>   play back a recorded capture (csv or binary) instead of synthetic sets
+    --loop               start the capture over when it ends, synthetic sets always loop
+    --jumps <count>      rebound jumps per synthetic set, the default is 5
+    --speed <factor>     play the samples back faster or slower, the default is 1
+    --mtu <bytes>        att mtu the notifications are chunked for, the default is 247
+    --ota-image <path>   save applied updates to path
+    --ota-key <path>     take updates signed with the key whose public key is at path (the .pub
+                         sensor-cli keygen writes), without one every update is rejected";
+
+/// flash kept for the session log, 256 KB
+const SESSION_FLASH_SECTORS: usize = 64;
+
+struct Options {
+    listen: String,
+    replay: Option<String>,
+    looped: bool,
+    jumps: u32,
+    speed: f64,
+    mtu: usize,
+    ota_image: Option<PathBuf>,
+    ota_key: Option<String>,
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        listen: format!("tcp:127.0.0.1:{}", DEFAULT_PORT),
+        replay: None,
+        looped: false,
+        jumps: 5,
+        speed: 1.0,
+        mtu: 247,
+        ota_image: None,
+        ota_key: None,
+    };
+    while let Some(arg) = args.next() {
+        if arg == "--loop" {
+            options.looped = true;
+            continue;
+        }
+        let value = args.next().ok_or(format!("{} needs a value", arg))?;
+        let bad_value = format!("bad value {} for {}", value, arg);
+        match arg.as_str() {
+            "--listen" => options.listen = value,
+            "--replay" => options.replay = Some(value),
+            "--jumps" => options.jumps = value.parse().map_err(|_| bad_value)?,
+            "--speed" => options.speed = value.parse().map_err(|_| bad_value)?,
+            "--mtu" => options.mtu = value.parse().map_err(|_| bad_value)?,
+            "--ota-image" => options.ota_image = Some(value.into()),
+            "--ota-key" => options.ota_key = Some(value),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    if options.speed <= 0.0 {
+        return Err("speed has to be above 0".to_string());
+    }
+    Ok(options)
+}
+
+fn public_key(path: &str) -> Result<[u8; PUBLIC_KEY_SIZE], String> {
+    std::fs::read(path)
+        .map_err(|err| format!("error reading {}: {}", path, err))?
+        .try_into()
+        .map_err(|_| format!("{} is not a {} byte public key", path, PUBLIC_KEY_SIZE))
+}
+
+fn replay(path: &str) -> Result<ReplaySource, String> {
+    let bytes = std::fs::read(path).map_err(|err| format!("error reading {}: {}", path, err))?;
+    match path.ends_with(".csv") {
+        true => String::from_utf8(bytes)
+            .map_err(|err| err.to_string())
+            .and_then(|csv| ReplaySource::from_csv(&csv).map_err(|err| format!("{:?}", err))),
+        false => ReplaySource::from_bytes(&bytes).map_err(|err| format!("{:?}", err)),
+    }
+    .map_err(|err| format!("error reading capture {}: {}", path, err))
+}
+
+// feeds the app from source in real time on its own thread, like the firmware's imu thread
+fn spawn_imu<S: ImuSource + Send + 'static>(
+    source: S,
+    speed: f64,
+    sender: SyncSender<ImuSample>,
+    configurations: Receiver<SensorConfig>,
+) {
+    thread::spawn(move || {
+        let mut source = Paced::new(source, speed);
+        match imu::forward(&mut source, &sender, &configurations) {
+            Ok(dropped) => println!("imu source ran out, {} samples dropped", dropped),
+            Err(err) => println!("error reading imu source {:?}", err),
+        }
+    });
+}
+
+fn run(options: Options) -> Result<(), String> {
+    let (link, ble) = duplex::pipe(duplex::DEFAULT_CAPACITY);
+
+    let mut my_app = App::new(Box::new(link));
+    my_app.set_session_flash(Box::new(MemoryFlash::new(SESSION_FLASH_SECTORS)));
+    let sender = my_app.sample_sender();
+    let configurations = my_app
+        .sensor_config_receiver()
+        .ok_or("sensor configurations already taken")?;
+
+    match (options.replay.as_deref(), options.looped) {
+        (Some(path), false) => spawn_imu(replay(path)?, options.speed, sender, configurations),
+        (Some(path), true) => {
+            let capture = replay(path)?;
+            let source = Looped::new(move || capture.clone(), SYNTHETIC_PERIOD);
+            spawn_imu(source, options.speed, sender, configurations)
+        }
+        (None, _) => {
+            let jumps = options.jumps;
+            let source = Looped::new(move || synthetic_set(jumps), SYNTHETIC_PERIOD);
+            spawn_imu(source, options.speed, sender, configurations)
+        }
+    }
+    thread::spawn(move || my_app.run());
+
+    let public_key = match options.ota_key.as_deref() {
+        Some(path) => public_key(path)?,
+        // the all zero key is refused as weak, so every update is rejected
+        None => [0; PUBLIC_KEY_SIZE],
+    };
+    let ota = OtaUpdate::new(SimulatedPartition::new(options.ota_image), public_key);
+    let mut peripheral = Peripheral::new(ble, ota, options.mtu);
+    let listen_error = |err| format!("error listening on {}: {}", options.listen, err);
+    match options.listen.split_once(':') {
+        Some(("tcp", address)) => {
+            let listener = TcpListener::bind(address).map_err(listen_error)?;
+            println!("listening on {}", options.listen);
+            for stream in listener.incoming() {
+                let result = stream.and_then(|mut stream| {
+                    stream.set_nodelay(true)?;
+                    peripheral.serve(&mut stream)
+                });
+                println!("client disconnected {:?}", result);
+            }
+        }
+        Some(("unix", path)) => {
+            // a socket left behind by an earlier run
+            let _ = std::fs::remove_file(path);
+            let listener = UnixListener::bind(path).map_err(listen_error)?;
+            println!("listening on {}", options.listen);
+            for stream in listener.incoming() {
+                let result = stream.and_then(|mut stream| peripheral.serve(&mut stream));
+                println!("client disconnected {:?}", result);
+            }
+        }
+        _ => return Err(format!("unknown address {}", options.listen)),
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match parse_options(std::env::args().skip(1)).and_then(run) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::path::PathBuf;
+
+use app::ota::update::{NakReason, OtaTarget};
+
+/*
+    ota partition without flash
+
+    app::ota::update does the checking the firmware does, this stands in for the ota partition and
+    keeps the image in memory. the firmware has esp_ota_end validate the image, here it only has to
+    start with the magic byte of an esp image. applying it saves the image to a file when one is
+    given, the sensor would restart into it.
+*/
+
+/// first byte of every esp app image
+pub const ESP_IMAGE_MAGIC: u8 = 0xE9;
+/// size of an ota partition in partitions_two_ota.csv
+pub const PARTITION_SIZE: usize = 0x100000;
+
+#[derive(Debug, Default)]
+pub struct SimulatedPartition {
+    image: Vec<u8>,
+    // where an applied image is saved
+    output: Option<PathBuf>,
+}
+
+impl SimulatedPartition {
+    pub fn new(output: Option<PathBuf>) -> Self {
+        SimulatedPartition {
+            image: Vec::new(),
+            output,
+        }
+    }
+}
+
+impl OtaTarget for SimulatedPartition {
+    fn capacity(&self) -> usize {
+        PARTITION_SIZE
+    }
+
+    fn begin(&mut self, image_size: usize) -> Result<(), NakReason> {
+        self.image = Vec::with_capacity(image_size);
+        Ok(())
+    }
+
+    fn write(&mut self, data: &[u8]) -> Result<(), NakReason> {
+        self.image.extend_from_slice(data);
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<(), NakReason> {
+        if self.image.first() != Some(&ESP_IMAGE_MAGIC) {
+            println!("image validation failed, image is corrupted!");
+            return Err(NakReason::InvalidImage);
+        }
+        println!("received a {} byte image", self.image.len());
+        Ok(())
+    }
+
+    fn abort(&mut self) {
+        self.image.clear();
+    }
+
+    fn apply(&mut self) -> Result<(), NakReason> {
+        let Some(output) = &self.output else {
+            return Ok(());
+        };
+        std::fs::write(output, &self.image).map_err(|err| {
+            println!("error saving the image to {:?} {:?}", output, err);
+            NakReason::FlashFailed
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use app::ota::signature::{self, PUBLIC_KEY_SIZE, SEED_SIZE};
+    use app::ota::update::{chunk, OtaRequest, OtaUpdate};
+    use app::transport::gatt::{
+        OTA_CONTROL_DONE, OTA_CONTROL_DONE_NAK, OTA_CONTROL_REQUEST_NAK, UPDATE_SUCCESS,
+    };
+
+    /// the simulator in the tests is started with its public key
+    pub(crate) const SEED: [u8; SEED_SIZE] = [5; SEED_SIZE];
+
+    pub(crate) fn signed_request(image: &[u8], packet_size: u16) -> OtaRequest {
+        let signed = signature::sign(image, &SEED);
+        let (_, signature) = signature::split_signed(&signed).unwrap();
+        OtaRequest::for_image(image, signature, packet_size)
+    }
+
+    fn send(image: &[u8], output: Option<PathBuf>) -> (Option<Vec<u8>>, u8) {
+        let mut update = OtaUpdate::new(
+            SimulatedPartition::new(output),
+            signature::public_key(&SEED),
+        );
+        update.control(&signed_request(image, 244).to_bytes());
+        update.data(&chunk(0, image));
+        let done = update.control(&[OTA_CONTROL_DONE]);
+        (done, update.apply())
+    }
+
+    #[test]
+    fn saves_applied_images() {
+        let output = std::env::temp_dir().join(format!("ota-{}.bin", std::process::id()));
+        let image = [ESP_IMAGE_MAGIC, 1, 2, 3];
+
+        let (_, applied) = send(&image, Some(output.clone()));
+
+        assert_eq!(applied, UPDATE_SUCCESS);
+        assert_eq!(std::fs::read(&output).unwrap(), image);
+        std::fs::remove_file(output).unwrap();
+    }
+
+    #[test]
+    fn rejects_updates_without_a_key() {
+        let mut update = OtaUpdate::new(SimulatedPartition::new(None), [0; PUBLIC_KEY_SIZE]);
+
+        let request = update.control(&signed_request(&[ESP_IMAGE_MAGIC], 244).to_bytes());
+
+        assert_eq!(
+            request,
+            Some(vec![OTA_CONTROL_REQUEST_NAK, NakReason::BadSignature as u8])
+        );
+    }
+
+    #[test]
+    fn rejects_other_images() {
+        let (done, _) = send(b"not an image", None);
+
+        assert_eq!(
+            done,
+            Some(vec![OTA_CONTROL_DONE_NAK, NakReason::InvalidImage as u8])
+        );
+    }
+}
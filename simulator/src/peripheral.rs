@@ -0,0 +1,307 @@
+use std::{
+    io::{self, Read, Write},
+    net::TcpStream,
+    os::unix::net::UnixStream,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use app::ota::update::OtaUpdate;
+use app::transport::{
+    chunking::Chunker,
+    duplex::PipeEnd,
+    gatt::{Characteristic, UPDATE_SUCCESS},
+    link::{LinkDecoder, Packet},
+};
+
+use crate::ota::SimulatedPartition;
+
+/*
+    the simulated ble peripheral
+
+    does what BluetoothProcessing::run_ble does on the esp32, with a link connection (see
+    app::transport::link) in place of the gatt server. byte in is written to the app's link, the
+    app's output is chunked for the mtu and notified on byte out as soon as it is written, and the
+    ota characteristics take an update. like ble there is one client at a time, output while nobody
+    is connected is lost the same way notifications without a subscriber are.
+*/
+
+/// a link connection from a client
+pub trait Connection: Read + Write {
+    /// a handle writing to the same connection, for the notifications
+    fn writer(&self) -> io::Result<Box<dyn Write + Send>>;
+}
+
+impl Connection for TcpStream {
+    fn writer(&self) -> io::Result<Box<dyn Write + Send>> {
+        Ok(Box::new(self.try_clone()?))
+    }
+}
+
+impl Connection for UnixStream {
+    fn writer(&self) -> io::Result<Box<dyn Write + Send>> {
+        Ok(Box::new(self.try_clone()?))
+    }
+}
+
+// the connected client, every notification goes through here so packets are never interleaved
+type Client = Arc<Mutex<Option<Box<dyn Write + Send>>>>;
+
+fn notify(client: &Client, packet: Packet) {
+    let mut client = client.lock().unwrap();
+    if let Some(writer) = client.as_mut() {
+        if let Err(err) = writer.write_all(&packet.encode()) {
+            println!("error notifying the client {:?}", err);
+            *client = None;
+        }
+    }
+}
+
+pub struct Peripheral {
+    // the ble end of the app's link
+    link: PipeEnd,
+    client: Client,
+    ota: OtaUpdate<SimulatedPartition>,
+    // set once an update was applied, the sensor restarts and the client is dropped
+    restarting: bool,
+}
+
+impl Peripheral {
+    /// notifies the app's output in chunks for the mtu
+    pub fn new(link: PipeEnd, ota: OtaUpdate<SimulatedPartition>, mtu: usize) -> Self {
+        let client: Client = Arc::new(Mutex::new(None));
+
+        let mut output = link.clone();
+        let output_client = Arc::clone(&client);
+        thread::spawn(move || {
+            let mut chunker = Chunker::new();
+            let mut buffer = [0u8; 1024];
+            while let Ok(len @ 1..) = output.read(&mut buffer) {
+                for chunk in chunker.chunk(&buffer[..len], mtu) {
+                    notify(&output_client, Packet::new(Characteristic::ByteOut, &chunk));
+                }
+            }
+        });
+
+        Peripheral {
+            link,
+            client,
+            ota,
+            restarting: false,
+        }
+    }
+
+    /// serves a client until it disconnects
+    pub fn serve<C: Connection>(&mut self, connection: &mut C) -> io::Result<()> {
+        *self.client.lock().unwrap() = Some(connection.writer()?);
+        self.restarting = false;
+        let result = self.read_writes(connection);
+        *self.client.lock().unwrap() = None;
+        // an update in progress is kept for the client to resume
+        result
+    }
+
+    fn read_writes<C: Connection>(&mut self, connection: &mut C) -> io::Result<()> {
+        let mut decoder = LinkDecoder::new();
+        let mut buffer = [0u8; 1024];
+        while !self.restarting {
+            let len = match connection.read(&mut buffer) {
+                Ok(0) => return Ok(()),
+                Ok(len) => len,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            for packet in decoder.push(&buffer[..len]) {
+                self.write(packet)?;
+            }
+        }
+        println!("update applied, dropping the client as the sensor would restart");
+        Ok(())
+    }
+
+    // handles a write to a characteristic
+    fn write(&mut self, packet: Packet) -> io::Result<()> {
+        let characteristic = packet.characteristic;
+        let notification = match characteristic {
+            Characteristic::ByteIn => {
+                self.link.write_all(&packet.value)?;
+                None
+            }
+            Characteristic::OtaControl => self.ota.control(&packet.value),
+            Characteristic::OtaData => {
+                // progress is notified on ota control
+                if let Some(progress) = self.ota.data(&packet.value) {
+                    notify(
+                        &self.client,
+                        Packet::new(Characteristic::OtaControl, &progress),
+                    );
+                }
+                None
+            }
+            Characteristic::OtaApply => {
+                let result = self.ota.apply();
+                self.restarting = result == UPDATE_SUCCESS;
+                Some(vec![result])
+            }
+            // notify only
+            Characteristic::ByteOut => None,
+        };
+        if let Some(value) = notification {
+            notify(&self.client, Packet::new(characteristic, &value));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ota::tests::{signed_request, SEED};
+    use crate::ota::ESP_IMAGE_MAGIC;
+    use app::app::app::App;
+    use app::ota::signature;
+    use app::ota::update::{chunk, next_offset};
+    use app::rust_proto::algorithim;
+    use app::transport::{chunking::Reassembler, duplex, framing, gatt};
+    use std::{collections::VecDeque, time::Duration};
+
+    // a client on the other end of a socket to a peripheral with a running app
+    struct Client {
+        stream: UnixStream,
+        decoder: LinkDecoder,
+        notifications: VecDeque<Packet>,
+    }
+
+    impl Client {
+        fn connect() -> Self {
+            Client::connect_all(1).pop().unwrap()
+        }
+
+        // clients the same peripheral serves one after the other
+        fn connect_all(count: usize) -> Vec<Self> {
+            let (ours, theirs): (Vec<_>, Vec<_>) =
+                (0..count).map(|_| UnixStream::pair().unwrap()).unzip();
+            let (link, ble) = duplex::pipe(duplex::DEFAULT_CAPACITY);
+            let app = App::new(Box::new(link));
+            thread::spawn(move || app.run());
+            thread::spawn(move || {
+                let ota =
+                    OtaUpdate::new(SimulatedPartition::new(None), signature::public_key(&SEED));
+                let mut peripheral = Peripheral::new(ble, ota, 23);
+                for mut ours in ours {
+                    peripheral.serve(&mut ours).unwrap();
+                }
+            });
+            theirs
+                .into_iter()
+                .map(|stream| {
+                    stream
+                        .set_read_timeout(Some(Duration::from_secs(5)))
+                        .unwrap();
+                    Client {
+                        stream,
+                        decoder: LinkDecoder::new(),
+                        notifications: VecDeque::new(),
+                    }
+                })
+                .collect()
+        }
+
+        fn write(&mut self, characteristic: Characteristic, value: &[u8]) {
+            self.stream
+                .write_all(&Packet::new(characteristic, value).encode())
+                .unwrap();
+        }
+
+        fn notification(&mut self) -> Packet {
+            let mut buffer = [0u8; 1024];
+            loop {
+                if let Some(packet) = self.notifications.pop_front() {
+                    return packet;
+                }
+                let len = self.stream.read(&mut buffer).unwrap();
+                assert!(len > 0, "disconnected");
+                self.notifications.extend(self.decoder.push(&buffer[..len]));
+            }
+        }
+    }
+
+    #[test]
+    fn messages_go_through_the_app() {
+        let mut client = Client::connect();
+        let mut message = algorithim::Message::new();
+        message.requestId = 9;
+        message.set_listSessions(algorithim::ListSessions::new());
+
+        client.write(
+            Characteristic::ByteIn,
+            &framing::encode(&protobuf::Message::write_to_bytes(&message).unwrap()),
+        );
+        let mut reassembler = Reassembler::new();
+        let frame = loop {
+            let packet = client.notification();
+            assert_eq!(packet.characteristic, Characteristic::ByteOut);
+            // a 23 byte mtu fits 19 bytes of the stream in a chunk
+            assert!(packet.value.len() <= 20);
+            if let Some(frame) = reassembler.push(&packet.value).pop() {
+                break frame.unwrap();
+            }
+        };
+
+        let response: algorithim::Message = protobuf::Message::parse_from_bytes(&frame).unwrap();
+        assert_eq!(response.response().requestId, 9);
+    }
+
+    #[test]
+    fn takes_an_update() {
+        let mut client = Client::connect();
+
+        let image = [ESP_IMAGE_MAGIC, 0];
+
+        client.write(
+            Characteristic::OtaControl,
+            &signed_request(&image, 2).to_bytes(),
+        );
+        assert_eq!(next_offset(&client.notification().value), Some(0));
+        client.write(Characteristic::OtaData, &chunk(0, &image));
+        assert_eq!(next_offset(&client.notification().value), Some(2));
+        client.write(Characteristic::OtaControl, &[gatt::OTA_CONTROL_DONE]);
+        assert_eq!(
+            client.notification(),
+            Packet::new(Characteristic::OtaControl, &[gatt::OTA_CONTROL_DONE_ACK])
+        );
+        client.write(Characteristic::OtaApply, &[1]);
+        assert_eq!(
+            client.notification(),
+            Packet::new(Characteristic::OtaApply, &[gatt::UPDATE_SUCCESS])
+        );
+
+        // the sensor restarts
+        let mut buffer = [0u8; 1];
+        assert_eq!(client.stream.read(&mut buffer).unwrap(), 0);
+    }
+
+    #[test]
+    fn keeps_an_update_for_the_next_client() {
+        let mut clients = Client::connect_all(2);
+        let image = [ESP_IMAGE_MAGIC, 1, 2, 3];
+        let request = signed_request(&image, 2).to_bytes();
+
+        let mut first = clients.remove(0);
+        first.write(Characteristic::OtaControl, &request);
+        first.notification();
+        first.write(Characteristic::OtaData, &chunk(0, &image[..2]));
+        drop(first);
+
+        let mut second = clients.remove(0);
+        second.write(Characteristic::OtaControl, &request);
+        assert_eq!(next_offset(&second.notification().value), Some(2));
+        second.write(Characteristic::OtaData, &chunk(0, &image[2..]));
+        assert_eq!(next_offset(&second.notification().value), Some(4));
+        second.write(Characteristic::OtaControl, &[gatt::OTA_CONTROL_DONE]);
+        assert_eq!(
+            second.notification(),
+            Packet::new(Characteristic::OtaControl, &[gatt::OTA_CONTROL_DONE_ACK])
+        );
+    }
+}
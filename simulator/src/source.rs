@@ -0,0 +1,140 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use app::domain::{
+    imu::{ImuSource, SyntheticSource},
+    sample::ImuSample,
+};
+
+/*
+    imu sources for the simulator
+
+    the sources in app::domain::imu hand out samples as fast as they are asked for, Paced holds
+    each back until its timestamp comes round the way the real sensor delivers them. Looped starts
+    a source over when it runs out, moving the timestamps on so they keep going up.
+*/
+
+/// period of the synthetic samples in micro seconds, 400 Hz like the sensor's default
+pub const SYNTHETIC_PERIOD: i64 = 2_500;
+
+/// a set of rebound jumps with some standing around either side
+pub fn synthetic_set(jumps: u32) -> SyntheticSource {
+    SyntheticSource::new(SYNTHETIC_PERIOD)
+        .stand(3_000_000)
+        .rebound_jumps(jumps, 500_000, 200_000)
+        .stand(5_000_000)
+}
+
+pub struct Paced<S> {
+    source: S,
+    speed: f64,
+    // when the first sample was handed out and its timestamp
+    start: Option<(Instant, i64)>,
+}
+
+impl<S: ImuSource> Paced<S> {
+    /// speed 2 plays the samples back twice as fast as they were taken
+    pub fn new(source: S, speed: f64) -> Self {
+        Paced {
+            source,
+            speed,
+            start: None,
+        }
+    }
+}
+
+impl<S: ImuSource> ImuSource for Paced<S> {
+    type Error = S::Error;
+
+    fn next_sample(&mut self) -> Result<Option<ImuSample>, Self::Error> {
+        let Some(sample) = self.source.next_sample()? else {
+            return Ok(None);
+        };
+        let (start, first) = *self.start.get_or_insert((Instant::now(), sample.timestamp));
+        let due = start
+            + Duration::from_micros(((sample.timestamp - first).max(0) as f64 / self.speed) as u64);
+        thread::sleep(due.saturating_duration_since(Instant::now()));
+        Ok(Some(sample))
+    }
+}
+
+pub struct Looped<S, F> {
+    new_source: F,
+    source: S,
+    // added to the timestamps of the current source
+    offset: i64,
+    last: Option<ImuSample>,
+    period: i64,
+}
+
+impl<S: ImuSource, F: FnMut() -> S> Looped<S, F> {
+    /// new_source makes each run, period is the gap in micro seconds between the last sample of a
+    /// run and the first of the next
+    pub fn new(mut new_source: F, period: i64) -> Self {
+        Looped {
+            source: new_source(),
+            new_source,
+            offset: 0,
+            last: None,
+            period,
+        }
+    }
+}
+
+impl<S: ImuSource, F: FnMut() -> S> ImuSource for Looped<S, F> {
+    type Error = S::Error;
+
+    fn next_sample(&mut self) -> Result<Option<ImuSample>, Self::Error> {
+        let mut sample = match self.source.next_sample()? {
+            Some(sample) => sample,
+            None => {
+                // an empty source would loop forever
+                let Some(last) = self.last else {
+                    return Ok(None);
+                };
+                self.source = (self.new_source)();
+                let Some(first) = self.source.next_sample()? else {
+                    return Ok(None);
+                };
+                self.offset = last.timestamp + self.period - first.timestamp;
+                first
+            }
+        };
+        sample.timestamp += self.offset;
+        self.last = Some(sample);
+        Ok(Some(sample))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loops_with_rising_timestamps() {
+        let mut source = Looped::new(|| SyntheticSource::new(10).stand(30), 10);
+
+        let timestamps: Vec<i64> = (0..7)
+            .map(|_| source.next_sample().unwrap().unwrap().timestamp)
+            .collect();
+
+        assert_eq!(timestamps, vec![0, 10, 20, 30, 40, 50, 60]);
+        assert!(Looped::new(|| SyntheticSource::new(10), 10)
+            .next_sample()
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn paces_samples() {
+        let mut source = Paced::new(SyntheticSource::new(10_000).stand(50_000), 2.0);
+
+        let start = Instant::now();
+        while source.next_sample().unwrap().is_some() {}
+
+        // the last sample is 40 ms in, at twice the speed that is 20 ms
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+}